@@ -1,30 +1,44 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Ident, Lifetime, Result};
+use syn::{Ident, Lifetime, Result, Type, parse_quote, parse2};
 
 use crate::meta::{MetaAttribute, MetaAttributeType};
 
 #[derive(Clone)]
 pub struct Config {
     pub input_name: Ident,
+    pub input_types: Vec<Type>,
+    pub output_name: Ident,
     pub selector_name: Ident,
+    pub versions_name: Ident,
     pub selector_parser: Option<TokenStream>,
     pub selection_error: Option<TokenStream>,
     pub error_type: Ident,
     pub lifetime: Lifetime,
     pub separator: TokenStream,
+    pub separator_char: TokenStream,
 }
 
 impl Config {
     pub fn from_meta_attributes(attribute_list: &[MetaAttribute]) -> Result<Self> {
+        let lifetime = Lifetime::new("'nmea", Span::call_site());
+        let mut input_types = vec![];
         let mut selector_parser = None;
-        let mut separator = quote! { nom::character::complete::char(',') };
+        let mut separator_char = quote! { ',' };
         let mut selection_error = None;
 
         for meta in attribute_list {
             match meta.r#type {
+                MetaAttributeType::Input => {
+                    let mut input_type = parse2::<Type>(meta.arg().unwrap().clone())?;
+                    // Elided input lifetimes borrow for the `'nmea` lifetime
+                    if let Type::Reference(reference) = &mut input_type {
+                        reference.lifetime.get_or_insert_with(|| lifetime.clone());
+                    }
+                    input_types.push(input_type);
+                }
                 MetaAttributeType::Selector => selector_parser = Some(meta.arg().unwrap().clone()),
-                MetaAttributeType::Separator => separator = meta.arg().unwrap().clone(),
+                MetaAttributeType::Separator => separator_char = meta.arg().unwrap().clone(),
                 MetaAttributeType::SelectionError => {
                     selection_error = Some(meta.arg().unwrap().clone())
                 }
@@ -32,14 +46,24 @@ impl Config {
             }
         }
 
+        if input_types.is_empty() {
+            input_types.push(parse_quote!(&#lifetime str));
+        }
+
+        let separator = quote! { nom::character::complete::char(#separator_char) };
+
         Ok(Self {
             input_name: Ident::new("nmea_input", Span::call_site()),
+            input_types,
+            output_name: Ident::new("nmea_output", Span::call_site()),
             selector_name: Ident::new("nmea_selector", Span::call_site()),
+            versions_name: Ident::new("nmea_versions", Span::call_site()),
             selector_parser,
             selection_error,
             error_type: Ident::new("NmeaError", Span::call_site()),
-            lifetime: Lifetime::new("'nmea", Span::call_site()),
+            lifetime,
             separator,
+            separator_char,
         })
     }
 }
@@ -1,6 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Attribute, DataEnum, Generics, Ident, Path, Result, parse_quote, spanned::Spanned};
+use syn::{
+    Attribute, DataEnum, Generics, Ident, Path, Result, Type, parse_quote, spanned::Spanned,
+};
 
 use crate::{
     config::Config,
@@ -37,17 +39,6 @@ impl Enum {
             ));
         }
 
-        let separator = attributes
-            .iter()
-            .find(|attr| attr.r#type == meta::MetaAttributeType::Separator);
-
-        if let Some(attr) = separator {
-            return Err(syn::Error::new(
-                attr.span(),
-                "nmea0183-derive: Enums do not support `separator` attributes yet; this will be implemented in the future.",
-            ));
-        }
-
         let variant_parsers = dataenum
             .variants
             .iter()
@@ -65,7 +56,11 @@ impl Enum {
         })
     }
 
-    pub fn generate_variants(&self) -> Result<(bool, Vec<TokenStream>)> {
+    pub fn generate_variants(
+        &self,
+        input_type: &Type,
+        versioned: bool,
+    ) -> Result<(bool, Vec<TokenStream>)> {
         let enum_name = &self.name;
         let input = &self.config.input_name;
         let mut default_case_handled = false;
@@ -92,7 +87,9 @@ impl Enum {
                     struct_parser: variant_parser.struct_parser.clone(),
                 };
 
-                let struct_body = r#struct.generate_parse_body(false).unwrap();
+                let struct_body = r#struct
+                    .generate_parse_body(input_type, false, versioned)
+                    .unwrap();
 
                 quote! {
                     #selector => {
@@ -140,13 +137,20 @@ impl Generator for Enum {
         &self.generics
     }
 
-    fn generate_parse_body(&self, use_nom_parser: bool) -> Result<TokenStream> {
+    fn generate_parse_body(
+        &self,
+        input_type: &Type,
+        use_nom_parser: bool,
+        versioned: bool,
+    ) -> Result<TokenStream> {
         let (pre_exec, post_exec) = (&self.pre_exec, &self.post_exec);
         let input = &self.config.input_name;
         let selector = &self.config.selector_name;
+        let error_type = &self.config.error_type;
         let selector_parser = self.config.selector_parser.as_ref().unwrap();
         let selection_error = self.config.selection_error.as_ref();
-        let (default_case_handled, variant_tokens) = self.generate_variants()?;
+        let (default_case_handled, variant_tokens) =
+            self.generate_variants(input_type, versioned)?;
 
         let default_case = if default_case_handled {
             quote! {}
@@ -166,14 +170,72 @@ impl Generator for Enum {
             #use_nom_parser
             #pre_exec
             let (#input, #selector) = #selector_parser.parse(#input)?;
-            let (#input, enum_def) = match #selector {
+            let result: nmea0183_parser::IResult<#input_type, Self, #error_type> = match #selector {
                 #(#variant_tokens)*
                 #default_case
-            }?;
+            };
+            let (#input, enum_def) = result?;
             #post_exec
             Ok((#input, enum_def))
         };
 
         Ok(body)
     }
+
+    /// Variants parsing their fields through `NmeaParse` pass the NMEA version on to them.
+    fn is_versioned(&self) -> bool {
+        self.variant_parsers.iter().any(|variant_parser| {
+            variant_parser.struct_parser.is_versioned()
+                || variant_parser
+                    .struct_parser
+                    .parsers
+                    .iter()
+                    .any(|field_parser| field_parser.parser.is_nmeaparse())
+        })
+    }
+
+    fn generate_encode_body(&self) -> Result<TokenStream> {
+        let enum_name = &self.name;
+        let variant_tokens = self
+            .variant_parsers
+            .iter()
+            .map(|variant_parser| {
+                let variant_name = &variant_parser.name;
+                let selector_writer = variant_parser.selector_writer.clone()?;
+
+                let r#struct = Struct {
+                    config: self.config.clone(),
+                    name: parse_quote!(#enum_name::#variant_name),
+                    generics: self.generics.clone(),
+                    pre_exec: None,
+                    post_exec: None,
+                    struct_parser: variant_parser.struct_parser.clone(),
+                };
+
+                let (pattern, writes) = r#struct.generate_write_fields()?;
+
+                Ok(quote! {
+                    #pattern => {
+                        #selector_writer
+                        #writes
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // An enum without variants, such as one whose variants are all disabled by features,
+        // cannot be matched through a reference
+        if variant_tokens.is_empty() {
+            return Ok(quote! { match *self {} });
+        }
+
+        let body = quote! {
+            match self {
+                #(#variant_tokens)*
+            }
+            Ok(())
+        };
+
+        Ok(body)
+    }
 }
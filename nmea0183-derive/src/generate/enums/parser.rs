@@ -1,15 +1,17 @@
 use proc_macro2::TokenStream;
-use syn::{Error, Ident, Result, Variant, spanned::Spanned};
+use quote::quote;
+use syn::{Error, Ident, Pat, Result, Variant, parse2, spanned::Spanned};
 
 use crate::{
     config::Config,
     generate::{pre_post_exec, structs::parser::StructParser},
-    meta,
+    meta::{self, MetaAttribute, PatAndGuard},
 };
 
 pub struct VariantParser {
     pub name: Ident,
     pub selector: TokenStream,
+    pub selector_writer: Result<TokenStream>,
     pub pre_exec: Option<TokenStream>,
     pub post_exec: Option<TokenStream>,
     pub struct_parser: StructParser,
@@ -28,15 +30,53 @@ impl VariantParser {
                 "nmea0183-derive: Variants must have a `selector` attribute",
             ))?;
 
+        let selector_writer = Self::get_selector_writer(&attributes, &selector, config);
         let struct_parser = StructParser::from_fields(&variant.fields, config, true)?;
         let (pre_exec, post_exec) = pre_post_exec(&attributes, config)?;
 
         Ok(Self {
             name: variant.ident.clone(),
             selector,
+            selector_writer,
             pre_exec,
             post_exec,
             struct_parser,
         })
     }
+
+    fn get_selector_writer(
+        attributes: &[MetaAttribute],
+        selector: &TokenStream,
+        config: &Config,
+    ) -> Result<TokenStream> {
+        let output = &config.output_name;
+
+        if let Some(writer) = attributes
+            .iter()
+            .find(|attr| attr.r#type == meta::MetaAttributeType::Writer)
+        {
+            let writer = writer.arg().unwrap();
+            return Ok(quote! { (#writer)(self, #output)?; });
+        }
+
+        let pattern = parse2::<PatAndGuard>(selector.clone())?;
+        match Self::get_selector_literal(&pattern.pat) {
+            Some(literal) if pattern.guard.is_none() => {
+                Ok(quote! { nmea0183_parser::NmeaEncode::encode(&#literal, #output)?; })
+            }
+            _ => Err(Error::new(
+                selector.span(),
+                "nmea0183-derive: Variants with a non-literal `selector` require a `writer` attribute to derive `NmeaEncode`",
+            )),
+        }
+    }
+
+    fn get_selector_literal(pattern: &Pat) -> Option<&Pat> {
+        match pattern {
+            Pat::Lit(_) => Some(pattern),
+            Pat::Or(or) => or.cases.first().and_then(Self::get_selector_literal),
+            Pat::Paren(paren) => Self::get_selector_literal(&paren.pat),
+            _ => None,
+        }
+    }
 }
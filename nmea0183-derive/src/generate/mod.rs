@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
-    Data, DeriveInput, Error, GenericParam, Generics, LifetimeParam, Path, Result, TypeParam,
+    Data, DeriveInput, Error, GenericParam, Generics, LifetimeParam, Path, Result, Type, TypeParam,
     WhereClause, parse_quote,
 };
 
@@ -39,37 +39,98 @@ trait Generator {
     fn name(&self) -> &Path;
     fn config(&self) -> &Config;
     fn generics(&self) -> &Generics;
-    fn generate_parse_body(&self, use_nom_parser: bool) -> Result<TokenStream>;
+    fn generate_parse_body(
+        &self,
+        input_type: &Type,
+        use_nom_parser: bool,
+        versioned: bool,
+    ) -> Result<TokenStream>;
+    fn generate_encode_body(&self) -> Result<TokenStream>;
+
+    /// Returns whether `NmeaParse::parse_version` is overridden to depend on the NMEA version.
+    fn is_versioned(&self) -> bool;
+
+    fn generate_parse_decl(&self, input_type: &Type) -> TokenStream {
+        let input = &self.config().input_name;
+        let error_type = &self.config().error_type;
 
-    fn generate_parse_decl(&self) -> TokenStream {
+        quote! {
+            fn parse(#input: #input_type) -> nmea0183_parser::IResult<#input_type, Self, #error_type>
+        }
+    }
+
+    fn generate_parse_version_decl(&self, input_type: &Type) -> TokenStream {
         let input = &self.config().input_name;
+        let versions = &self.config().versions_name;
         let error_type = &self.config().error_type;
-        let nmea_lifetime = &self.config().lifetime;
 
         quote! {
-            fn parse(#input: &#nmea_lifetime str) -> nmea0183_parser::IResult<&#nmea_lifetime str, Self, #error_type>
+            fn parse_version(
+                #input: #input_type,
+                #versions: nmea0183_parser::VersionRange,
+            ) -> nmea0183_parser::IResult<#input_type, Self, #error_type>
         }
     }
 
-    fn generate_parse(&self) -> Result<TokenStream> {
-        let decl = self.generate_parse_decl();
-        let body = self.generate_parse_body(true)?;
+    fn generate_parse(&self, input_type: &Type) -> Result<TokenStream> {
+        let decl = self.generate_parse_decl(input_type);
+
+        if !self.is_versioned() {
+            let body = self.generate_parse_body(input_type, true, false)?;
+
+            return Ok(quote! {
+                #decl
+                {
+                    #body
+                }
+            });
+        }
+
+        let input = &self.config().input_name;
+        let error_type = &self.config().error_type;
+        let version_decl = self.generate_parse_version_decl(input_type);
+        let version_body = self.generate_parse_body(input_type, true, true)?;
 
+        // `parse` accepts the fields of every version, the ones added after NMEA 2.0 being optional
         let func = quote! {
             #decl
             {
-                #body
+                Self::parse_version(#input, nmea0183_parser::VersionRange::default())
+            }
+
+            #version_decl
+            {
+                #version_body
+            }
+
+            fn parse_version_preceded<S>(
+                separator: S,
+                versions: nmea0183_parser::VersionRange,
+            ) -> impl nom::Parser<#input_type, Output = Self, Error = nmea0183_parser::Error<#input_type, #error_type>>
+            where
+                S: nom::Parser<#input_type, Error = nmea0183_parser::Error<#input_type, #error_type>>,
+            {
+                nom::sequence::preceded(separator, move |i: #input_type| Self::parse_version(i, versions))
             }
         };
 
         Ok(func)
     }
 
-    fn generate_impl(&self) -> Result<TokenStream> {
+    fn generate_impls(&self) -> Result<TokenStream> {
+        let mut impls_tokens = TokenStream::new();
+        for input_type in &self.config().input_types {
+            impls_tokens.extend(self.generate_impl(input_type)?);
+        }
+
+        Ok(impls_tokens)
+    }
+
+    fn generate_impl(&self, input_type: &Type) -> Result<TokenStream> {
         let name = self.name();
         let error_type = &self.config().error_type;
         let nmea_lifetime = &self.config().lifetime;
-        let parse_tokens = self.generate_parse()?;
+        let parse_tokens = self.generate_parse(input_type)?;
         let generics = self.generics();
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -105,9 +166,9 @@ trait Generator {
         // Make sure generic parameters implement NmeaParse
         for param in generics.type_params() {
             let param = &param.ident;
-            impl_where.predicates.push(
-                parse_quote!(#param: nmea0183_parser::NmeaParse<&#nmea_lifetime str, #error_type>),
-            );
+            impl_where
+                .predicates
+                .push(parse_quote!(#param: nmea0183_parser::NmeaParse<#input_type, #error_type>));
         }
 
         // // Push nmea input type to the where clause
@@ -118,17 +179,51 @@ trait Generator {
         // Push nmea error type to the where clause
         impl_where
             .predicates
-            .push(parse_quote!(#error_type: nom::error::ParseError<&#nmea_lifetime str>));
+            .push(parse_quote!(#error_type: nom::error::ParseError<#input_type>));
 
         // Generate the implementation
         let impl_tokens = quote! {
-            impl #impl_generics nmea0183_parser::NmeaParse<&#nmea_lifetime str, #error_type> for #name #ty_generics #impl_where {
+            impl #impl_generics nmea0183_parser::NmeaParse<#input_type, #error_type> for #name #ty_generics #impl_where {
                 #parse_tokens
             }
         };
 
         Ok(impl_tokens)
     }
+
+    fn generate_encode_impl(&self) -> Result<TokenStream> {
+        let name = self.name();
+        let output = &self.config().output_name;
+        let body = self.generate_encode_body()?;
+        let generics = self.generics();
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        // If there is no where clause, create a new one
+        let mut impl_where: WhereClause = if where_clause.is_some() {
+            parse_quote!(#where_clause)
+        } else {
+            parse_quote!(where)
+        };
+
+        // Make sure generic parameters implement NmeaEncode
+        for param in generics.type_params() {
+            let param = &param.ident;
+            impl_where
+                .predicates
+                .push(parse_quote!(#param: nmea0183_parser::NmeaEncode));
+        }
+
+        // Generate the implementation
+        let impl_tokens = quote! {
+            impl #impl_generics nmea0183_parser::NmeaEncode for #name #ty_generics #impl_where {
+                fn encode(&self, #output: &mut nmea0183_parser::Encoder<'_>) -> ::std::fmt::Result {
+                    #body
+                }
+            }
+        };
+
+        Ok(impl_tokens)
+    }
 }
 
 pub fn get_error_if(cond: &TokenStream, config: &Config) -> TokenStream {
@@ -190,7 +285,7 @@ pub fn pre_post_exec(
     Ok((pre_exec, post_exec))
 }
 
-pub fn generate_nmea_parse_impl(input: &DeriveInput) -> Result<TokenStream> {
+fn get_generator(input: &DeriveInput) -> Result<Box<dyn Generator>> {
     let generator: Box<dyn Generator> = match &input.data {
         Data::Struct(datastruct) => {
             let name = &input.ident;
@@ -216,5 +311,13 @@ pub fn generate_nmea_parse_impl(input: &DeriveInput) -> Result<TokenStream> {
         }
     };
 
-    generator.generate_impl()
+    Ok(generator)
+}
+
+pub fn generate_nmea_parse_impl(input: &DeriveInput) -> Result<TokenStream> {
+    get_generator(input)?.generate_impls()
+}
+
+pub fn generate_nmea_encode_impl(input: &DeriveInput) -> Result<TokenStream> {
+    get_generator(input)?.generate_encode_impl()
 }
@@ -1,6 +1,6 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Attribute, DataStruct, Generics, Ident, Path, Result, parse_quote};
+use syn::{Attribute, DataStruct, Generics, Ident, Path, Result, Type, parse_quote};
 
 use crate::{
     config::Config,
@@ -42,12 +42,6 @@ impl Struct {
                         "nmea0183-derive: Structs do not support `selection_error` attributes; only enums support this feature.",
                     ));
                 }
-                meta::MetaAttributeType::Separator => {
-                    return Err(syn::Error::new(
-                        attribute.span(),
-                        "nmea0183-derive: Structs do not support `separator` attributes yet; this will be implemented in the future.",
-                    ));
-                }
                 _ => {}
             }
         }
@@ -67,6 +61,44 @@ impl Struct {
     }
 }
 
+impl Struct {
+    /// Generates the pattern destructuring the struct, and the statements writing its fields.
+    pub fn generate_write_fields(&self) -> Result<(TokenStream, TokenStream)> {
+        let name = &self.name;
+        let output = &self.config.output_name;
+
+        let mut bindings = vec![];
+        let mut writes = vec![];
+        for field_parser in &self.struct_parser.parsers {
+            let writer = field_parser.writer.clone()?;
+            let field = Ident::new(&field_parser.variable_name, Span::call_site());
+
+            if writer.is_ignored() {
+                bindings.push((field, quote! { _ }));
+            } else {
+                writes.push(writer.write_value(quote! { #field }, output));
+                bindings.push((field.clone(), quote! { #field }));
+            }
+        }
+
+        let pattern = match (self.struct_parser.empty, self.struct_parser.unnamed) {
+            (true, _) => quote! { #name },
+            (_, true) => {
+                let bindings = bindings.iter().map(|(_, binding)| binding);
+                quote! { #name(#(#bindings),*) }
+            }
+            (_, false) => {
+                let bindings = bindings
+                    .iter()
+                    .map(|(field, binding)| quote! { #field: #binding });
+                quote! { #name { #(#bindings),* } }
+            }
+        };
+
+        Ok((pattern, quote! { #(#writes)* }))
+    }
+}
+
 impl Generator for Struct {
     fn name(&self) -> &Path {
         &self.name
@@ -80,20 +112,44 @@ impl Generator for Struct {
         &self.generics
     }
 
-    fn generate_parse_body(&self, use_nom_parser: bool) -> Result<TokenStream> {
+    fn generate_parse_body(
+        &self,
+        input_type: &Type,
+        use_nom_parser: bool,
+        versioned: bool,
+    ) -> Result<TokenStream> {
         let name = &self.name;
+        let error_type = &self.config.error_type;
         let (pre_exec, post_exec) = (&self.pre_exec, &self.post_exec);
         let input = &self.config.input_name;
+        let versions = versioned.then_some(&self.config.versions_name);
 
-        let (variable_name, parser): (Vec<_>, Vec<_>) = self
+        let (variable_name, parse): (Vec<_>, Vec<_>) = self
             .struct_parser
             .parsers
             .iter()
             .map(|field_parser| {
-                (
-                    Ident::new(&field_parser.variable_name, Span::call_site()),
-                    &field_parser.parser,
-                )
+                let variable_name = Ident::new(&field_parser.variable_name, Span::call_site());
+                let parser = field_parser
+                    .parser
+                    .clone()
+                    .into_nmeaparse(input_type, error_type, versions);
+
+                // Fields of a version are required, accepted or forbidden depending on the range
+                let parse = match (&field_parser.version, versions) {
+                    (Some(version), Some(versions)) => quote! {
+                        match #versions.field_mode(#version) {
+                            nmea0183_parser::FieldMode::Required => #parser.parse(#input)?,
+                            nmea0183_parser::FieldMode::Optional => nom::combinator::opt(#parser)
+                                .map(Option::unwrap_or_default)
+                                .parse(#input)?,
+                            nmea0183_parser::FieldMode::Forbidden => (#input, Default::default()),
+                        }
+                    },
+                    _ => quote! { #parser.parse(#input)? },
+                };
+
+                (variable_name, parse)
             })
             .unzip();
 
@@ -133,7 +189,7 @@ impl Generator for Struct {
         let body = quote! {
             #use_nom_parser
             #pre_exec
-            #(#field_pre_exec let (#input, #variable_name) = #parser.parse(#input)?; #field_post_exec)*
+            #(#field_pre_exec let (#input, #variable_name) = #parse; #field_post_exec)*
             let struct_def = #struct_def;
             #post_exec
             Ok((#input, struct_def))
@@ -143,4 +199,20 @@ impl Generator for Struct {
 
         // todo!("Implement generate_parse_body for Struct");
     }
+
+    fn is_versioned(&self) -> bool {
+        self.struct_parser.is_versioned()
+    }
+
+    fn generate_encode_body(&self) -> Result<TokenStream> {
+        let (pattern, writes) = self.generate_write_fields()?;
+
+        let body = quote! {
+            let #pattern = self;
+            #writes
+            Ok(())
+        };
+
+        Ok(body)
+    }
 }
@@ -7,12 +7,15 @@ use crate::{
     generate::pre_post_exec,
     meta::{self, MetaAttribute, MetaAttributeType},
     parser::Parser,
+    writer::Writer,
 };
 
 #[derive(Clone)]
 pub struct FieldParser {
     pub variable_name: String,
     pub parser: Parser,
+    pub writer: Result<Writer>,
+    pub version: Option<TokenStream>,
     pub pre_exec: Option<TokenStream>,
     pub post_exec: Option<TokenStream>,
 }
@@ -40,8 +43,6 @@ impl StructParser {
         }
 
         let separator = &config.separator;
-        let error_type = &config.error_type;
-        let nmea_lifetime = &config.lifetime;
 
         let mut first_field = !preceded;
         let mut parsers = vec![];
@@ -53,15 +54,20 @@ impl StructParser {
             let attributes = meta::parse_field_level_attributes(&field.attrs)?;
 
             let mut ignore = false;
+            let mut version = None;
             for attribute in &attributes {
-                if attribute.r#type == MetaAttributeType::Ignore {
-                    ignore = true;
+                match attribute.r#type {
+                    MetaAttributeType::Ignore => ignore = true,
+                    MetaAttributeType::Version => version = attribute.arg().cloned(),
+                    _ => {}
                 }
             }
 
+            let separator_char = Some(&config.separator_char).filter(|_| !first_field && !ignore);
+            let writer = Self::get_writer(&attributes, separator_char.cloned());
+
             let separator = Some(separator).filter(|_| !first_field && !ignore);
             let parser = Self::get_parser(&field.ty, &attributes, separator.cloned())?;
-            let parser = parser.as_nmeaparse(error_type, nmea_lifetime);
 
             if first_field && !ignore {
                 first_field = false;
@@ -72,6 +78,8 @@ impl StructParser {
             parsers.push(FieldParser {
                 variable_name,
                 parser,
+                writer,
+                version,
                 pre_exec,
                 post_exec,
             });
@@ -84,6 +92,13 @@ impl StructParser {
         })
     }
 
+    /// Returns whether a field depends on the NMEA version, through the `version` attribute.
+    pub fn is_versioned(&self) -> bool {
+        self.parsers
+            .iter()
+            .any(|field_parser| field_parser.version.is_some())
+    }
+
     fn get_parser(
         ty: &Type,
         attributes: &[MetaAttribute],
@@ -146,17 +161,67 @@ impl StructParser {
         })
     }
 
+    fn get_writer(attributes: &[MetaAttribute], separator: Option<TokenStream>) -> Result<Writer> {
+        // Attributes transforming the parsed value can only be reversed by a paired writer
+        let mut transform = None;
+        let mut attributes = attributes;
+        while let Some((attribute, rest)) = attributes.split_first() {
+            match attribute.r#type {
+                MetaAttributeType::Writer => {
+                    let writer = attribute.arg().unwrap().clone();
+                    return Ok(Writer::Raw { writer, separator });
+                }
+                MetaAttributeType::Parser | MetaAttributeType::Map | MetaAttributeType::Into => {
+                    transform.get_or_insert(attribute);
+                }
+                MetaAttributeType::ParseAs if transform.is_none() => {
+                    let parse_as = attribute.arg().unwrap();
+                    let parse_as_type = parse2::<Type>(parse_as.clone())?;
+                    return Ok(Writer::Type {
+                        ty: Some(Box::new(parse_as_type)),
+                        separator,
+                    });
+                }
+                MetaAttributeType::Ignore if transform.is_none() => {
+                    return Ok(Writer::Ignore);
+                }
+                MetaAttributeType::Cond if transform.is_none() => {
+                    let writer = Self::get_writer(rest, separator)?;
+                    return Ok(Writer::Cond(Box::new(writer)));
+                }
+                _ => {}
+            }
+
+            attributes = rest;
+        }
+
+        if let Some(attribute) = transform {
+            return Err(Error::new(
+                attribute.span(),
+                format!(
+                    "nmea0183-derive: Attribute `{}` requires a paired `writer` attribute to derive `NmeaEncode`",
+                    attribute.r#type
+                ),
+            ));
+        }
+
+        Ok(Writer::Type {
+            ty: None,
+            separator,
+        })
+    }
+
     fn get_innermost_type_parser(ty: &Type, expected: &str, attr: &str) -> Result<TokenStream> {
-        if let Type::Path(TypePath { path, .. }) = ty {
-            if let Some(segment) = path.segments.last() {
-                let ident = &segment.ident.to_string();
-                if ident == expected {
-                    if let PathArguments::AngleBracketed(ref args) = segment.arguments {
-                        return Ok(args.args.to_token_stream());
-                    }
-                } else {
-                    return Ok(quote! { #ty });
+        if let Type::Path(TypePath { path, .. }) = ty
+            && let Some(segment) = path.segments.last()
+        {
+            let ident = &segment.ident.to_string();
+            if ident == expected {
+                if let PathArguments::AngleBracketed(ref args) = segment.arguments {
+                    return Ok(args.args.to_token_stream());
                 }
+            } else {
+                return Ok(quote! { #ty });
             }
         }
 
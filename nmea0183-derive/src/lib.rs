@@ -10,7 +10,7 @@
 //! [`nmea0183-parser`]: https://crates.io/crates/nmea0183-parser
 //! [`nom-derive`]: https://crates.io/crates/nom-derive
 
-use generate::generate_nmea_parse_impl;
+use generate::{generate_nmea_encode_impl, generate_nmea_parse_impl};
 use proc_macro::TokenStream;
 use syn::{DeriveInput, parse_macro_input};
 
@@ -18,6 +18,7 @@ mod config;
 mod generate;
 mod meta;
 mod parser;
+mod writer;
 
 #[doc = include_str!("../README.md")]
 #[proc_macro_derive(NmeaParse, attributes(nmea))]
@@ -29,3 +30,16 @@ pub fn derive_nmea_parse(input: TokenStream) -> TokenStream {
         Err(err) => err.to_compile_error().into(),
     }
 }
+
+/// Derives `NmeaEncode` from the same `#[nmea(...)]` attributes as [`NmeaParse`](derive@NmeaParse).
+///
+/// See the [`NmeaParse`](derive@NmeaParse) derive macro for the supported attributes.
+#[proc_macro_derive(NmeaEncode, attributes(nmea))]
+pub fn derive_nmea_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match generate_nmea_encode_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -14,6 +14,7 @@ pub enum MetaAttributeType {
     Cond,
     Exact,
     Ignore,
+    Input,
     Into,
     Map,
     ParseAs,
@@ -25,6 +26,8 @@ pub enum MetaAttributeType {
     Separator,
     SkipAfter,
     SkipBefore,
+    Version,
+    Writer,
 }
 
 impl MetaAttributeType {
@@ -33,6 +36,7 @@ impl MetaAttributeType {
             "cond" => Some(Self::Cond),
             "exact" => Some(Self::Exact),
             "ignore" => Some(Self::Ignore),
+            "input" => Some(Self::Input),
             "into" => Some(Self::Into),
             "map" => Some(Self::Map),
             "parse_as" => Some(Self::ParseAs),
@@ -44,6 +48,8 @@ impl MetaAttributeType {
             "separator" => Some(Self::Separator),
             "skip_after" => Some(Self::SkipAfter),
             "skip_before" => Some(Self::SkipBefore),
+            "version" => Some(Self::Version),
+            "writer" => Some(Self::Writer),
             _ => None,
         }
     }
@@ -52,6 +58,7 @@ impl MetaAttributeType {
         matches!(
             self,
             Self::Cond
+                | Self::Input
                 | Self::Map
                 | Self::ParseAs
                 | Self::Parser
@@ -62,13 +69,15 @@ impl MetaAttributeType {
                 | Self::Separator
                 | Self::SkipAfter
                 | Self::SkipBefore
+                | Self::Version
+                | Self::Writer
         )
     }
 
     fn allowed_multiple(&self) -> bool {
         matches!(
             self,
-            Self::Cond | Self::Map | Self::PreExec | Self::PostExec
+            Self::Cond | Self::Input | Self::Map | Self::PreExec | Self::PostExec
         )
     }
 }
@@ -79,6 +88,7 @@ impl Display for MetaAttributeType {
             Self::Cond => "cond",
             Self::Exact => "exact",
             Self::Ignore => "ignore",
+            Self::Input => "input",
             Self::Into => "into",
             Self::Map => "map",
             Self::ParseAs => "parse_as",
@@ -90,12 +100,14 @@ impl Display for MetaAttributeType {
             Self::Separator => "separator",
             Self::SkipAfter => "skip_after",
             Self::SkipBefore => "skip_before",
+            Self::Version => "version",
+            Self::Writer => "writer",
         };
         write!(f, "{name}")
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct MetaAttribute {
     pub r#type: MetaAttributeType,
     arg: Option<TokenStream>,
@@ -111,6 +123,7 @@ impl MetaAttribute {
         matches!(
             self.r#type,
             MetaAttributeType::Exact
+                | MetaAttributeType::Input
                 | MetaAttributeType::PreExec
                 | MetaAttributeType::PostExec
                 | MetaAttributeType::Selector
@@ -125,6 +138,7 @@ impl MetaAttribute {
         !matches!(
             self.r#type,
             MetaAttributeType::Exact
+                | MetaAttributeType::Input
                 | MetaAttributeType::Separator
                 | MetaAttributeType::SelectionError
         )
@@ -152,7 +166,9 @@ impl Parse for MetaAttribute {
                 MetaAttributeType::PreExec | MetaAttributeType::PostExec => {
                     parse_argument::<Stmt>(input)?
                 }
-                MetaAttributeType::ParseAs => parse_argument::<Type>(input)?,
+                MetaAttributeType::Input | MetaAttributeType::ParseAs => {
+                    parse_argument::<Type>(input)?
+                }
                 MetaAttributeType::Selector => parse_argument::<PatAndGuard>(input)?,
                 _ => parse_argument::<Expr>(input)?,
             };
@@ -276,7 +292,9 @@ pub fn parse_top_level_attributes(attrs: &[Attribute]) -> Result<Vec<MetaAttribu
                 ));
             }
 
-            if !attributes_set.insert(meta_attr.r#type.to_string()) {
+            if !meta_attr.r#type.allowed_multiple()
+                && !attributes_set.insert(meta_attr.r#type.to_string())
+            {
                 return Err(Error::new(
                     meta_attr.span(),
                     format!(
@@ -21,16 +21,54 @@ pub enum Parser {
 }
 
 impl Parser {
-    pub fn as_nmeaparse(self, error_type: &syn::Ident, nmea_lifetime: &syn::Lifetime) -> Self {
+    /// Returns whether the field type is parsed through its `NmeaParse` implementation.
+    pub fn is_nmeaparse(&self) -> bool {
+        match self {
+            Self::Cond { parser, .. } | Self::Into(parser) | Self::Map { parser, .. } => {
+                parser.is_nmeaparse()
+            }
+            Self::Raw(_) => false,
+            Self::Type { .. } => true,
+        }
+    }
+
+    /// Resolves the parsers of field types to their `NmeaParse` implementation.
+    ///
+    /// With `versions`, the name of the version range of `parse_version`, the field types are
+    /// parsed through their `parse_version` method for the same range.
+    pub fn into_nmeaparse(
+        self,
+        input_type: &Type,
+        error_type: &syn::Ident,
+        versions: Option<&syn::Ident>,
+    ) -> Self {
         match self {
             Self::Type { ty, separator } => {
-                let parser = if let Some(separator) = separator {
-                    quote! { <#ty as nmea0183_parser::NmeaParse<&#nmea_lifetime str, #error_type>>::parse_preceded(#separator) }
-                } else {
-                    quote! { <#ty as nmea0183_parser::NmeaParse<&#nmea_lifetime str, #error_type>>::parse }
+                let nmea_parse =
+                    quote! { <#ty as nmea0183_parser::NmeaParse<#input_type, #error_type>> };
+                let parser = match (separator, versions) {
+                    (Some(separator), Some(versions)) => {
+                        quote! { #nmea_parse::parse_version_preceded(#separator, #versions) }
+                    }
+                    (None, Some(versions)) => {
+                        quote! { (|i: #input_type| #nmea_parse::parse_version(i, #versions)) }
+                    }
+                    (Some(separator), None) => quote! { #nmea_parse::parse_preceded(#separator) },
+                    (None, None) => quote! { #nmea_parse::parse },
                 };
                 Self::Raw(parser)
             }
+            Self::Cond { parser, condition } => Self::Cond {
+                parser: Box::new(parser.into_nmeaparse(input_type, error_type, versions)),
+                condition,
+            },
+            Self::Into(parser) => Self::Into(Box::new(
+                parser.into_nmeaparse(input_type, error_type, versions),
+            )),
+            Self::Map { parser, map } => Self::Map {
+                parser: Box::new(parser.into_nmeaparse(input_type, error_type, versions)),
+                map,
+            },
             parser => parser,
         }
     }
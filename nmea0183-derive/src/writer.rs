@@ -0,0 +1,58 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident, Type};
+
+#[derive(Clone)]
+pub enum Writer {
+    Cond(Box<Writer>),
+    Ignore,
+    Raw {
+        writer: TokenStream,
+        separator: Option<TokenStream>,
+    },
+    Type {
+        ty: Option<Box<Type>>,
+        separator: Option<TokenStream>,
+    },
+}
+
+impl Writer {
+    pub fn is_ignored(&self) -> bool {
+        matches!(self, Self::Ignore)
+    }
+
+    /// Generates the statements writing `value`, a reference to the field value, to `output`.
+    pub fn write_value(&self, value: TokenStream, output: &Ident) -> TokenStream {
+        match self {
+            Self::Cond(writer) => {
+                let write = writer.write_value(quote! { nmea_value }, output);
+                quote! {
+                    if let Some(nmea_value) = #value {
+                        #write
+                    }
+                }
+            }
+            Self::Ignore => quote! {},
+            Self::Raw { writer, separator } => {
+                let separator = separator.as_ref().map(|separator| {
+                    quote! { ::std::fmt::Write::write_char(#output, #separator)?; }
+                });
+                quote! {
+                    #separator
+                    (#writer)(#value, #output)?;
+                }
+            }
+            Self::Type { ty, separator } => {
+                let encode = match ty {
+                    Some(ty) => quote! { <#ty as nmea0183_parser::NmeaEncode> },
+                    None => quote! { nmea0183_parser::NmeaEncode },
+                };
+                if let Some(separator) = separator {
+                    quote! { #encode::encode_preceded(#value, #separator, #output)?; }
+                } else {
+                    quote! { #encode::encode(#value, #output)?; }
+                }
+            }
+        }
+    }
+}
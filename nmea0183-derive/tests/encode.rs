@@ -0,0 +1,263 @@
+use nmea0183_parser::{Encoder, IResult, NmeaEncode, NmeaParse};
+use nom::{character::complete::one_of, combinator::map, number::complete::double};
+use std::fmt::{self, Write};
+
+fn encode<T: NmeaEncode>(value: &T) -> String {
+    let mut output = String::new();
+    value.encode(&mut Encoder::new(&mut output)).unwrap();
+    output
+}
+
+#[derive(Debug, PartialEq, NmeaEncode, NmeaParse)]
+struct Fields {
+    a: u8,
+    b: Option<f32>,
+    c: char,
+}
+
+#[derive(Debug, PartialEq, NmeaEncode, NmeaParse)]
+struct Trailing {
+    a: u8,
+    b: Option<u8>,
+    c: Option<char>,
+}
+
+#[derive(Debug, PartialEq, NmeaEncode, NmeaParse)]
+struct Ignored {
+    #[nmea(ignore)]
+    first: u8,
+    a: u8,
+    #[nmea(ignore)]
+    middle: Option<u8>,
+    b: u8,
+}
+
+#[derive(Debug, PartialEq, NmeaEncode, NmeaParse)]
+struct Conditional {
+    a: u8,
+    #[nmea(cond(a > 0))]
+    b: Option<u8>,
+    c: u8,
+}
+
+#[derive(Debug, PartialEq, NmeaEncode, NmeaParse)]
+struct CustomWriter {
+    count: u8,
+    #[nmea(parser(map(double, |v| v * 2.0)), writer(write_half))]
+    value: f64,
+    #[nmea(cond(count > 1), map(|c: char| c == 'Y'), parse_as(char), writer(write_flag))]
+    flag: Option<bool>,
+}
+
+fn write_half(value: &f64, e: &mut Encoder<'_>) -> fmt::Result {
+    (value / 2.0).encode(e)
+}
+
+fn write_flag(flag: &bool, e: &mut Encoder<'_>) -> fmt::Result {
+    e.write_char(if *flag { 'Y' } else { 'N' })
+}
+
+#[derive(Debug, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(selector(u8::parse))]
+enum Selected {
+    #[nmea(selector(0))]
+    Unit,
+    #[nmea(selector(1))]
+    Tuple(u8, Option<u8>),
+    #[nmea(selector(2))]
+    Named { a: char, b: u8 },
+}
+
+#[derive(Debug, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(selector(one_of("AB")))]
+enum CharSelected {
+    #[nmea(selector('A'))]
+    A,
+    #[nmea(selector('B'))]
+    B,
+}
+
+#[derive(Debug, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(selector(u8::parse))]
+enum Guarded {
+    #[nmea(selector(value if value < 10), writer(write_guarded))]
+    Small(u8),
+    #[nmea(selector(_), writer(write_guarded))]
+    Large(u8),
+}
+
+fn write_guarded(value: &Guarded, e: &mut Encoder<'_>) -> fmt::Result {
+    match value {
+        Guarded::Small(_) => e.write_char('1'),
+        Guarded::Large(_) => e.write_str("10"),
+    }
+}
+
+#[test]
+fn test_encode_fields() {
+    let data = Fields {
+        a: 1,
+        b: Some(2.5),
+        c: 'A',
+    };
+    assert_eq!(encode(&data), "1,2.5,A");
+
+    let data = Fields {
+        a: 1,
+        b: None,
+        c: 'A',
+    };
+    assert_eq!(encode(&data), "1,,A");
+
+    let result: IResult<_, _> = Fields::parse("1,,A");
+    assert_eq!(result, Ok(("", data)));
+}
+
+#[test]
+fn test_encode_trailing_options() {
+    let cases = [
+        (
+            Trailing {
+                a: 1,
+                b: Some(2),
+                c: Some('C'),
+            },
+            "1,2,C",
+        ),
+        (
+            Trailing {
+                a: 1,
+                b: Some(2),
+                c: None,
+            },
+            "1,2,",
+        ),
+        (
+            Trailing {
+                a: 1,
+                b: None,
+                c: None,
+            },
+            "1,,",
+        ),
+    ];
+
+    for (data, expected) in cases {
+        let output = encode(&data);
+        assert_eq!(output, expected);
+
+        let result: IResult<_, _> = Trailing::parse(output.as_str());
+        assert_eq!(result, Ok(("", data)));
+    }
+}
+
+#[test]
+fn test_encode_ignore() {
+    let data = Ignored {
+        first: 7,
+        a: 1,
+        middle: Some(9),
+        b: 2,
+    };
+    assert_eq!(encode(&data), "1,2");
+
+    let result: IResult<_, _> = Ignored::parse("1,2");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Ignored {
+                first: 0,
+                a: 1,
+                middle: None,
+                b: 2
+            }
+        ))
+    );
+}
+
+#[test]
+fn test_encode_cond() {
+    let cases = [
+        (
+            Conditional {
+                a: 0,
+                b: None,
+                c: 3,
+            },
+            "0,3",
+        ),
+        (
+            Conditional {
+                a: 1,
+                b: Some(2),
+                c: 3,
+            },
+            "1,2,3",
+        ),
+    ];
+
+    for (data, expected) in cases {
+        let output = encode(&data);
+        assert_eq!(output, expected);
+
+        let result: IResult<_, _> = Conditional::parse(output.as_str());
+        assert_eq!(result, Ok(("", data)));
+    }
+}
+
+#[test]
+fn test_encode_writer() {
+    let cases = [
+        (
+            CustomWriter {
+                count: 1,
+                value: 5.0,
+                flag: None,
+            },
+            "1,2.5",
+        ),
+        (
+            CustomWriter {
+                count: 2,
+                value: 3.0,
+                flag: Some(true),
+            },
+            "2,1.5,Y",
+        ),
+    ];
+
+    for (data, expected) in cases {
+        let output = encode(&data);
+        assert_eq!(output, expected);
+
+        let result: IResult<_, _> = CustomWriter::parse(output.as_str());
+        assert_eq!(result, Ok(("", data)));
+    }
+}
+
+#[test]
+fn test_encode_enum_selectors() {
+    let cases = [
+        (Selected::Unit, "0"),
+        (Selected::Tuple(7, None), "1,7,"),
+        (Selected::Tuple(7, Some(8)), "1,7,8"),
+        (Selected::Named { a: 'X', b: 3 }, "2,X,3"),
+    ];
+
+    for (data, expected) in cases {
+        let output = encode(&data);
+        assert_eq!(output, expected);
+
+        let result: IResult<_, _> = Selected::parse(output.as_str());
+        assert_eq!(result, Ok(("", data)));
+    }
+
+    assert_eq!(encode(&CharSelected::A), "A");
+    assert_eq!(encode(&CharSelected::B), "B");
+
+    assert_eq!(encode(&Guarded::Small(4)), "1,4");
+    assert_eq!(encode(&Guarded::Large(4)), "10,4");
+    let result: IResult<_, _> = Guarded::parse("10,4");
+    assert_eq!(result, Ok(("", Guarded::Large(4))));
+}
@@ -0,0 +1,183 @@
+use nmea0183_parser::{IResult, NmeaParse, NmeaVersion, VersionRange};
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Data {
+    a: u8,
+    #[nmea(version(NmeaVersion::V2_3))]
+    b: Option<u8>,
+    #[nmea(version(NmeaVersion::V4_11))]
+    c: Option<char>,
+}
+
+#[derive(Debug, PartialEq, NmeaParse)]
+struct Outer {
+    data: Data,
+    #[nmea(version(NmeaVersion::V3_0))]
+    d: u8,
+}
+
+#[test]
+fn test_version_required() {
+    let versions = VersionRange::from(NmeaVersion::V2_3..=NmeaVersion::V4_11);
+
+    let result: IResult<_, _> = Data::parse_version("1,2", versions);
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Data {
+                a: 1,
+                b: Some(2),
+                c: None,
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = Data::parse_version("1,", versions);
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Data {
+                a: 1,
+                b: None,
+                c: None,
+            }
+        ))
+    );
+
+    let result: IResult<_, Data> = Data::parse_version("1", versions);
+    assert!(result.is_err(), "{result:?}");
+
+    let result: IResult<_, Data> = Data::parse_version("1,2", NmeaVersion::V4_11.into());
+    assert!(result.is_err(), "{result:?}");
+}
+
+#[test]
+fn test_version_optional() {
+    let versions = VersionRange::from(NmeaVersion::V2_0..=NmeaVersion::V4_11);
+
+    let cases = [
+        (
+            "1,2,C",
+            Data {
+                a: 1,
+                b: Some(2),
+                c: Some('C'),
+            },
+        ),
+        (
+            "1,2",
+            Data {
+                a: 1,
+                b: Some(2),
+                c: None,
+            },
+        ),
+        (
+            "1",
+            Data {
+                a: 1,
+                b: None,
+                c: None,
+            },
+        ),
+    ];
+
+    for (input, expected) in cases {
+        let result: IResult<_, _> = Data::parse_version(input, versions);
+        assert_eq!(result, Ok(("", expected)), "Failed: {input:?}");
+    }
+}
+
+#[test]
+fn test_version_forbidden() {
+    let result: IResult<_, _> = Data::parse_version("1", NmeaVersion::V2_0.into());
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Data {
+                a: 1,
+                b: None,
+                c: None,
+            }
+        ))
+    );
+
+    // A forbidden field that is present is left unparsed
+    let result: IResult<_, _> = Data::parse_version("1,2", NmeaVersion::V2_0.into());
+    assert_eq!(
+        result,
+        Ok((
+            ",2",
+            Data {
+                a: 1,
+                b: None,
+                c: None,
+            }
+        ))
+    );
+
+    let versions = VersionRange::from(NmeaVersion::V2_0..=NmeaVersion::V2_3);
+    let result: IResult<_, _> = Data::parse_version("1,2,C", versions);
+    assert_eq!(
+        result,
+        Ok((
+            ",C",
+            Data {
+                a: 1,
+                b: Some(2),
+                c: None,
+            }
+        ))
+    );
+}
+
+#[test]
+fn test_version_default() {
+    for input in ["1,2,C", "1,2", "1"] {
+        let expected: IResult<_, Data> = Data::parse_version(input, VersionRange::default());
+        let result: IResult<_, Data> = Data::parse(input);
+        assert!(matches!(result, Ok(("", _))), "Failed: {input:?}");
+        assert_eq!(result, expected, "Failed: {input:?}");
+    }
+}
+
+#[test]
+fn test_version_nested() {
+    let result: IResult<_, _> = Outer::parse_version("1,2,3", NmeaVersion::V3_0.into());
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Outer {
+                data: Data {
+                    a: 1,
+                    b: Some(2),
+                    c: None,
+                },
+                d: 3,
+            }
+        ))
+    );
+
+    let result: IResult<_, _> = Outer::parse_version("1,2,C,3", NmeaVersion::V3_0.into());
+    assert!(result.is_err(), "{result:?}");
+
+    let result: IResult<_, _> = Outer::parse("1,2,C,3");
+    assert_eq!(
+        result,
+        Ok((
+            "",
+            Outer {
+                data: Data {
+                    a: 1,
+                    b: Some(2),
+                    c: Some('C'),
+                },
+                d: 3,
+            }
+        ))
+    );
+}
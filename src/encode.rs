@@ -0,0 +1,354 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
+
+/// Numeric precision used when encoding floating point and time values.
+///
+/// The defaults are chosen so that values parsed from NMEA 0183 sentences are
+/// encoded back to text that parses to the same values again, which keeps
+/// parse-encode round-trips stable.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{Encoder, NmeaEncode, Precision};
+///
+/// let mut output = String::new();
+/// let precision = Precision {
+///     decimals: Some(2),
+///     ..Precision::default()
+/// };
+///
+/// 12.3456f32
+///     .encode(&mut Encoder::with_precision(&mut output, precision))
+///     .unwrap();
+/// assert_eq!(output, "12.35");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Precision {
+    /// Number of decimal places for floating point fields.
+    ///
+    /// [`None`] writes the shortest representation that parses back to the
+    /// same value. Defaults to [`None`].
+    pub decimals: Option<usize>,
+
+    /// Number of decimal places for the minutes of latitude and longitude fields.
+    ///
+    /// Defaults to 5, about 2 cm at the equator.
+    pub minutes: usize,
+
+    /// Number of decimal places for the seconds of time fields, at most 3.
+    ///
+    /// Defaults to 2, as in the `hhmmss.ss` format of the NMEA 0183 standard.
+    pub seconds: usize,
+}
+
+impl Default for Precision {
+    fn default() -> Self {
+        Precision {
+            decimals: None,
+            minutes: 5,
+            seconds: 2,
+        }
+    }
+}
+
+/// Output sink for [`NmeaEncode`] implementations.
+///
+/// An `Encoder` wraps any [`fmt::Write`] implementor, such as a [`String`] or a
+/// `heapless::String`, together with the [`Precision`] to use for numeric fields.
+/// It implements [`fmt::Write`] itself, so the [`write!`] macro can be used to
+/// write custom content.
+pub struct Encoder<'a> {
+    /// Output the encoded content is written to.
+    output: &'a mut dyn Write,
+
+    /// Numeric precision for encoded fields.
+    precision: Precision,
+}
+
+impl<'a> Encoder<'a> {
+    /// Creates an encoder writing to `output` with the default [`Precision`].
+    pub fn new(output: &'a mut dyn Write) -> Self {
+        Encoder::with_precision(output, Precision::default())
+    }
+
+    /// Creates an encoder writing to `output` with the given [`Precision`].
+    pub fn with_precision(output: &'a mut dyn Write, precision: Precision) -> Self {
+        Encoder { output, precision }
+    }
+
+    /// Returns the numeric precision of the encoder.
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+}
+
+impl Write for Encoder<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.output.write_str(s)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.output.write_char(c)
+    }
+}
+
+/// Trait for encoding types into NMEA 0183 sentence fields.
+///
+/// The `NmeaEncode` trait is the counterpart of [`NmeaParse`](crate::NmeaParse):
+/// it writes a value in the format its parser accepts, so that encoding a parsed
+/// value and parsing it again yields the same value. Implementations are provided
+/// for primitive types, `Option<T>`, `Vec<T>`, arrays, and more types, and you can
+/// implement this trait for your own types.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{Encoder, NmeaEncode};
+///
+/// let mut output = String::new();
+/// let mut encoder = Encoder::new(&mut output);
+///
+/// 42u8.encode(&mut encoder).unwrap();
+/// None::<u8>.encode_preceded(',', &mut encoder).unwrap();
+/// vec![1u8, 2, 3].encode_preceded(',', &mut encoder).unwrap();
+///
+/// assert_eq!(output, "42,,1,2,3");
+/// ```
+///
+/// # Implementing for Custom Types
+///
+/// ```rust
+/// use nmea0183_parser::{Encoder, NmeaEncode};
+/// use std::fmt;
+///
+/// struct MyData {
+///     a: u8,
+///     b: Option<u32>,
+/// }
+///
+/// impl NmeaEncode for MyData {
+///     fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+///         self.a.encode(e)?;
+///         self.b.encode_preceded(',', e)
+///     }
+/// }
+///
+/// let mut output = String::new();
+/// MyData { a: 1, b: None }.encode(&mut Encoder::new(&mut output)).unwrap();
+/// assert_eq!(output, "1,");
+/// ```
+pub trait NmeaEncode {
+    /// Encodes the value into the encoder.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The encoder to write the value to.
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result;
+
+    /// Encodes the value preceded by a separator, such as a comma in NMEA sentences.
+    ///
+    /// This is the counterpart of [`NmeaParse::parse_preceded`](crate::NmeaParse::parse_preceded).
+    /// By default the separator is written before the value, but implementations may
+    /// override this, such as `Vec<T>`, which writes the separator before each element.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator` - The separator to write before the value.
+    /// * `e` - The encoder to write the value to.
+    fn encode_preceded(&self, separator: char, e: &mut Encoder<'_>) -> fmt::Result {
+        e.write_char(separator)?;
+        self.encode(e)
+    }
+}
+
+macro_rules! impl_display_type {
+    ($($t:ty),*) => ($(
+        impl NmeaEncode for $t {
+            fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+                write!(e, "{self}")
+            }
+        }
+    )*)
+}
+
+impl_display_type!(u8, u16, u32, u64, u128, usize);
+impl_display_type!(i8, i16, i32, i64, i128, isize);
+impl_display_type!(char);
+
+macro_rules! impl_float_type {
+    ($($t:ty),*) => ($(
+        impl NmeaEncode for $t {
+            fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+                match e.precision().decimals {
+                    Some(decimals) => write!(e, "{self:.decimals$}"),
+                    None => write!(e, "{self}"),
+                }
+            }
+        }
+    )*)
+}
+
+impl_float_type!(f32, f64);
+
+impl NmeaEncode for str {
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        e.write_str(self)
+    }
+}
+
+impl NmeaEncode for Cow<'_, str> {
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        write_escaped(self, e)
+    }
+}
+
+/// Writes a text field, escaping the reserved characters as `^hh`.
+pub(crate) fn write_escaped(text: &str, e: &mut Encoder<'_>) -> fmt::Result {
+    for c in text.chars() {
+        match c {
+            '\r' | '\n' | '$' | '*' | ',' | '!' | '\\' | '^' | '~' | '\x7f' => {
+                write!(e, "^{:02X}", c as u8)?;
+            }
+            c => e.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+impl<T> NmeaEncode for &T
+where
+    T: NmeaEncode + ?Sized,
+{
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        (**self).encode(e)
+    }
+
+    fn encode_preceded(&self, separator: char, e: &mut Encoder<'_>) -> fmt::Result {
+        (**self).encode_preceded(separator, e)
+    }
+}
+
+impl<T> NmeaEncode for Option<T>
+where
+    T: NmeaEncode,
+{
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        match self {
+            Some(value) => value.encode(e),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T, const N: usize> NmeaEncode for [T; N]
+where
+    T: NmeaEncode,
+{
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        self.as_slice().encode(e)
+    }
+
+    fn encode_preceded(&self, separator: char, e: &mut Encoder<'_>) -> fmt::Result {
+        self.as_slice().encode_preceded(separator, e)
+    }
+}
+
+impl<T> NmeaEncode for [T]
+where
+    T: NmeaEncode,
+{
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        if let Some((first, rest)) = self.split_first() {
+            first.encode(e)?;
+            rest.encode_preceded(',', e)?;
+        }
+        Ok(())
+    }
+
+    fn encode_preceded(&self, separator: char, e: &mut Encoder<'_>) -> fmt::Result {
+        self.iter()
+            .try_for_each(|elem| elem.encode_preceded(separator, e))
+    }
+}
+
+impl<T> NmeaEncode for Vec<T>
+where
+    T: NmeaEncode,
+{
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        self.as_slice().encode(e)
+    }
+
+    fn encode_preceded(&self, separator: char, e: &mut Encoder<'_>) -> fmt::Result {
+        self.as_slice().encode_preceded(separator, e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Encoder, NmeaEncode, Precision};
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_encode_vec() {
+        let values: Vec<Option<u8>> = vec![Some(1), Some(2), None, Some(4)];
+
+        let mut output = String::new();
+        values.encode(&mut Encoder::new(&mut output)).unwrap();
+        assert_eq!(output, "1,2,,4");
+
+        let mut output = String::new();
+        values
+            .encode_preceded(',', &mut Encoder::new(&mut output))
+            .unwrap();
+        assert_eq!(output, ",1,2,,4");
+
+        let mut output = String::new();
+        Vec::<u8>::new()
+            .encode_preceded(',', &mut Encoder::new(&mut output))
+            .unwrap();
+        assert_eq!(output, "");
+    }
+
+    #[test]
+    fn test_encode_text() {
+        let mut output = String::new();
+        Cow::Borrowed("A,B^C*")
+            .encode(&mut Encoder::new(&mut output))
+            .unwrap();
+        assert_eq!(output, "A^2CB^5EC^2A");
+    }
+
+    #[test]
+    fn test_encode_float() {
+        let mut output = String::new();
+        (0.1f32, -45.67f64)
+            .encode_pair(&mut Encoder::new(&mut output))
+            .unwrap();
+        assert_eq!(output, "0.1,-45.67");
+
+        let precision = Precision {
+            decimals: Some(3),
+            ..Precision::default()
+        };
+        let mut output = String::new();
+        (0.1f32, -45.67f64)
+            .encode_pair(&mut Encoder::with_precision(&mut output, precision))
+            .unwrap();
+        assert_eq!(output, "0.100,-45.670");
+    }
+
+    trait EncodePair {
+        fn encode_pair(&self, e: &mut Encoder<'_>) -> std::fmt::Result;
+    }
+
+    impl<A: NmeaEncode, B: NmeaEncode> EncodePair for (A, B) {
+        fn encode_pair(&self, e: &mut Encoder<'_>) -> std::fmt::Result {
+            self.0.encode(e)?;
+            self.1.encode_preceded(',', e)
+        }
+    }
+}
@@ -52,6 +52,14 @@ pub enum Error<I, E> {
     /// The message type that caused the error is provided for reference.
     UnrecognizedMessage(I),
 
+    /// The talker ID of the sentence was rejected by the talker filter.
+    ///
+    /// This variant is returned when a talker filter is configured with
+    /// [`Nmea0183ParserBuilder::talker_filter`](crate::Nmea0183ParserBuilder::talker_filter)
+    /// and the filter does not accept the sentence's talker ID.
+    /// The sentence content is provided for reference.
+    FilteredTalker(I),
+
     /// A field in the NMEA sentence was invalid.
     ///
     /// This error occurs when a specific field in the NMEA sentence does not
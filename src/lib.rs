@@ -50,6 +50,7 @@
 //! - ✅ Built on `nom` combinators
 //! - ✅ Fully pluggable content parser (you bring the domain logic)
 //! - ✅ Optional built-in support for common NMEA sentences
+//! - ✅ Sentence writer with checksum calculation and round-trip stable encoding
 //!
 //! ---
 //!
@@ -131,7 +132,8 @@
 //! 1. **Framing parser** handles the outer structure:
 //!
 //!    - ASCII-only validation
-//!    - Start delimiter (`$`)
+//!    - Optional TAG block (`\s:source,c:time*CC\`)
+//!    - Start delimiter (`$`, or `!` for encapsulated sentences such as AIS)
 //!    - Optional checksum validation (`*CC`)
 //!    - Optional CRLF endings (`\r\n`)
 //!
@@ -147,6 +149,23 @@
 //! data of the sentence. The `Nmea0183ParserBuilder` creates a parser that handles the
 //! framing, while you focus on the content.
 //!
+//! To look at a sentence before committing to a typed structure, use `RawSentence` as the
+//! content parser. It gives access to the talker ID, sentence type and any field without
+//! allocating, and `Nmea0183ParserBuilder::build_raw` also keeps the original span and
+//! checksum of each sentence:
+//!
+//! ```rust
+//! use nmea0183_parser::{IResult, Nmea0183ParserBuilder};
+//!
+//! let mut parser = Nmea0183ParserBuilder::new().build_raw();
+//!
+//! let result: IResult<_, _> = parser("$GPGGA,data*6A\r\n");
+//! let (_, sentence) = result.unwrap();
+//! assert_eq!(sentence.sentence_type(), "GGA");
+//! assert_eq!(sentence.field(0), Some("data"));
+//! assert_eq!(sentence.checksum(), Some(0x6A));
+//! ```
+//!
 //! ---
 //!
 //! ## 🔧 Configuration Options
@@ -251,6 +270,108 @@
 //!
 //! ---
 //!
+//! ## 📡 Decoding Byte Streams
+//!
+//! Serial ports and sockets rarely deliver exactly one sentence per read. The
+//! `FrameDecoder` buffers chunks of any size, splits them into frames, resynchronizes
+//! on the next `$` or `!` after garbage, and runs each complete frame through the same framing
+//! parser, honoring the configured `ChecksumMode` and `LineEndingMode`.
+//!
+//! ```rust
+//! use nmea0183_parser::{IResult, Nmea0183ParserBuilder};
+//!
+//! fn parse_content(input: &[u8]) -> IResult<&[u8], usize> {
+//!     Ok((&input[input.len()..], input.len()))
+//! }
+//!
+//! let mut decoder = Nmea0183ParserBuilder::new().decoder();
+//!
+//! for chunk in [&b"$GPGGA,da"[..], b"ta*6A\r\n$Header,", b"field1,field2*3C\r\n"] {
+//!     decoder.push(chunk);
+//!
+//!     while let Some(result) = decoder.decode(parse_content) {
+//!         match result {
+//!             Ok((_, length)) => println!("Decoded {length} content bytes"),
+//!             Err(e) => println!("Parse error: {:?}", e),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ---
+//!
+//! ## 🏷️ TAG Blocks
+//!
+//! AIS feeds and IEC 61162-450 networks prefix sentences with NMEA 4.10 TAG blocks, such as
+//! `\s:r3669961,c:1241544035*7F\!AIVDM,...`. Set a `TagBlockMode` to accept them: the TAG
+//! block checksum is always validated, and parsers built with `build_tagged` return the typed
+//! source (`s`), UNIX time (`c`), line count (`n`), relative time (`r`), destination (`d`)
+//! and group (`g`) parameters next to the output of the content parser.
+//!
+//! ```rust
+//! use nmea0183_parser::{IResult, Nmea0183ParserBuilder, TagBlockMode, TagGroup};
+//! use nom::Parser;
+//!
+//! fn content_parser(input: &str) -> IResult<&str, &str> {
+//!     Ok(("", input))
+//! }
+//!
+//! let mut parser = Nmea0183ParserBuilder::new()
+//!     .tag_block_mode(TagBlockMode::Required)
+//!     .build_tagged(content_parser);
+//!
+//! let sentence = "\\g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A\\$GPGGA,data*6A\r\n";
+//! let (_, tagged) = parser.parse(sentence).unwrap();
+//! let tag_block = tagged.tag_block.unwrap();
+//!
+//! assert_eq!(tag_block.source, Some("r003669945"));
+//! assert_eq!(tag_block.unix_time, Some(1241544035));
+//! assert_eq!(tag_block.line_count, Some(157036));
+//! assert_eq!(
+//!     tag_block.group,
+//!     Some(TagGroup { sentence_number: 1, total_sentences: 2, group_id: 73874 })
+//! );
+//! assert_eq!(tagged.content, "GPGGA,data");
+//! ```
+//!
+//! ---
+//!
+//! ## ✍️ Writing Sentences
+//!
+//! The `Nmea0183WriterBuilder` mirrors the parser builder: it writes the `$` start delimiter,
+//! or `!` for encapsulated sentences with `StartDelimiter::Encapsulation`, an optional talker
+//! ID, the content produced by a content encoder, and the XOR checksum and CRLF line ending
+//! according to the configured `ChecksumMode` and `LineEndingMode`.
+//!
+//! Content encoders implement the `NmeaEncode` trait, the counterpart of `NmeaParse`. All the
+//! built-in sentences implement it, and the `Precision` of numeric fields is configurable, with
+//! defaults that keep parse-encode round-trips stable.
+//!
+//! ```rust
+//! # #[cfg(feature = "nmea-content")] {
+//! use nmea0183_parser::{
+//!     Nmea0183WriterBuilder, NmeaEncode,
+//!     nmea_content::{NmeaSentence, TalkerId, ZDA},
+//! };
+//!
+//! let mut writer = Nmea0183WriterBuilder::new()
+//!     .talker(TalkerId::Gps.code())
+//!     .build(NmeaSentence::encode);
+//!
+//! let zda = ZDA {
+//!     time: Some(time::Time::from_hms(12, 34, 56).unwrap()),
+//!     date: Some(time::Date::from_calendar_date(2024, time::Month::February, 29).unwrap()),
+//!     utc_offset: Some(time::UtcOffset::UTC),
+//! };
+//!
+//! let mut output = String::new();
+//! writer(&mut output, &NmeaSentence::ZDA(zda)).unwrap();
+//! assert!(output.starts_with("$GPZDA,123456.00,29,02,2024,00,00*"));
+//! # }
+//! ```
+//!
+//! ---
+//!
 //! ## 🧩 `NmeaParse` trait and `#[derive(NmeaParse)]` Macro
 //!
 //! The `NmeaParse` trait provides a generic interface for parsing values from NMEA 0183-style
@@ -378,6 +499,9 @@
 //! For more details on how to use the `NmeaParse` derive macro and customize parsing behavior,
 //! refer to the [documentation](https://docs.rs/nmea0183-parser/latest/nmea0183_parser/derive.NmeaParse.html).
 //!
+//! The `#[derive(NmeaEncode)]` macro generates the matching `NmeaEncode` implementation from the
+//! same attributes, so derived types can be written back with the `Nmea0183WriterBuilder`.
+//!
 //! ---
 //!
 //! ## 🧱 Built-in NMEA Sentence Content Parser
@@ -394,6 +518,7 @@
 //! content parser:
 //!
 //! ```rust
+//! # #[cfg(feature = "nmea-content")] {
 //! use nmea0183_parser::{
 //!     IResult, Nmea0183ParserBuilder, NmeaParse,
 //!     nmea_content::{GGA, Location, NmeaSentence, Quality},
@@ -426,51 +551,200 @@
 //!         ..
 //!     })
 //! ));
+//! # }
+//! ```
+//!
+//! `NmeaSentence` only looks at the sentence type and skips the talker ID. To keep it, parse
+//! into a `Sentence`, which pairs the parsed `NmeaSentence` with its `TalkerId`:
+//!
+//! ```rust
+//! # #[cfg(feature = "nmea-content")] {
+//! use nmea0183_parser::{
+//!     IResult, Nmea0183ParserBuilder, NmeaParse,
+//!     nmea_content::{NmeaSentence, Sentence, TalkerId},
+//! };
+//! use nom::Parser;
+//!
+//! let mut nmea_parser = Nmea0183ParserBuilder::new().build(Sentence::parse);
+//!
+//! let result: IResult<_, _> = nmea_parser.parse("$GLGSV,1,1,00*65\r\n");
+//! let (_, sentence) = result.unwrap();
+//!
+//! match (sentence.talker, sentence.data) {
+//!     (TalkerId::Glonass, NmeaSentence::GSV(gsv)) => println!("GLONASS satellites: {gsv:?}"),
+//!     (talker, data) => println!("{talker:?}: {data:?}"),
+//! }
+//! # }
 //! ```
 //!
-//! > **Note:** While the `Nmea0183ParserBuilder` framing parser can accept both `&str` and `&[u8]`
-//! > inputs, the built-in content parser only accepts `&str`, as it is designed specifically for
-//! > text-based NMEA sentences.
+//! Sentences from unwanted talkers can also be rejected by the framing parser itself with
+//! `Nmea0183ParserBuilder::talker_filter`, before any content parsing takes place.
+//!
+//! Sentence types that `NmeaSentence` does not support fail with `Error::UnrecognizedMessage`.
+//! To process mixed streams, parse into an `AnySentence` instead: supported sentences are
+//! parsed as usual, while unsupported ones become `AnySentence::Unknown`, with their talker
+//! ID, sentence type and fields borrowed from the input.
+//!
+//! Proprietary sentences, whose `$P` address is followed by a manufacturer code instead of a
+//! talker ID, are parsed by `ProprietarySentence`, and become `AnySentence::Proprietary` when
+//! their manufacturer is supported. Each manufacturer is enabled by its own feature flag:
+//!
+//! | Feature Flag | Sentences                                                       |
+//! | ------------ | --------------------------------------------------------------- |
+//! | `garmin`     | Garmin `PGRME`, `PGRMM`, `PGRMO` and `PGRMZ`                    |
+//! | `mediatek`   | MediaTek `PMTK001`, `PMTK220` and `PMTK251`                     |
+//! | `sirf`       | SiRF `PSRF100` and `PSRF103`                                    |
+//! | `ublox`      | u-blox `PUBX,00`, `PUBX,03`, `PUBX,04`, `PUBX,40` and `PUBX,41` |
+//!
+//! Query sentences, such as `$CCGPQ,GGA` where a listener asks the `GP` talker for a `GGA`
+//! sentence, become `AnySentence::Query`. A `nmea_content::Query` is also written by the
+//! framing writer, with `Nmea0183WriterBuilder::new().build(Query::encode)`.
+//!
+//! Messages split across several sentences, such as `GSV` satellite lists, `RTE` routes,
+//! `ALF` alerts, `TXT` texts and multi-sentence AIS `VDM` payloads, are joined by a
+//! `nmea_content::Reassembler`. It buffers the fragments per talker ID and message, accepts
+//! them in any order, restarts a message on duplicate fragments, and drops the fragments older
+//! than its timeout. `HeaplessReassembler` stores a fixed number of fragments for bounded
+//! memory use.
+//!
+//! A `nmea_content::FixAggregator` merges the `GGA`, `RMC`, `GLL`, `GSA`, `VTG` and `ZDA`
+//! sentences of each navigation epoch into a single `NavigationFix`, with the position,
+//! velocity, dilutions of precision, fix quality, used satellites and UTC date and time.
+//!
+//! Both the framing parser and the built-in content parser accept `&str` and `&[u8]` inputs,
+//! so byte buffers, such as the frames of a `FrameDecoder`, are parsed without a UTF-8 conversion:
+//!
+//! ```rust
+//! # #[cfg(feature = "nmea-content")] {
+//! use nmea0183_parser::{IResult, Nmea0183ParserBuilder, NmeaParse, nmea_content::Sentence};
+//! use nom::Parser;
+//!
+//! let mut nmea_parser = Nmea0183ParserBuilder::new().build(Sentence::parse);
+//!
+//! let input: &[u8] = b"$GLGSV,1,1,00*65\r\n";
+//! let result: IResult<_, _> = nmea_parser.parse(input);
+//! assert!(result.is_ok());
+//! # }
+//! ```
 //!
 //! ### Supported NMEA Sentences
 //!
+//! - [`ACN`](https://gpsd.gitlab.io/gpsd/NMEA.html#_acn_alert_command) - Alert Command
+//! - [`ALC`](https://gpsd.gitlab.io/gpsd/NMEA.html#_alc_cyclic_alert_list) - Cyclic Alert List
+//! - [`ALF`](https://gpsd.gitlab.io/gpsd/NMEA.html#_alf_alert_sentence) - Alert Sentence
+//! - [`ALR`](https://gpsd.gitlab.io/gpsd/NMEA.html#_alr_set_alarm_state) - Set Alarm State
+//! - [`APB`](https://gpsd.gitlab.io/gpsd/NMEA.html#_apb_autopilot_sentence_b) - Autopilot Sentence "B"
+//! - [`ARC`](https://gpsd.gitlab.io/gpsd/NMEA.html#_arc_alert_command_refused) - Alert Command Refused
+//! - [`BOD`](https://gpsd.gitlab.io/gpsd/NMEA.html#_bod_bearing_waypoint_to_waypoint) - Bearing: Waypoint to Waypoint
+//! - [`BWC`](https://gpsd.gitlab.io/gpsd/NMEA.html#_bwc_bearing_distance_to_waypoint_great_circle) - Bearing & Distance to Waypoint: Great Circle
+//! - [`BWR`](https://gpsd.gitlab.io/gpsd/NMEA.html#_bwr_bearing_and_distance_to_waypoint_rhumb_line) - Bearing and Distance to Waypoint: Rhumb Line
 //! - [`DBT`](https://gpsd.gitlab.io/gpsd/NMEA.html#_dbt_depth_below_transducer) - Depth Below Transducer
 //! - [`DPT`](https://gpsd.gitlab.io/gpsd/NMEA.html#_dpt_depth_of_water) - Depth of Water
+//! - [`DSC`](https://gpsd.gitlab.io/gpsd/NMEA.html#_dsc_digital_selective_calling_information) - Digital Selective Calling Information
+//! - [`DSE`](https://gpsd.gitlab.io/gpsd/NMEA.html#_dse_expanded_digital_selective_calling) - Expanded Digital Selective Calling
+//! - [`DTM`](https://gpsd.gitlab.io/gpsd/NMEA.html#_dtm_datum_reference) - Datum Reference
+//! - [`GBS`](https://gpsd.gitlab.io/gpsd/NMEA.html#_gbs_gps_satellite_fault_detection) - GPS Satellite Fault Detection
 //! - [`GGA`](https://gpsd.gitlab.io/gpsd/NMEA.html#_gga_global_positioning_system_fix_data) - Global Positioning System Fix Data
 //! - [`GLL`](https://gpsd.gitlab.io/gpsd/NMEA.html#_gll_geographic_position_latitudelongitude) - Geographic Position: Latitude/Longitude
+//! - [`GNS`](https://gpsd.gitlab.io/gpsd/NMEA.html#_gns_fix_data) - Fix data
+//! - [`GRS`](https://gpsd.gitlab.io/gpsd/NMEA.html#_grs_gps_range_residuals) - GPS Range Residuals
 //! - [`GSA`](https://gpsd.gitlab.io/gpsd/NMEA.html#_gsa_gps_dop_and_active_satellites) - GPS DOP and Active Satellites
+//! - [`GST`](https://gpsd.gitlab.io/gpsd/NMEA.html#_gst_gps_pseudorange_noise_statistics) - GPS Pseudorange Noise Statistics
 //! - [`GSV`](https://gpsd.gitlab.io/gpsd/NMEA.html#_gsv_satellites_in_view) - Satellites in View
+//! - [`HDG`](https://gpsd.gitlab.io/gpsd/NMEA.html#_hdg_heading_deviation_variation) - Heading: Deviation & Variation
+//! - [`HDM`](https://gpsd.gitlab.io/gpsd/NMEA.html#_hdm_heading_magnetic) - Heading: Magnetic
+//! - [`HDT`](https://gpsd.gitlab.io/gpsd/NMEA.html#_hdt_heading_true) - Heading: True
+//! - [`HSC`](https://gpsd.gitlab.io/gpsd/NMEA.html#_hsc_heading_steering_command) - Heading Steering Command
+//! - [`MDA`](https://gpsd.gitlab.io/gpsd/NMEA.html#_mda_meteorological_composite) - Meteorological Composite
+//! - [`MTW`](https://gpsd.gitlab.io/gpsd/NMEA.html#_mtw_mean_temperature_of_water) - Mean Temperature of Water
+//! - [`MWD`](https://gpsd.gitlab.io/gpsd/NMEA.html#_mwd_wind_direction_speed) - Wind Direction & Speed
+//! - [`MWV`](https://gpsd.gitlab.io/gpsd/NMEA.html#_mwv_wind_speed_and_angle) - Wind Speed and Angle
+//! - [`OSD`](https://gpsd.gitlab.io/gpsd/NMEA.html#_osd_own_ship_data) - Own Ship Data
+//! - [`RMB`](https://gpsd.gitlab.io/gpsd/NMEA.html#_rmb_recommended_minimum_navigation_information) - Recommended Minimum Navigation Information
 //! - [`RMC`](https://gpsd.gitlab.io/gpsd/NMEA.html#_rmc_recommended_minimum_navigation_information) - Recommended Minimum Navigation Information
+//! - [`ROT`](https://gpsd.gitlab.io/gpsd/NMEA.html#_rot_rate_of_turn) - Rate Of Turn
+//! - [`RPM`](https://gpsd.gitlab.io/gpsd/NMEA.html#_rpm_revolutions) - Revolutions
+//! - [`RSA`](https://gpsd.gitlab.io/gpsd/NMEA.html#_rsa_rudder_sensor_angle) - Rudder Sensor Angle
+//! - [`RSD`](https://gpsd.gitlab.io/gpsd/NMEA.html#_rsd_radar_system_data) - Radar System Data
+//! - [`RTE`](https://gpsd.gitlab.io/gpsd/NMEA.html#_rte_routes) - Routes
+//! - [`THS`](https://gpsd.gitlab.io/gpsd/NMEA.html#_ths_true_heading_and_status) - True Heading and Status (NMEA 3.0)
+//! - [`TLL`](https://gpsd.gitlab.io/gpsd/NMEA.html#_tll_target_latitude_and_longitude) - Target Latitude and Longitude
+//! - [`TTM`](https://gpsd.gitlab.io/gpsd/NMEA.html#_ttm_tracked_target_message) - Tracked Target Message
+//! - [`TXT`](https://gpsd.gitlab.io/gpsd/NMEA.html#_txt_text_transmission) - Text Transmission
+//! - [`VBW`](https://gpsd.gitlab.io/gpsd/NMEA.html#_vbw_dual_groundwater_speed) - Dual Ground/Water Speed
+//! - [`VDM`/`VDO`](https://gpsd.gitlab.io/gpsd/AIVDM.html#_aivdmaivdo_sentence_layer) - AIS VHF Data-link Message, decoded by the `nmea_content::ais` module
+//! - [`VDR`](https://gpsd.gitlab.io/gpsd/NMEA.html#_vdr_set_and_drift) - Set and Drift
+//! - [`VHW`](https://gpsd.gitlab.io/gpsd/NMEA.html#_vhw_water_speed_and_heading) - Water Speed and Heading
+//! - [`VLW`](https://gpsd.gitlab.io/gpsd/NMEA.html#_vlw_distance_traveled_through_water) - Distance Traveled through Water
 //! - [`VTG`](https://gpsd.gitlab.io/gpsd/NMEA.html#_vtg_track_made_good_and_ground_speed) - Track made good and Ground speed
+//! - [`VWR`](https://gpsd.gitlab.io/gpsd/NMEA.html#_vwr_relative_wind_speed_and_angle) - Relative Wind Speed and Angle
+//! - [`VWT`](https://gpsd.gitlab.io/gpsd/NMEA.html#_vwt_true_wind_speed_and_angle) - True Wind Speed and Angle
+//! - [`WPL`](https://gpsd.gitlab.io/gpsd/NMEA.html#_wpl_waypoint_location) - Waypoint Location
+//! - [`XDR`](https://gpsd.gitlab.io/gpsd/NMEA.html#_xdr_transducer_measurement) - Transducer Measurement
+//! - [`XTE`](https://gpsd.gitlab.io/gpsd/NMEA.html#_xte_cross_track_error_measured) - Cross-Track Error, Measured
 //! - [`ZDA`](https://gpsd.gitlab.io/gpsd/NMEA.html#_zda_time_date_utc_day_month_year_and_local_time_zone) - Time & Date: UTC, day, month, year and local time zone
 //!
 //! ### NMEA Version Support
 //!
 //! Different NMEA versions may include additional fields in certain sentence types.
-//! You can choose the version that matches your equipment by enabling the appropriate feature flags.
-//!
-//! | Feature Flag   | NMEA Version | When to Use                |
-//! | -------------- | ------------ | -------------------------- |
-//! | `nmea-content` | Pre-2.3      | Standard NMEA parsing      |
-//! | `nmea-v2-3`    | NMEA 2.3     | Older GPS/marine equipment |
-//! | `nmea-v3-0`    | NMEA 3.0     | Mid-range equipment        |
-//! | `nmea-v4-11`   | NMEA 4.11    | Modern equipment           |
-//!
 //! For specific field differences between versions, please refer to the
 //! [NMEA 0183 standard documentation](https://gpsd.gitlab.io/gpsd/NMEA.html).
+//! The `nmea-v2-3`, `nmea-v3-0` and `nmea-v4-11` features are kept for compatibility and only
+//! enable `nmea-content`.
+//!
+//! The fields of all versions are always compiled in, and `NmeaParse::parse` accepts the
+//! sentences of every version, the fields added after NMEA 2.0 being optional. The fields
+//! expected from each device are selected at runtime with a `VersionRange`, either by parsing
+//! with `NmeaParse::parse_version` or by setting it on the `Nmea0183ParserBuilder`: the fields
+//! of the versions up to its minimum are required, the fields of the versions after its
+//! maximum are forbidden, and the fields in between are optional. This way a single program
+//! talks to both older and newer equipment:
+//!
+//! ```rust
+//! # #[cfg(feature = "nmea-content")] {
+//! use nmea0183_parser::{
+//!     IResult, Nmea0183ParserBuilder, NmeaParse, NmeaVersion, VersionRange,
+//!     nmea_content::{NmeaSentence, Sentence},
+//! };
+//! use nom::Parser;
+//!
+//! // NMEA 2.3 device, sending the FAA mode indicator but no navigation status
+//! let mut parser = Nmea0183ParserBuilder::new()
+//!     .version(VersionRange::from(NmeaVersion::V2_3))
+//!     .build_version(Sentence::parse_version);
+//!
+//! let result: IResult<_, _> = parser
+//!     .parse("$GPRMC,001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A*65\r\n");
+//! let (_, sentence) = result.unwrap();
+//! let NmeaSentence::RMC(rmc) = sentence.data else {
+//!     panic!("Expected an RMC sentence");
+//! };
+//! assert!(rmc.faa_mode.is_some());
+//! assert_eq!(rmc.nav_status, None);
+//! # }
+//! ```
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+mod encode;
 mod error;
 mod nmea0183;
 #[cfg(feature = "nmea-content")]
 #[cfg_attr(docsrs, doc(cfg(feature = "nmea-content")))]
 pub mod nmea_content;
 mod parse;
+mod version;
 
+pub use encode::{Encoder, NmeaEncode, Precision};
 pub use error::{Error, IResult};
-pub use nmea0183::{ChecksumMode, LineEndingMode, Nmea0183ParserBuilder};
+pub use nmea0183::{
+    AllTalkers, ChecksumMode, DEFAULT_MAX_FRAME_LENGTH, Fields, FrameDecoder, LineEndingMode,
+    Nmea0183ParserBuilder, Nmea0183WriterBuilder, RawSentence, StartDelimiter, TagBlock,
+    TagBlockMode, TagGroup, Tagged, TalkerFilter,
+};
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
-pub use nmea0183_derive::NmeaParse;
+pub use nmea0183_derive::{NmeaEncode, NmeaParse};
+pub(crate) use parse::AsStr;
 pub use parse::NmeaParse;
+pub use version::{FieldMode, NmeaVersion, VersionRange};
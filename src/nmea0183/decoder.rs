@@ -0,0 +1,380 @@
+//! # Incremental NMEA 0183 Frame Decoder
+//!
+//! This module provides a stateful decoder for NMEA 0183-style byte streams.
+//! Serial ports and sockets deliver data in arbitrary chunks, so a single
+//! sentence may be split across several reads, and a single read may contain
+//! several sentences (or garbage between them).
+//!
+//! The [`FrameDecoder`] buffers incoming bytes, splits them into frames, and
+//! hands each complete frame to the framing parser built by
+//! [`Nmea0183ParserBuilder`], so the configured [`ChecksumMode`] and
+//! [`LineEndingMode`] apply to every frame exactly as they do for single
+//! sentences.
+//!
+//! [`ChecksumMode`]: crate::ChecksumMode
+
+use std::ops::Range;
+
+use nom::{Parser, error::ParseError};
+
+use crate::{
+    AllTalkers, Error, IResult, LineEndingMode, Nmea0183ParserBuilder, TagBlockMode, Tagged,
+    TalkerFilter, VersionRange,
+};
+
+/// Default upper bound for the length of a buffered frame, in bytes.
+///
+/// The NMEA 0183 standard limits a sentence to 82 characters, but many receivers
+/// emit longer proprietary sentences, so the default leaves some headroom.
+pub const DEFAULT_MAX_FRAME_LENGTH: usize = 256;
+
+/// A stateful decoder that extracts NMEA 0183-style frames from a byte stream.
+///
+/// Bytes are fed to the decoder with [`FrameDecoder::push`] in chunks of any size.
+/// Complete frames are then retrieved one by one, either raw with
+/// [`FrameDecoder::next_frame`], or already run through the framing parser and a
+/// content parser with [`FrameDecoder::decode`] and [`FrameDecoder::decode_str`].
+/// The NMEA versions set with [`Nmea0183ParserBuilder::version`] are passed to the
+/// content parser by [`FrameDecoder::decode_version`] and [`FrameDecoder::decode_str_version`].
+///
+/// ## Frame boundaries
+///
+/// A frame always starts with the `$` or `!` start delimiter, or with the `\` opening
+/// a TAG block unless the [`TagBlockMode`] is [`TagBlockMode::Forbidden`]; anything
+/// before it is discarded. Where the frame ends depends on the configured
+/// [`LineEndingMode`]:
+///
+/// - [`LineEndingMode::Required`]: the frame ends after the first `\r\n`.
+///   If another start delimiter shows up before the line ending, the current frame
+///   was truncated; it is yielded as is (so that the framing parser reports it) and
+///   decoding resynchronizes on the new start delimiter.
+/// - [`LineEndingMode::Forbidden`]: the frame ends right before the next start delimiter.
+///   Since the last frame of a stream has no successor, call
+///   [`FrameDecoder::finish`] once the stream is exhausted to flush it.
+///
+/// If no frame boundary is found within [`FrameDecoder::max_frame_length`] bytes,
+/// the buffered frame is dropped and decoding resynchronizes on the next start delimiter.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, Nmea0183ParserBuilder};
+///
+/// fn content_parser(input: &str) -> IResult<&str, Vec<&str>> {
+///     Ok(("", input.split(',').collect()))
+/// }
+///
+/// let mut decoder = Nmea0183ParserBuilder::new().decoder();
+///
+/// // A sentence split across two reads, preceded by some line noise
+/// decoder.push(b"\x00noise$Header,fie");
+/// assert!(decoder.decode_str(content_parser).is_none());
+///
+/// decoder.push(b"ld1,field2*3C\r\n$Header");
+/// let (_, fields) = decoder.decode_str(content_parser).unwrap().unwrap();
+/// assert_eq!(fields, vec!["Header", "field1", "field2"]);
+///
+/// // The start of the next sentence stays buffered until it is complete
+/// assert!(decoder.decode_str(content_parser).is_none());
+/// assert_eq!(decoder.buffered(), 7);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameDecoder<T = AllTalkers> {
+    /// Framing parser configuration applied to every frame.
+    builder: Nmea0183ParserBuilder<T>,
+
+    /// Bytes received but not yet consumed.
+    buffer: Vec<u8>,
+
+    /// Length of the frame yielded by the previous call, removed on the next call.
+    consumed: usize,
+
+    /// Maximum length of a buffered frame before it is dropped.
+    max_frame_length: usize,
+
+    /// Whether the end of the stream was signaled with [`FrameDecoder::finish`].
+    finished: bool,
+}
+
+impl<T> FrameDecoder<T> {
+    /// Creates a new frame decoder using the given framing parser configuration.
+    ///
+    /// This is equivalent to calling [`Nmea0183ParserBuilder::decoder`].
+    pub fn new(builder: Nmea0183ParserBuilder<T>) -> Self {
+        FrameDecoder {
+            builder,
+            buffer: Vec::with_capacity(DEFAULT_MAX_FRAME_LENGTH),
+            consumed: 0,
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+            finished: false,
+        }
+    }
+
+    /// Sets the maximum length of a single frame, in bytes.
+    ///
+    /// Defaults to [`DEFAULT_MAX_FRAME_LENGTH`].
+    pub fn max_frame_length(mut self, length: usize) -> Self {
+        self.max_frame_length = length;
+        self
+    }
+
+    /// Appends a chunk of bytes received from the stream.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+        self.finished = false;
+    }
+
+    /// Signals that the stream is exhausted.
+    ///
+    /// Any remaining buffered bytes starting with a start delimiter are yielded as a final frame
+    /// by the next call to [`FrameDecoder::next_frame`] or one of the decode methods.
+    /// Pushing more bytes after this call resumes normal decoding.
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    /// Returns the number of buffered bytes that were not yielded as a frame yet.
+    pub fn buffered(&self) -> usize {
+        self.buffer.len() - self.consumed
+    }
+
+    /// Discards all buffered bytes.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.consumed = 0;
+        self.finished = false;
+    }
+
+    /// Returns the next complete raw frame, including its start delimiter,
+    /// checksum and line ending, or [`None`] if more bytes are needed.
+    pub fn next_frame(&mut self) -> Option<&[u8]> {
+        let range = self.next_frame_range()?;
+        Some(&self.buffer[range])
+    }
+
+    /// Extracts the next complete frame and parses it with the framing parser,
+    /// using `content_parser` for the frame content.
+    ///
+    /// Returns [`None`] if more bytes are needed to complete a frame.
+    pub fn decode<'a, O, F, E>(
+        &'a mut self,
+        mut content_parser: F,
+    ) -> Option<IResult<&'a [u8], O, E>>
+    where
+        F: Parser<&'a [u8], Output = O, Error = Error<&'a [u8], E>>,
+        E: ParseError<&'a [u8]>,
+        T: TalkerFilter,
+    {
+        let range = self.next_frame_range()?;
+        let frame = &self.buffer[range];
+
+        Some(
+            self.builder
+                .parse_frame(&mut content_parser, frame)
+                .map(|(i, tagged)| (i, tagged.content)),
+        )
+    }
+
+    /// Extracts the next complete frame and parses it with the framing parser,
+    /// using `content_parser` for the frame content.
+    ///
+    /// This is the `&str` counterpart of [`FrameDecoder::decode`]. A frame that is
+    /// not valid UTF-8 is reported as [`Error::NonAscii`].
+    ///
+    /// Returns [`None`] if more bytes are needed to complete a frame.
+    pub fn decode_str<'a, O, F, E>(
+        &'a mut self,
+        mut content_parser: F,
+    ) -> Option<IResult<&'a str, O, E>>
+    where
+        F: Parser<&'a str, Output = O, Error = Error<&'a str, E>>,
+        E: ParseError<&'a str>,
+        T: TalkerFilter,
+    {
+        let range = self.next_frame_range()?;
+
+        match std::str::from_utf8(&self.buffer[range]) {
+            Ok(frame) => Some(
+                self.builder
+                    .parse_frame(&mut content_parser, frame)
+                    .map(|(i, tagged)| (i, tagged.content)),
+            ),
+            Err(_) => Some(Err(nom::Err::Error(Error::NonAscii))),
+        }
+    }
+
+    /// Extracts the next complete frame and parses it with the framing parser,
+    /// using `content_parser` for the frame content and the configured NMEA versions.
+    ///
+    /// This is the [`Nmea0183ParserBuilder::build_version`] counterpart of
+    /// [`FrameDecoder::decode`], `content_parser` is called with the versions set by
+    /// [`Nmea0183ParserBuilder::version`].
+    ///
+    /// Returns [`None`] if more bytes are needed to complete a frame.
+    pub fn decode_version<'a, O, F, E>(
+        &'a mut self,
+        mut content_parser: F,
+    ) -> Option<IResult<&'a [u8], O, E>>
+    where
+        F: FnMut(&'a [u8], VersionRange) -> IResult<&'a [u8], O, E>,
+        E: ParseError<&'a [u8]>,
+        T: TalkerFilter,
+    {
+        let versions = self.builder.versions;
+
+        self.decode(move |i: &'a [u8]| content_parser(i, versions))
+    }
+
+    /// Extracts the next complete frame and parses it with the framing parser,
+    /// using `content_parser` for the frame content and the configured NMEA versions.
+    ///
+    /// This is the `&str` counterpart of [`FrameDecoder::decode_version`]. A frame that
+    /// is not valid UTF-8 is reported as [`Error::NonAscii`].
+    ///
+    /// Returns [`None`] if more bytes are needed to complete a frame.
+    pub fn decode_str_version<'a, O, F, E>(
+        &'a mut self,
+        mut content_parser: F,
+    ) -> Option<IResult<&'a str, O, E>>
+    where
+        F: FnMut(&'a str, VersionRange) -> IResult<&'a str, O, E>,
+        E: ParseError<&'a str>,
+        T: TalkerFilter,
+    {
+        let versions = self.builder.versions;
+
+        self.decode_str(move |i: &'a str| content_parser(i, versions))
+    }
+
+    /// Extracts the next complete frame and parses it with the framing parser,
+    /// using `content_parser` for the frame content, keeping its TAG block.
+    ///
+    /// This is the [`Nmea0183ParserBuilder::build_tagged`] counterpart of
+    /// [`FrameDecoder::decode`].
+    ///
+    /// Returns [`None`] if more bytes are needed to complete a frame.
+    #[allow(clippy::type_complexity)]
+    pub fn decode_tagged<'a, O, F, E>(
+        &'a mut self,
+        mut content_parser: F,
+    ) -> Option<IResult<&'a [u8], Tagged<&'a [u8], O>, E>>
+    where
+        F: Parser<&'a [u8], Output = O, Error = Error<&'a [u8], E>>,
+        E: ParseError<&'a [u8]>,
+        T: TalkerFilter,
+    {
+        let range = self.next_frame_range()?;
+        let frame = &self.buffer[range];
+
+        Some(self.builder.parse_frame(&mut content_parser, frame))
+    }
+
+    /// Extracts the next complete frame and parses it with the framing parser,
+    /// using `content_parser` for the frame content, keeping its TAG block.
+    ///
+    /// This is the `&str` counterpart of [`FrameDecoder::decode_tagged`]. A frame
+    /// that is not valid UTF-8 is reported as [`Error::NonAscii`].
+    ///
+    /// Returns [`None`] if more bytes are needed to complete a frame.
+    pub fn decode_str_tagged<'a, O, F, E>(
+        &'a mut self,
+        mut content_parser: F,
+    ) -> Option<IResult<&'a str, Tagged<&'a str, O>, E>>
+    where
+        F: Parser<&'a str, Output = O, Error = Error<&'a str, E>>,
+        E: ParseError<&'a str>,
+        T: TalkerFilter,
+    {
+        let range = self.next_frame_range()?;
+
+        match std::str::from_utf8(&self.buffer[range]) {
+            Ok(frame) => Some(self.builder.parse_frame(&mut content_parser, frame)),
+            Err(_) => Some(Err(nom::Err::Error(Error::NonAscii))),
+        }
+    }
+
+    /// Locates the next frame in the buffer, dropping the previously yielded frame
+    /// and any garbage preceding the next start delimiter.
+    fn next_frame_range(&mut self) -> Option<Range<usize>> {
+        self.buffer.drain(..self.consumed);
+        self.consumed = 0;
+
+        let tag_blocks = self.builder.tag_block_mode != TagBlockMode::Forbidden;
+
+        loop {
+            match self
+                .buffer
+                .iter()
+                .position(|&b| is_start_delimiter(b, tag_blocks))
+            {
+                Some(start) => {
+                    self.buffer.drain(..start);
+                }
+                None => {
+                    self.buffer.clear();
+                    return None;
+                }
+            }
+
+            // The `$` or `!` following a TAG block belongs to the same frame, so the
+            // next start delimiter is searched after the closing `\`
+            let sentence_start = if self.buffer[0] == b'\\' {
+                self.buffer[1..]
+                    .iter()
+                    .position(|&b| b == b'\\')
+                    .map(|position| position + 2)
+            } else {
+                Some(0)
+            };
+
+            let next_start = sentence_start.and_then(|sentence_start| {
+                let from = sentence_start + 1;
+                self.buffer
+                    .get(from..)?
+                    .iter()
+                    .position(|&b| is_start_delimiter(b, tag_blocks))
+                    .map(|position| position + from)
+            });
+
+            let end = match self.builder.line_ending_mode {
+                LineEndingMode::Required => self.buffer[..next_start.unwrap_or(self.buffer.len())]
+                    .windows(2)
+                    .position(|window| window == b"\r\n")
+                    .map(|position| position + 2)
+                    .or(next_start),
+                LineEndingMode::Forbidden => next_start,
+            };
+
+            let end = match end {
+                Some(end) if end <= self.max_frame_length => end,
+                Some(_) => {
+                    self.buffer.drain(..next_start.unwrap_or(self.buffer.len()));
+                    continue;
+                }
+                None if self.buffer.len() > self.max_frame_length => {
+                    self.buffer.drain(..1);
+                    continue;
+                }
+                None if self.finished => {
+                    self.finished = false;
+                    self.buffer.len()
+                }
+                None => return None,
+            };
+
+            self.consumed = end;
+            return Some(0..end);
+        }
+    }
+}
+
+impl Default for FrameDecoder {
+    fn default() -> Self {
+        FrameDecoder::new(Nmea0183ParserBuilder::new())
+    }
+}
+
+/// Returns whether the byte starts a new frame.
+fn is_start_delimiter(byte: u8, tag_blocks: bool) -> bool {
+    byte == b'$' || byte == b'!' || (tag_blocks && byte == b'\\')
+}
@@ -0,0 +1,60 @@
+//! # Sentence Fields
+//!
+//! This module provides an iterator over the comma-separated fields of a sentence,
+//! which borrows each field from the input without copying.
+
+use nom::{AsChar, Input};
+
+/// Iterator over the comma-separated fields of a sentence.
+///
+/// Each field is a slice of the input; empty fields, such as the ones between two
+/// consecutive commas, are yielded as empty slices.
+///
+/// ```rust
+/// use nmea0183_parser::Fields;
+///
+/// let fields: Vec<_> = Fields::new("1,,A").collect();
+/// assert_eq!(fields, ["1", "", "A"]);
+///
+/// assert_eq!(Fields::<&str>::empty().count(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fields<I> {
+    /// Fields not yet yielded, [`None`] once the last field was yielded
+    rest: Option<I>,
+}
+
+impl<I> Fields<I> {
+    /// Creates an iterator over the fields of `input`.
+    ///
+    /// An empty input holds a single empty field.
+    pub fn new(input: I) -> Self {
+        Fields { rest: Some(input) }
+    }
+
+    /// Creates an iterator over no fields at all.
+    pub fn empty() -> Self {
+        Fields { rest: None }
+    }
+}
+
+impl<I> Iterator for Fields<I>
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<I> {
+        let rest = self.rest.take()?;
+
+        match rest.position(|item| item.as_char() == ',') {
+            Some(index) => {
+                let (tail, field) = rest.take_split(index);
+                self.rest = Some(tail.take_from(1));
+                Some(field)
+            }
+            None => Some(rest),
+        }
+    }
+}
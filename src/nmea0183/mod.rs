@@ -1,24 +1,43 @@
 //! # NMEA 0183 Message Parser
 //!
 //! This module provides the main parsing functionality for NMEA 0183-style messages.
-//! It handles the standard NMEA 0183 format: `$HHH,D1,D2,...,Dn*CC\r\n`
+//! It handles the standard NMEA 0183 format: `$HHH,D1,D2,...,Dn*CC\r\n`, as well as
+//! encapsulated sentences starting with `!`, such as AIS `!AIVDM` sentences.
 //!
 //! The parser is configurable to handle variations in:
 //! - Checksum requirements (required or optional)
 //! - Line ending requirements (CRLF required or forbidden)
+//! - NMEA 4.10 TAG blocks (forbidden, optional or required)
+//!
+//! The [`FrameDecoder`] builds on the same framing parser to decode byte streams
+//! that deliver sentences in arbitrary chunks.
 
 use nom::{
     AsBytes, AsChar, Compare, Err, FindSubstring, Input, Parser,
     branch::alt,
     bytes::complete::{tag, take, take_until},
-    character::complete::{char, hex_digit0},
+    character::complete::{char, hex_digit0, one_of},
     combinator::{opt, rest, rest_len, verify},
     error::{ErrorKind, ParseError},
     number::complete::hex_u32,
     sequence::terminated,
 };
 
-use crate::{Error, IResult};
+#[cfg(feature = "nmea-content")]
+use crate::nmea_content::TalkerId;
+use crate::{Error, IResult, VersionRange};
+
+mod decoder;
+mod fields;
+mod raw;
+mod tag_block;
+mod writer;
+
+pub use decoder::{DEFAULT_MAX_FRAME_LENGTH, FrameDecoder};
+pub use fields::Fields;
+pub use raw::RawSentence;
+pub use tag_block::{TagBlock, TagGroup, Tagged};
+pub use writer::{Nmea0183WriterBuilder, StartDelimiter};
 
 /// Defines how the parser should handle NMEA message checksums.
 ///
@@ -74,6 +93,67 @@ pub enum LineEndingMode {
     Forbidden,
 }
 
+/// Defines how the parser should handle NMEA 4.10 TAG blocks.
+///
+/// A TAG block prefixes the sentence with `\code:value,...*CC\` parameters, such as
+/// the source station and the UNIX time, and carries its own `*CC` checksum.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum TagBlockMode {
+    #[default]
+    /// TAG blocks are not expected.
+    ///
+    /// The parser will fail if the message starts with a TAG block instead of `$` or `!`.
+    ///
+    /// Use this mode for plain NMEA 0183 sources such as GNSS receivers.
+    Forbidden,
+
+    /// TAG block is optional but will be validated if present.
+    ///
+    /// The parser will accept messages both with and without a TAG block:
+    /// - If no TAG block is present, parsing continues normally
+    /// - If a TAG block is present, its checksum must be valid or parsing will fail
+    ///
+    /// Use this mode when working with network feeds that only tag some sentences.
+    Optional,
+
+    /// TAG block is required and must be present.
+    ///
+    /// The parser will fail if the message does not start with a valid TAG block.
+    ///
+    /// Use this mode for IEC 61162-450 networks or AIS feeds that tag every sentence.
+    Required,
+}
+
+/// Filter applied by the framing parser to the talker ID of each sentence.
+///
+/// [`AllTalkers`], the default filter of [`Nmea0183ParserBuilder`], accepts every sentence.
+/// With the `nmea-content` feature, any `FnMut(TalkerId) -> bool` closure is a talker filter,
+/// see [`Nmea0183ParserBuilder::talker_filter`].
+pub trait TalkerFilter {
+    /// Returns whether the sentence whose address field starts with `talker` is parsed.
+    fn accept(&mut self, talker: [u8; 2]) -> bool;
+}
+
+/// Talker filter accepting every sentence, the default of [`Nmea0183ParserBuilder`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllTalkers;
+
+impl TalkerFilter for AllTalkers {
+    fn accept(&mut self, _talker: [u8; 2]) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "nmea-content")]
+impl<F> TalkerFilter for F
+where
+    F: FnMut(TalkerId) -> bool,
+{
+    fn accept(&mut self, talker: [u8; 2]) -> bool {
+        self(TalkerId::from(talker))
+    }
+}
+
 /// Creates a configurable NMEA 0183-style parser factory.
 ///
 /// This struct allows you to configure the NMEA 0183 framing parser with different
@@ -144,12 +224,24 @@ pub enum LineEndingMode {
 /// assert!(lenient_parser.parse("$GPGGA,data\r\n").is_err()); // (CRLF present)
 /// ```
 #[must_use]
-pub struct Nmea0183ParserBuilder {
+#[derive(Debug, Clone)]
+pub struct Nmea0183ParserBuilder<T = AllTalkers> {
     /// Checksum mode for the parser.
     checksum_mode: ChecksumMode,
 
     /// Line ending mode for the parser.
     line_ending_mode: LineEndingMode,
+
+    /// Filter applied to the talker ID of each sentence.
+    talker_filter: T,
+
+    /// TAG block mode for the parser.
+    tag_block_mode: TagBlockMode,
+
+    /// NMEA versions passed to the content parser by [`build_version`].
+    ///
+    /// [`build_version`]: Nmea0183ParserBuilder::build_version
+    versions: VersionRange,
 }
 
 impl Nmea0183ParserBuilder {
@@ -158,13 +250,21 @@ impl Nmea0183ParserBuilder {
     /// The default settings are:
     /// - Checksum mode: [`ChecksumMode::Required`]
     /// - Line ending mode: [`LineEndingMode::Required`]
+    /// - Talker filter: [`AllTalkers`], all talkers are accepted
+    /// - TAG block mode: [`TagBlockMode::Forbidden`]
+    /// - Versions: [`VersionRange::default()`], every NMEA version is accepted
     pub fn new() -> Self {
         Nmea0183ParserBuilder {
             checksum_mode: ChecksumMode::Required,
             line_ending_mode: LineEndingMode::Required,
+            talker_filter: AllTalkers,
+            tag_block_mode: TagBlockMode::Forbidden,
+            versions: VersionRange::default(),
         }
     }
+}
 
+impl<T> Nmea0183ParserBuilder<T> {
     /// Sets the checksum mode for the parser.
     ///
     /// # Arguments
@@ -189,6 +289,110 @@ impl Nmea0183ParserBuilder {
         self
     }
 
+    /// Sets a filter for the talker ID of parsed sentences.
+    ///
+    /// The filter receives the [`TalkerId`] of the first two characters of the address
+    /// field and returns whether the sentence should be parsed. Sentences rejected by the
+    /// filter fail with [`Error::FilteredTalker`] before the content parser is called.
+    ///
+    /// The filter is called once for each sentence, so it can depend on runtime state, such
+    /// as a set of talkers loaded from a configuration, or route sentences on the side.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Closure returning `true` for talker IDs to accept
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{Error, IResult, Nmea0183ParserBuilder, nmea_content::TalkerId};
+    /// use nom::Parser;
+    ///
+    /// fn content_parser(i: &str) -> IResult<&str, bool> {
+    ///     Ok((i, true))
+    /// }
+    ///
+    /// let allowed = vec![TalkerId::Gnss, TalkerId::Other(*b"II")];
+    /// let mut parser = Nmea0183ParserBuilder::new()
+    ///     .talker_filter(|talker| allowed.contains(&talker))
+    ///     .build(content_parser);
+    ///
+    /// assert!(parser.parse("$GNGGA,data*74\r\n").is_ok());
+    /// assert_eq!(
+    ///     parser.parse("$GPGGA,data*6A\r\n"),
+    ///     Err(nom::Err::Error(Error::FilteredTalker("GPGGA,data")))
+    /// );
+    /// ```
+    ///
+    /// [`TalkerId`]: crate::nmea_content::TalkerId
+    #[cfg(feature = "nmea-content")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-content")))]
+    pub fn talker_filter<F>(self, filter: F) -> Nmea0183ParserBuilder<F>
+    where
+        F: FnMut(TalkerId) -> bool,
+    {
+        Nmea0183ParserBuilder {
+            checksum_mode: self.checksum_mode,
+            line_ending_mode: self.line_ending_mode,
+            talker_filter: filter,
+            tag_block_mode: self.tag_block_mode,
+            versions: self.versions,
+        }
+    }
+
+    /// Sets the TAG block mode for the parser.
+    ///
+    /// TAG blocks are only returned by parsers built with [`build_tagged`]; parsers
+    /// built with [`build`] validate and then discard them.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The desired TAG block mode:
+    ///   - [`TagBlockMode::Forbidden`]: Message must start with `$` or `!`
+    ///   - [`TagBlockMode::Optional`]: TAG block may be absent or must be valid if present
+    ///   - [`TagBlockMode::Required`]: TAG block must be present and valid
+    ///
+    /// [`build`]: Nmea0183ParserBuilder::build
+    /// [`build_tagged`]: Nmea0183ParserBuilder::build_tagged
+    pub fn tag_block_mode(mut self, mode: TagBlockMode) -> Self {
+        self.tag_block_mode = mode;
+        self
+    }
+
+    /// Sets the NMEA versions implemented by the device for parsers built with
+    /// [`build_version`], and for frames decoded with [`FrameDecoder::decode_version`] and
+    /// [`FrameDecoder::decode_str_version`].
+    ///
+    /// The other parsers leave the content parser in charge of the versions, [`build`] with
+    /// [`NmeaParse::parse`] accepts every version.
+    ///
+    /// The fields added by each NMEA version are required, optional or forbidden depending on
+    /// the [`VersionRange`], see [`NmeaParse::parse_version`].
+    ///
+    /// # Arguments
+    ///
+    /// * `versions` - The range of NMEA versions to accept
+    ///
+    /// [`build`]: Nmea0183ParserBuilder::build
+    /// [`build_version`]: Nmea0183ParserBuilder::build_version
+    /// [`NmeaParse::parse`]: crate::NmeaParse::parse
+    /// [`NmeaParse::parse_version`]: crate::NmeaParse::parse_version
+    pub fn version(mut self, versions: VersionRange) -> Self {
+        self.versions = versions;
+        self
+    }
+
+    /// Creates a [`FrameDecoder`] for byte streams with the configured settings.
+    ///
+    /// The decoder buffers chunks of any size, splits them into frames and runs
+    /// each complete frame through the framing parser, exactly as [`build`] does
+    /// for a single sentence.
+    ///
+    /// [`build`]: Nmea0183ParserBuilder::build
+    pub fn decoder(self) -> FrameDecoder<T> {
+        FrameDecoder::new(self)
+    }
+
     /// Builds the NMEA 0183-style parser with the configured settings.
     ///
     /// This method takes a user-provided parser function that will handle the
@@ -196,9 +400,11 @@ impl Nmea0183ParserBuilder {
     ///
     /// The returned parser will:
     /// * Validate that the input is ASCII-only
-    /// * Expect the message to start with `$`
+    /// * Parse and validate the TAG block, if allowed by the [`TagBlockMode`]
+    /// * Expect the message to start with `$`, or `!` for encapsulated sentences
     /// * Extract the message content (everything before `*CC` or `\r\n`)
     /// * Parse and validate the checksum using the provided checksum parser
+    /// * Apply the talker filter, if any, to the first two characters of the content
     /// * Call the user-provided parser on the message content
     ///
     /// # Arguments
@@ -209,34 +415,183 @@ impl Nmea0183ParserBuilder {
     ///
     /// A parser function that takes an input and returns a result containing the parsed content
     /// or an error if the input does not conform to the expected NMEA 0183 format.
-    pub fn build<'a, I, O, F, E>(self, mut content_parser: F) -> impl FnMut(I) -> IResult<I, O, E>
+    pub fn build<'a, I, O, F, E>(self, content_parser: F) -> impl FnMut(I) -> IResult<I, O, E>
     where
         I: Input + AsBytes + Compare<&'a str> + FindSubstring<&'a str>,
         <I as Input>::Item: AsChar,
         F: Parser<I, Output = O, Error = Error<I, E>>,
         E: ParseError<I>,
+        T: TalkerFilter,
     {
-        move |i: I| {
-            if !i.as_bytes().is_ascii() {
-                return Err(nom::Err::Error(Error::NonAscii));
-            }
+        let mut parser = self.build_tagged(content_parser);
 
-            let (i, _) = char('$').parse(i)?;
-            let (cc, data) = alt((take_until("*"), take_until("\r\n"), rest)).parse(i)?;
-            let (_, cc) = checksum_crlf(self.checksum_mode, self.line_ending_mode).parse(cc)?;
-            let (data, calc_cc) = checksum(data);
-
-            if let Some(cc) = cc
-                && cc != calc_cc
-            {
-                return Err(nom::Err::Error(Error::ChecksumMismatch {
-                    expected: calc_cc,
-                    found: cc,
-                }));
-            }
+        move |i: I| parser(i).map(|(i, tagged)| (i, tagged.content))
+    }
+
+    /// Builds the NMEA 0183-style parser with the configured settings, passing the
+    /// configured [`VersionRange`] to the content parser.
+    ///
+    /// The returned parser behaves exactly like the one returned by [`build`], with
+    /// `content_parser` called with the versions set by [`version`], such as
+    /// [`NmeaParse::parse_version`].
+    ///
+    /// # Arguments
+    ///
+    /// * `content_parser` - User-provided parser for the message content and NMEA versions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "nmea-content")] {
+    /// use nmea0183_parser::{
+    ///     IResult, Nmea0183ParserBuilder, NmeaParse, NmeaVersion, nmea_content::Sentence,
+    /// };
+    /// use nom::Parser;
+    ///
+    /// // NMEA 4.11 device, sending the navigation status
+    /// let mut parser = Nmea0183ParserBuilder::new()
+    ///     .version(NmeaVersion::V4_11.into())
+    ///     .build_version(Sentence::parse_version);
+    ///
+    /// let result: IResult<_, _> = parser
+    ///     .parse("$GPRMC,001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A,V*1F\r\n");
+    /// assert!(result.is_ok());
+    ///
+    /// let result: IResult<_, _> = parser
+    ///     .parse("$GPRMC,001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A*65\r\n");
+    /// assert!(result.is_err());
+    /// # }
+    /// ```
+    ///
+    /// [`build`]: Nmea0183ParserBuilder::build
+    /// [`version`]: Nmea0183ParserBuilder::version
+    /// [`NmeaParse::parse_version`]: crate::NmeaParse::parse_version
+    pub fn build_version<'a, I, O, F, E>(
+        self,
+        mut content_parser: F,
+    ) -> impl FnMut(I) -> IResult<I, O, E>
+    where
+        I: Input + AsBytes + Compare<&'a str> + FindSubstring<&'a str>,
+        <I as Input>::Item: AsChar,
+        F: FnMut(I, VersionRange) -> IResult<I, O, E>,
+        E: ParseError<I>,
+        T: TalkerFilter,
+    {
+        let versions = self.versions;
+
+        self.build(move |i: I| content_parser(i, versions))
+    }
+
+    /// Builds the NMEA 0183-style parser with the configured settings, keeping the TAG block.
+    ///
+    /// The returned parser behaves exactly like the one returned by [`build`], but
+    /// its output is a [`Tagged`] value holding the parsed [`TagBlock`], if any,
+    /// next to the output of the content parser.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_parser` - User-provided parser for the message content.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, Nmea0183ParserBuilder, TagBlockMode};
+    /// use nom::Parser;
+    ///
+    /// fn content_parser(i: &str) -> IResult<&str, &str> {
+    ///     Ok(("", i))
+    /// }
+    ///
+    /// let mut parser = Nmea0183ParserBuilder::new()
+    ///     .tag_block_mode(TagBlockMode::Optional)
+    ///     .build_tagged(content_parser);
+    ///
+    /// let (_, tagged) = parser
+    ///     .parse("\\s:r3669961,c:1241544035*7F\\$GPGGA,data*6A\r\n")
+    ///     .unwrap();
+    /// let tag_block = tagged.tag_block.unwrap();
+    /// assert_eq!(tag_block.source, Some("r3669961"));
+    /// assert_eq!(tag_block.unix_time, Some(1241544035));
+    /// assert_eq!(tagged.content, "GPGGA,data");
+    ///
+    /// let (_, tagged) = parser.parse("$GPGGA,data*6A\r\n").unwrap();
+    /// assert_eq!(tagged.tag_block, None);
+    /// ```
+    ///
+    /// [`build`]: Nmea0183ParserBuilder::build
+    pub fn build_tagged<'a, I, O, F, E>(
+        mut self,
+        mut content_parser: F,
+    ) -> impl FnMut(I) -> IResult<I, Tagged<I, O>, E>
+    where
+        I: Input + AsBytes + Compare<&'a str> + FindSubstring<&'a str>,
+        <I as Input>::Item: AsChar,
+        F: Parser<I, Output = O, Error = Error<I, E>>,
+        E: ParseError<I>,
+        T: TalkerFilter,
+    {
+        move |i: I| self.parse_frame(&mut content_parser, i)
+    }
 
-            content_parser.parse(data)
+    /// Parses a single frame with the configured settings, running `content_parser` on its
+    /// content.
+    ///
+    /// This is the parser returned by [`build_tagged`], borrowing the builder so that the
+    /// [`FrameDecoder`] keeps the state of its talker filter between frames.
+    ///
+    /// [`build_tagged`]: Nmea0183ParserBuilder::build_tagged
+    pub(crate) fn parse_frame<'a, I, O, F, E>(
+        &mut self,
+        content_parser: &mut F,
+        i: I,
+    ) -> IResult<I, Tagged<I, O>, E>
+    where
+        I: Input + AsBytes + Compare<&'a str> + FindSubstring<&'a str>,
+        <I as Input>::Item: AsChar,
+        F: Parser<I, Output = O, Error = Error<I, E>>,
+        E: ParseError<I>,
+        T: TalkerFilter,
+    {
+        if !i.as_bytes().is_ascii() {
+            return Err(nom::Err::Error(Error::NonAscii));
+        }
+
+        // An optional TAG block must still be valid once its opening `\` is found
+        let parse_tag_block = match self.tag_block_mode {
+            TagBlockMode::Forbidden => false,
+            TagBlockMode::Optional => i.as_bytes().first() == Some(&b'\\'),
+            TagBlockMode::Required => true,
+        };
+
+        let (i, tag_block) = if parse_tag_block {
+            tag_block::tag_block.map(Some).parse(i)?
+        } else {
+            (i, None)
+        };
+
+        let (i, _) = one_of("$!").parse(i)?;
+        let (cc, data) = alt((take_until("*"), take_until("\r\n"), rest)).parse(i)?;
+        let (_, cc) = checksum_crlf(self.checksum_mode, self.line_ending_mode).parse(cc)?;
+        let (data, calc_cc) = checksum(data);
+
+        if let Some(cc) = cc
+            && cc != calc_cc
+        {
+            return Err(nom::Err::Error(Error::ChecksumMismatch {
+                expected: calc_cc,
+                found: cc,
+            }));
+        }
+
+        if let [first, second, ..] = *data.as_bytes()
+            && !self.talker_filter.accept([first, second])
+        {
+            return Err(nom::Err::Error(Error::FilteredTalker(data)));
         }
+
+        content_parser
+            .parse(data)
+            .map(|(i, content)| (i, Tagged { tag_block, content }))
     }
 }
 
@@ -472,4 +827,8 @@ mod tests {
     mod cc_crlf10;
     mod cc_crlf11;
     mod crlf;
+    mod decoder;
+    mod raw;
+    mod tag_block;
+    mod writer;
 }
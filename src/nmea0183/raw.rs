@@ -0,0 +1,192 @@
+//! # Raw Sentences
+//!
+//! This module provides [`RawSentence`], a zero-copy view of a sentence that gives access
+//! to its address and fields without parsing them into a typed structure.
+
+use nom::{
+    AsBytes, AsChar, Compare, FindSubstring, Input, Offset,
+    error::{ErrorKind, ParseError},
+};
+
+use crate::{AsStr, Error, Fields, IResult, NmeaParse};
+
+use super::{Nmea0183ParserBuilder, checksum};
+
+/// A sentence split into its address and fields, borrowed from the input.
+///
+/// `RawSentence` is a cheap, allocation-free layer to look at the address field or pull
+/// a single field before committing to a typed structure. It can be used as a content
+/// parser with [`Nmea0183ParserBuilder::build`], or produced by the framing parser
+/// returned by [`Nmea0183ParserBuilder::build_raw`], which also keeps the original span
+/// and the checksum of the sentence.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{IResult, Nmea0183ParserBuilder, NmeaParse, RawSentence};
+/// use nom::Parser;
+///
+/// let mut parser = Nmea0183ParserBuilder::new().build(RawSentence::parse);
+///
+/// let result: IResult<_, _> = parser.parse("$GPGGA,123456.00,4916.29,N,,*32\r\n");
+/// let (_, sentence) = result.unwrap();
+///
+/// assert_eq!(sentence.talker(), Some("GP"));
+/// assert_eq!(sentence.sentence_type(), "GGA");
+/// assert_eq!(sentence.field(1), Some("4916.29"));
+/// assert_eq!(sentence.fields().collect::<Vec<_>>(), ["123456.00", "4916.29", "N", "", ""]);
+/// assert_eq!(sentence.field(5), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RawSentence<'a> {
+    /// Original span of the sentence
+    span: &'a str,
+
+    /// Sentence content, between the start delimiter and the checksum
+    content: &'a str,
+
+    /// Address field, the talker ID and sentence type
+    address: &'a str,
+
+    /// Checksum found in the sentence
+    checksum: Option<u8>,
+}
+
+impl<'a> RawSentence<'a> {
+    /// Returns the address field, such as `GPGGA`, or `PGRME` for proprietary sentences.
+    pub fn address(&self) -> &'a str {
+        self.address
+    }
+
+    /// Returns the talker ID, or [`None`] for proprietary sentences.
+    ///
+    /// Proprietary sentences have an address field starting with `P`.
+    pub fn talker(&self) -> Option<&'a str> {
+        match self.address.as_bytes() {
+            [b'P', ..] => None,
+            _ => self.address.get(..2),
+        }
+    }
+
+    /// Returns the sentence type, or the whole address field for proprietary sentences.
+    pub fn sentence_type(&self) -> &'a str {
+        match self.talker() {
+            Some(talker) => &self.address[talker.len()..],
+            None => self.address,
+        }
+    }
+
+    /// Returns the field at `index`, starting at 0 for the field after the address.
+    pub fn field(&self, index: usize) -> Option<&'a str> {
+        self.fields().nth(index)
+    }
+
+    /// Returns an iterator over the fields after the address.
+    pub fn fields(&self) -> Fields<&'a str> {
+        match self.content.get(self.address.len()..) {
+            Some(fields) if !fields.is_empty() => Fields::new(&fields[1..]),
+            _ => Fields::empty(),
+        }
+    }
+
+    /// Returns the checksum found in the sentence.
+    ///
+    /// Only parsers built with [`Nmea0183ParserBuilder::build_raw`] see the checksum;
+    /// it is [`None`] when parsed as content, or when the sentence has no checksum.
+    pub fn checksum(&self) -> Option<u8> {
+        self.checksum
+    }
+
+    /// Returns the sentence content, between the start delimiter and the checksum.
+    pub fn content(&self) -> &'a str {
+        self.content
+    }
+
+    /// Returns the original span of the sentence.
+    ///
+    /// This is the whole framed sentence, including the TAG block, start delimiter,
+    /// checksum and line ending, when built with [`Nmea0183ParserBuilder::build_raw`],
+    /// and the content otherwise.
+    pub fn span(&self) -> &'a str {
+        self.span
+    }
+}
+
+impl<'a, I, E> NmeaParse<I, E> for RawSentence<'a>
+where
+    I: Input + AsStr<'a>,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        let Some(content) = i.as_str() else {
+            return Err(nom::Err::Error(Error::NonAscii));
+        };
+
+        let address = content.split(',').next().unwrap_or_default();
+        if address.is_empty() || !address.bytes().all(|byte| byte.is_ascii_alphanumeric()) {
+            return Err(nom::Err::Error(nom::error::make_error(
+                i,
+                ErrorKind::AlphaNumeric,
+            )));
+        }
+
+        let sentence = RawSentence {
+            span: content,
+            content,
+            address,
+            checksum: None,
+        };
+
+        Ok((i.take_from(i.input_len()), sentence))
+    }
+}
+
+impl Nmea0183ParserBuilder {
+    /// Builds the NMEA 0183-style parser with the configured settings, producing [`RawSentence`]s.
+    ///
+    /// The returned parser behaves exactly like the one returned by
+    /// [`build`](Nmea0183ParserBuilder::build) with [`RawSentence::parse`] as the content
+    /// parser, but the [`RawSentence`] also keeps the original span of the sentence and
+    /// its checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nmea0183_parser::{IResult, Nmea0183ParserBuilder};
+    ///
+    /// let mut parser = Nmea0183ParserBuilder::new().build_raw();
+    ///
+    /// let result: IResult<_, _> = parser("$GPGGA,data*6A\r\n");
+    /// let (_, sentence) = result.unwrap();
+    ///
+    /// assert_eq!(sentence.span(), "$GPGGA,data*6A\r\n");
+    /// assert_eq!(sentence.content(), "GPGGA,data");
+    /// assert_eq!(sentence.checksum(), Some(0x6A));
+    /// assert_eq!(sentence.field(0), Some("data"));
+    /// ```
+    pub fn build_raw<'a, I, E>(self) -> impl FnMut(I) -> IResult<I, RawSentence<'a>, E>
+    where
+        I: Input + AsBytes + AsStr<'a> + Compare<&'a str> + FindSubstring<&'a str>,
+        <I as Input>::Item: AsChar,
+        E: ParseError<I>,
+    {
+        let mut parser = self.build(RawSentence::parse);
+
+        move |i: I| {
+            // The framing parser consumes the whole sentence
+            let (rest, mut sentence) = parser(i.clone())?;
+            let Some(span) = i.as_str() else {
+                return Err(nom::Err::Error(Error::NonAscii));
+            };
+
+            // The framing parser already validated the checksum, if any
+            let content_end = span.offset(sentence.content) + sentence.content.len();
+            if span[content_end..].starts_with('*') {
+                sentence.checksum = Some(checksum(sentence.content).1);
+            }
+            sentence.span = span;
+
+            Ok((rest, sentence))
+        }
+    }
+}
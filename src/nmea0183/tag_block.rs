@@ -0,0 +1,171 @@
+//! # NMEA 4.10 TAG Blocks
+//!
+//! This module provides parsing for the TAG blocks that may prefix NMEA 0183
+//! sentences, as used by AIS feeds and IEC 61162-450 networks:
+//! `\s:r3669961,c:1241544035*7F\$GPGGA,...`
+//!
+//! A TAG block is enclosed in `\` characters and holds comma-separated
+//! `code:value` parameters followed by its own `*CC` checksum.
+
+use nom::{
+    AsBytes, AsChar, Compare, FindSubstring, Input, Parser,
+    bytes::complete::{take, take_till, take_until},
+    character::complete::{char, hex_digit0, satisfy, u8, u32, u64},
+    error::{ErrorKind, ParseError},
+    multi::separated_list1,
+    number::complete::hex_u32,
+    sequence::separated_pair,
+};
+
+use crate::{Error, IResult};
+
+use super::{checksum, consumed};
+
+/// Parameters of an NMEA 4.10 TAG block.
+///
+/// Only the parameters present in the TAG block are set. Text values, such as the
+/// source and destination, borrow from the input without copying.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagBlock<I> {
+    /// `s` - Source identification, such as the ID of the receiving station
+    pub source: Option<I>,
+
+    /// `c` - UNIX time of the sentence, in seconds since the epoch
+    pub unix_time: Option<u64>,
+
+    /// `n` - Line count, incremented for each sentence of the source
+    pub line_count: Option<u32>,
+
+    /// `r` - Relative time
+    pub relative_time: Option<u64>,
+
+    /// `d` - Destination identification
+    pub destination: Option<I>,
+
+    /// `g` - Sentence grouping, for sentences that belong together
+    pub group: Option<TagGroup>,
+}
+
+impl<I> Default for TagBlock<I> {
+    fn default() -> Self {
+        TagBlock {
+            source: None,
+            unix_time: None,
+            line_count: None,
+            relative_time: None,
+            destination: None,
+            group: None,
+        }
+    }
+}
+
+/// Sentence grouping parameter of a TAG block, formatted as `g:1-2-73874`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagGroup {
+    /// Number of the sentence within the group, starting at 1
+    pub sentence_number: u8,
+
+    /// Total number of sentences in the group
+    pub total_sentences: u8,
+
+    /// Identifier shared by all sentences of the group
+    pub group_id: u32,
+}
+
+/// Output of a framing parser built with
+/// [`Nmea0183ParserBuilder::build_tagged`](crate::Nmea0183ParserBuilder::build_tagged).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tagged<I, O> {
+    /// TAG block of the sentence, if present
+    pub tag_block: Option<TagBlock<I>>,
+
+    /// Output of the content parser
+    pub content: O,
+}
+
+/// Parses a TAG block, including its enclosing `\` characters, and validates its checksum.
+///
+/// Unlike the sentence checksum, the TAG block checksum is always required.
+pub(crate) fn tag_block<'a, I, E>(i: I) -> IResult<I, TagBlock<I>, E>
+where
+    I: Input + AsBytes + Compare<&'a str> + FindSubstring<&'a str>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    let (i, _) = char('\\').parse(i)?;
+    let (i, block) = take_until("\\").parse(i)?;
+    let (i, _) = char('\\').parse(i)?;
+
+    let (cc, parameters) = take_until("*").parse(block)?;
+    let (cc, _) = char('*').parse(cc)?;
+    let (_, cc) = consumed(take(2u8), ErrorKind::Count).parse(cc)?;
+    let (_, cc) = consumed(hex_digit0, ErrorKind::IsA).parse(cc)?;
+    let (_, cc) = hex_u32.map(|cc| cc as u8).parse(cc)?;
+
+    let (parameters, calc_cc) = checksum(parameters);
+    if cc != calc_cc {
+        return Err(nom::Err::Error(Error::ChecksumMismatch {
+            expected: calc_cc,
+            found: cc,
+        }));
+    }
+
+    let (_, parameters) = consumed(
+        separated_list1(
+            char(','),
+            separated_pair(
+                satisfy(|c| c.is_ascii_alphabetic()),
+                char(':'),
+                take_till(|c: <I as Input>::Item| c.as_char() == ','),
+            ),
+        ),
+        ErrorKind::Verify,
+    )
+    .parse(parameters)?;
+
+    let mut tag_block = TagBlock::default();
+    for (code, value) in parameters {
+        match code {
+            's' => tag_block.source = Some(value),
+            'c' => tag_block.unix_time = Some(tag_value(u64, value)?),
+            'n' => tag_block.line_count = Some(tag_value(u32, value)?),
+            'r' => tag_block.relative_time = Some(tag_value(u64, value)?),
+            'd' => tag_block.destination = Some(value),
+            'g' => tag_block.group = Some(tag_value(tag_group, value)?),
+            // Parameters not exposed by `TagBlock` are skipped
+            _ => {}
+        }
+    }
+
+    Ok((i, tag_block))
+}
+
+/// Parses the whole value of a TAG block parameter.
+fn tag_value<I, O, E, F>(parser: F, value: I) -> Result<O, nom::Err<Error<I, E>>>
+where
+    I: Input,
+    E: ParseError<I>,
+    F: Parser<I, Output = O, Error = Error<I, E>>,
+{
+    consumed(parser, ErrorKind::Verify)
+        .parse(value)
+        .map(|(_, value)| value)
+}
+
+/// Parses the value of the sentence grouping parameter, such as `1-2-73874`.
+fn tag_group<I, E>(i: I) -> IResult<I, TagGroup, E>
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    (u8, char('-'), u8, char('-'), u32)
+        .map(
+            |(sentence_number, _, total_sentences, _, group_id)| TagGroup {
+                sentence_number,
+                total_sentences,
+                group_id,
+            },
+        )
+        .parse(i)
+}
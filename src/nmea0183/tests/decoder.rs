@@ -0,0 +1,243 @@
+use nom::error::ErrorKind;
+
+use crate::{ChecksumMode, Error, IResult, LineEndingMode, Nmea0183ParserBuilder};
+
+fn fields(i: &str) -> IResult<&str, Vec<&str>> {
+    Ok(("", i.split(',').collect()))
+}
+
+fn raw(i: &[u8]) -> IResult<&[u8], &[u8]> {
+    Ok((&i[i.len()..], i))
+}
+
+#[test]
+fn test_decoder_byte_by_byte() {
+    let stream = b"$GPGGA,data*6A\r\n$Header,field1,field2*3C\r\n";
+    let mut decoder = Nmea0183ParserBuilder::new().decoder();
+    let mut decoded = vec![];
+
+    for byte in stream {
+        decoder.push(&[*byte]);
+        while let Some(result) = decoder.decode_str(fields) {
+            let (_, fields) = result.unwrap();
+            decoded.push(fields.join(","));
+        }
+    }
+
+    assert_eq!(decoded, vec!["GPGGA,data", "Header,field1,field2"]);
+    assert_eq!(decoder.buffered(), 0);
+}
+
+#[test]
+fn test_decoder_multiple_frames_in_chunk() {
+    let mut decoder = Nmea0183ParserBuilder::new().decoder();
+    decoder.push(b"$GPGGA,data*6A\r\n$GPGGA,data*6A\r\n$GPG");
+
+    assert_eq!(decoder.next_frame(), Some(&b"$GPGGA,data*6A\r\n"[..]));
+    assert_eq!(decoder.next_frame(), Some(&b"$GPGGA,data*6A\r\n"[..]));
+    assert_eq!(decoder.next_frame(), None);
+    assert_eq!(decoder.buffered(), 4);
+
+    decoder.push(b"GA,data*6A\r\n");
+    assert_eq!(decoder.next_frame(), Some(&b"$GPGGA,data*6A\r\n"[..]));
+    assert_eq!(decoder.next_frame(), None);
+}
+
+#[test]
+fn test_decoder_resync_after_garbage() {
+    let mut decoder = Nmea0183ParserBuilder::new().decoder();
+    decoder.push(b"\xff\xfe garbage \r\n$GPGGA,data*6A\r\n");
+
+    let (_, content) = decoder.decode(raw).unwrap().unwrap();
+    assert_eq!(content, b"GPGGA,data");
+    assert!(decoder.decode(raw).is_none());
+}
+
+#[test]
+fn test_decoder_truncated_frame() {
+    let mut decoder = Nmea0183ParserBuilder::new().decoder();
+    decoder.push(b"$GPGGA,da$GPGGA,data*6A\r\n");
+
+    let error = decoder.decode_str(fields).unwrap().unwrap_err();
+    assert!(matches!(
+        error,
+        nom::Err::Error(Error::ParsingError(nom::error::Error {
+            code: ErrorKind::CrLf,
+            ..
+        }))
+    ));
+
+    let (_, fields) = decoder.decode_str(fields).unwrap().unwrap();
+    assert_eq!(fields, vec!["GPGGA", "data"]);
+}
+
+#[test]
+fn test_decoder_checksum_mismatch() {
+    let mut decoder = Nmea0183ParserBuilder::new().decoder();
+    decoder.push(b"$GPGGA,data*99\r\n");
+
+    let error = decoder.decode_str(fields).unwrap().unwrap_err();
+    assert_eq!(
+        error,
+        nom::Err::Error(Error::ChecksumMismatch {
+            expected: 0x6A,
+            found: 0x99
+        })
+    );
+}
+
+#[test]
+fn test_decoder_crlf_forbidden() {
+    let mut decoder = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .line_ending_mode(LineEndingMode::Forbidden)
+        .decoder();
+    decoder.push(b"$GPGGA,data*6A$GPGGA,da");
+
+    let (_, content) = decoder.decode_str(fields).unwrap().unwrap();
+    assert_eq!(content, vec!["GPGGA", "data"]);
+    assert!(decoder.decode_str(fields).is_none());
+
+    decoder.push(b"ta");
+    decoder.finish();
+    let (_, content) = decoder.decode_str(fields).unwrap().unwrap();
+    assert_eq!(content, vec!["GPGGA", "data"]);
+    assert!(decoder.decode_str(fields).is_none());
+}
+
+#[test]
+fn test_decoder_max_frame_length() {
+    let mut decoder = Nmea0183ParserBuilder::new().decoder().max_frame_length(16);
+    decoder.push(b"$GPGGA,too,long,data*00\r\n$GPGGA,data*6A\r\n");
+
+    assert_eq!(decoder.next_frame(), Some(&b"$GPGGA,data*6A\r\n"[..]));
+
+    decoder.push(b"$GPGGA,still,too,long");
+    assert_eq!(decoder.next_frame(), None);
+    assert_eq!(decoder.buffered(), 0);
+}
+
+#[test]
+fn test_decoder_non_utf8_frame() {
+    let mut decoder = Nmea0183ParserBuilder::new().decoder();
+    decoder.push(b"$GPGGA,\xffdata*6A\r\n");
+
+    let error = decoder.decode_str(fields).unwrap().unwrap_err();
+    assert_eq!(error, nom::Err::Error(Error::NonAscii));
+}
+
+#[test]
+fn test_decoder_encapsulated_sentences() {
+    let mut decoder = Nmea0183ParserBuilder::new().decoder();
+    decoder.push(b"!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C\r\n$GPGGA,data*6A\r\n");
+
+    let (_, fields) = decoder.decode_str(fields).unwrap().unwrap();
+    assert_eq!(
+        fields,
+        vec![
+            "AIVDM",
+            "1",
+            "1",
+            "",
+            "B",
+            "177KQJ5000G?tO`K>RA1wUbN0TKH",
+            "0"
+        ]
+    );
+
+    assert_eq!(decoder.next_frame(), Some(&b"$GPGGA,data*6A\r\n"[..]));
+}
+
+#[test]
+#[cfg(feature = "nmea-content")]
+fn test_decoder_talker_filter() {
+    use crate::nmea_content::TalkerId;
+
+    let allowed = [TalkerId::Gnss, TalkerId::Other(*b"II")];
+    let mut rejected = vec![];
+    let mut decoder = Nmea0183ParserBuilder::new()
+        .talker_filter(|talker| {
+            let accept = allowed.contains(&talker);
+            if !accept {
+                rejected.push(talker);
+            }
+            accept
+        })
+        .decoder();
+
+    decoder.push(b"$GNGGA,data*74\r\n$GPGGA,data*6A\r\n$IIHDT,1.0,T*23\r\n$GPGGA,data*6A\r\n");
+
+    let (_, content) = decoder.decode_str(fields).unwrap().unwrap();
+    assert_eq!(content, vec!["GNGGA", "data"]);
+    assert_eq!(
+        decoder.decode_str(fields).unwrap(),
+        Err(nom::Err::Error(Error::FilteredTalker("GPGGA,data")))
+    );
+    let (_, content) = decoder.decode_str(fields).unwrap().unwrap();
+    assert_eq!(content, vec!["IIHDT", "1.0", "T"]);
+    assert!(decoder.decode_str(fields).unwrap().is_err());
+    assert!(decoder.decode_str(fields).is_none());
+
+    drop(decoder);
+    assert_eq!(rejected, vec![TalkerId::Gps, TalkerId::Gps]);
+}
+
+#[test]
+#[cfg(feature = "nmea-content")]
+fn test_decoder_version() {
+    use crate::{
+        NmeaParse, NmeaVersion,
+        nmea_content::{NmeaSentence, Sentence},
+    };
+
+    let stream = concat!(
+        "$GPRMC,001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A,V*1F\r\n",
+        "$GPRMC,001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A*65\r\n",
+    );
+
+    // NMEA 4.11 device, sending the navigation status
+    let mut decoder = Nmea0183ParserBuilder::new()
+        .version(NmeaVersion::V4_11.into())
+        .decoder();
+    decoder.push(stream.as_bytes());
+
+    let result: Option<IResult<_, _>> = decoder.decode_str_version(Sentence::parse_version);
+    assert!(
+        matches!(
+            result,
+            Some(Ok((
+                "",
+                Sentence {
+                    data: NmeaSentence::RMC(_),
+                    ..
+                }
+            )))
+        ),
+        "{result:?}"
+    );
+    let result: Option<IResult<_, _>> = decoder.decode_version(Sentence::parse_version);
+    assert!(matches!(result, Some(Err(_))), "{result:?}");
+    let result: Option<IResult<_, _>> = decoder.decode_str_version(Sentence::parse_version);
+    assert!(result.is_none());
+
+    // Without a version, both sentences are accepted
+    let mut decoder = Nmea0183ParserBuilder::new().decoder();
+    decoder.push(stream.as_bytes());
+
+    for _ in 0..2 {
+        let result: Option<IResult<_, _>> = decoder.decode_version(Sentence::parse_version);
+        assert!(
+            matches!(
+                result,
+                Some(Ok((
+                    b"",
+                    Sentence {
+                        data: NmeaSentence::RMC(_),
+                        ..
+                    }
+                )))
+            ),
+            "{result:?}"
+        );
+    }
+}
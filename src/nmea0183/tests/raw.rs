@@ -0,0 +1,80 @@
+use nom::Parser;
+
+use crate::{ChecksumMode, IResult, Nmea0183ParserBuilder, NmeaParse, RawSentence, TagBlockMode};
+
+#[test]
+fn test_raw_sentence_content() {
+    let cases = [
+        ("GPGGA,1,,3", Some("GP"), "GGA", vec!["1", "", "3"]),
+        ("GPGGA,", Some("GP"), "GGA", vec![""]),
+        ("GPGGA", Some("GP"), "GGA", vec![]),
+        ("PGRME,15.0,M", None, "PGRME", vec!["15.0", "M"]),
+        (
+            "AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0",
+            Some("AI"),
+            "VDM",
+            vec!["1", "1", "", "B", "177KQJ5000G?tO`K>RA1wUbN0TKH", "0"],
+        ),
+    ];
+
+    for (input, talker, sentence_type, fields) in cases {
+        let result: IResult<_, _> = RawSentence::parse(input);
+        let Ok(("", sentence)) = result else {
+            panic!("Failed: {input:?}\n\t{result:?}");
+        };
+
+        assert_eq!(sentence.talker(), talker, "Failed: {input:?}");
+        assert_eq!(sentence.sentence_type(), sentence_type, "Failed: {input:?}");
+        assert_eq!(
+            sentence.fields().collect::<Vec<_>>(),
+            fields,
+            "Failed: {input:?}"
+        );
+        assert_eq!(sentence.field(fields.len()), None, "Failed: {input:?}");
+        assert_eq!(sentence.span(), input, "Failed: {input:?}");
+        assert_eq!(sentence.checksum(), None, "Failed: {input:?}");
+    }
+
+    for input in ["", ",1,2", "GP GGA,1"] {
+        let result: IResult<_, _> = RawSentence::parse(input);
+        assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+    }
+}
+
+#[test]
+fn test_raw_sentence_framed() {
+    let mut parser = Nmea0183ParserBuilder::new()
+        .checksum_mode(ChecksumMode::Optional)
+        .tag_block_mode(TagBlockMode::Optional)
+        .build_raw();
+
+    let cases = [
+        ("$GPGGA,data*6A\r\n", "GPGGA,data", Some(0x6A)),
+        ("$GPGGA,data\r\n", "GPGGA,data", None),
+        (
+            "\\s:r3669961,c:1241544035*7F\\!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C\r\n",
+            "AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0",
+            Some(0x5C),
+        ),
+    ];
+
+    for (input, content, checksum) in cases {
+        let result: IResult<_, _> = parser(input);
+        let Ok((_, sentence)) = result else {
+            panic!("Failed: {input:?}\n\t{result:?}");
+        };
+
+        assert_eq!(sentence.span(), input, "Failed: {input:?}");
+        assert_eq!(sentence.content(), content, "Failed: {input:?}");
+        assert_eq!(sentence.checksum(), checksum, "Failed: {input:?}");
+    }
+
+    // Byte input, as produced by a `FrameDecoder`
+    let mut parser = Nmea0183ParserBuilder::new().build(RawSentence::parse);
+    let result: IResult<_, _> = parser.parse(&b"$GPGGA,data*6A\r\n"[..]);
+    let Ok((_, sentence)) = result else {
+        panic!("Failed: {result:?}");
+    };
+    assert_eq!(sentence.address(), "GPGGA");
+    assert_eq!(sentence.field(0), Some("data"));
+}
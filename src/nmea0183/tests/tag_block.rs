@@ -0,0 +1,158 @@
+use nom::{Parser, error::ErrorKind};
+
+use crate::{Error, IResult, Nmea0183ParserBuilder, TagBlock, TagBlockMode, TagGroup, Tagged};
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    Ok(("", i))
+}
+
+fn raw(i: &[u8]) -> IResult<&[u8], &[u8]> {
+    Ok((&i[i.len()..], i))
+}
+
+#[test]
+fn test_tag_block_parameters() {
+    let cases = [
+        (
+            "\\s:r3669961,c:1241544035*7F\\$GPGGA,data*6A\r\n",
+            TagBlock {
+                source: Some("r3669961"),
+                unix_time: Some(1241544035),
+                ..TagBlock::default()
+            },
+        ),
+        (
+            "\\g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A\\$GPGGA,data*6A\r\n",
+            TagBlock {
+                source: Some("r003669945"),
+                unix_time: Some(1241544035),
+                line_count: Some(157036),
+                group: Some(TagGroup {
+                    sentence_number: 1,
+                    total_sentences: 2,
+                    group_id: 73874,
+                }),
+                ..TagBlock::default()
+            },
+        ),
+        (
+            "\\d:SHIP,r:123*08\\$GPGGA,data*6A\r\n",
+            TagBlock {
+                destination: Some("SHIP"),
+                relative_time: Some(123),
+                ..TagBlock::default()
+            },
+        ),
+    ];
+
+    let mut parser = Nmea0183ParserBuilder::new()
+        .tag_block_mode(TagBlockMode::Required)
+        .build_tagged(content_parser);
+
+    for (input, expected) in cases {
+        let result = parser.parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                Tagged {
+                    tag_block: Some(expected),
+                    content: "GPGGA,data",
+                }
+            )),
+            "Failed: {input:?}\n\t{result:?}"
+        );
+    }
+}
+
+#[test]
+fn test_tag_block_errors() {
+    let cases = [
+        (
+            "\\s:r3669961,c:1241544035*4A\\$GPGGA,data*6A\r\n",
+            Error::ChecksumMismatch {
+                expected: 0x7F,
+                found: 0x4A,
+            },
+        ),
+        (
+            "\\s:r3669961,c:1241544035\\$GPGGA,data*6A\r\n",
+            Error::ParsingError(nom::error::Error::new(
+                "s:r3669961,c:1241544035",
+                ErrorKind::TakeUntil,
+            )),
+        ),
+        (
+            "\\s:r3669961,c:1241544035*7F$GPGGA,data*6A\r\n",
+            Error::ParsingError(nom::error::Error::new(
+                "s:r3669961,c:1241544035*7F$GPGGA,data*6A\r\n",
+                ErrorKind::TakeUntil,
+            )),
+        ),
+        (
+            "\\c:now*2F\\$GPGGA,data*6A\r\n",
+            Error::ParsingError(nom::error::Error::new("now", ErrorKind::Digit)),
+        ),
+    ];
+
+    let mut parser = Nmea0183ParserBuilder::new()
+        .tag_block_mode(TagBlockMode::Optional)
+        .build_tagged(content_parser);
+
+    for (input, expected) in cases {
+        let result = parser.parse(input);
+        assert_eq!(
+            result,
+            Err(nom::Err::Error(expected)),
+            "Failed: {input:?}\n\t{result:?}"
+        );
+    }
+}
+
+#[test]
+fn test_tag_block_modes() {
+    let tagged = "\\s:SRC*0B\\$GPGGA,data*6A\r\n";
+    let untagged = "$GPGGA,data*6A\r\n";
+
+    let cases = [
+        (TagBlockMode::Forbidden, false, true),
+        (TagBlockMode::Optional, true, true),
+        (TagBlockMode::Required, true, false),
+    ];
+
+    for (mode, tagged_ok, untagged_ok) in cases {
+        let mut parser = Nmea0183ParserBuilder::new()
+            .tag_block_mode(mode)
+            .build(content_parser);
+
+        assert_eq!(parser.parse(tagged).is_ok(), tagged_ok, "Failed: {mode:?}");
+        assert_eq!(
+            parser.parse(untagged).is_ok(),
+            untagged_ok,
+            "Failed: {mode:?}"
+        );
+    }
+}
+
+#[test]
+fn test_decoder_tag_blocks() {
+    let stream = b"noise\\s:SRC*0B\\$GPGGA,data*6A\r\n$GPGGA,data*6A\r\n\\s:SRC*0B\\$GPG";
+    let mut decoder = Nmea0183ParserBuilder::new()
+        .tag_block_mode(TagBlockMode::Optional)
+        .decoder();
+    decoder.push(stream);
+
+    let (_, tagged) = decoder.decode_tagged(raw).unwrap().unwrap();
+    assert_eq!(tagged.tag_block.unwrap().source, Some(&b"SRC"[..]));
+    assert_eq!(tagged.content, b"GPGGA,data");
+
+    let (_, tagged) = decoder.decode_tagged(raw).unwrap().unwrap();
+    assert_eq!(tagged.tag_block, None);
+
+    assert!(decoder.decode_tagged(raw).is_none());
+    decoder.push(b"GA,data*6A\r\n");
+    assert_eq!(
+        decoder.next_frame(),
+        Some(&b"\\s:SRC*0B\\$GPGGA,data*6A\r\n"[..])
+    );
+}
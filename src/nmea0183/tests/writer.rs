@@ -0,0 +1,125 @@
+use std::fmt::{self, Write};
+
+use nom::Parser;
+
+use crate::{
+    ChecksumMode, Encoder, IResult, LineEndingMode, Nmea0183ParserBuilder, Nmea0183WriterBuilder,
+    StartDelimiter,
+};
+
+fn content_encoder(content: &str, e: &mut Encoder<'_>) -> fmt::Result {
+    e.write_str(content)
+}
+
+fn content_parser(i: &str) -> IResult<&str, &str> {
+    Ok(("", i))
+}
+
+#[test]
+fn test_writer_modes() {
+    let cases = [
+        (
+            ChecksumMode::Required,
+            LineEndingMode::Required,
+            "$GPGGA,data*6A\r\n",
+        ),
+        (
+            ChecksumMode::Required,
+            LineEndingMode::Forbidden,
+            "$GPGGA,data*6A",
+        ),
+        (
+            ChecksumMode::Optional,
+            LineEndingMode::Required,
+            "$GPGGA,data\r\n",
+        ),
+        (
+            ChecksumMode::Optional,
+            LineEndingMode::Forbidden,
+            "$GPGGA,data",
+        ),
+    ];
+
+    for (cc, crlf, expected) in cases {
+        let mut writer = Nmea0183WriterBuilder::new()
+            .checksum_mode(cc)
+            .line_ending_mode(crlf)
+            .build(content_encoder);
+
+        let mut output = String::new();
+        writer(&mut output, "GPGGA,data").unwrap();
+        assert_eq!(output, expected, "Failed: {cc:?} {crlf:?}");
+
+        let mut parser = Nmea0183ParserBuilder::new()
+            .checksum_mode(cc)
+            .line_ending_mode(crlf)
+            .build(content_parser);
+        assert_eq!(parser.parse(output.as_str()), Ok(("", "GPGGA,data")));
+    }
+}
+
+#[test]
+fn test_writer_talker() {
+    let mut writer = Nmea0183WriterBuilder::new()
+        .talker(*b"GP")
+        .build(content_encoder);
+
+    let mut output = String::new();
+    writer(&mut output, "GGA,data").unwrap();
+    assert_eq!(output, "$GPGGA,data*6A\r\n");
+}
+
+#[cfg(feature = "nmea-content")]
+#[test]
+fn test_writer_heapless_string() {
+    let mut writer = Nmea0183WriterBuilder::new().build(content_encoder);
+
+    let mut output = heapless::String::<16>::new();
+    writer(&mut output, "GPGGA,data").unwrap();
+    assert_eq!(output.as_str(), "$GPGGA,data*6A\r\n");
+
+    // The output is too short for the sentence
+    let mut writer = Nmea0183WriterBuilder::new().build(content_encoder);
+    let mut output = heapless::String::<8>::new();
+    assert!(writer(&mut output, "GPGGA,data").is_err());
+}
+
+#[test]
+fn test_writer_start_delimiter() {
+    let mut writer = Nmea0183WriterBuilder::new()
+        .start_delimiter(StartDelimiter::Encapsulation)
+        .build(content_encoder);
+
+    let mut output = String::new();
+    writer(&mut output, "AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0").unwrap();
+    assert_eq!(
+        output,
+        "!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C\r\n"
+    );
+}
+
+#[cfg(feature = "nmea-content")]
+#[test]
+fn test_writer_vdm_round_trip() {
+    use crate::{
+        NmeaEncode, NmeaParse,
+        nmea_content::{NmeaSentence, Sentence, TalkerId},
+    };
+
+    let input = "!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C\r\n";
+
+    let mut parser = Nmea0183ParserBuilder::new().build(Sentence::parse);
+    let result: IResult<_, _> = parser.parse(input);
+    let (_, sentence) = result.unwrap();
+    assert_eq!(sentence.talker, TalkerId::Other(*b"AI"));
+    assert!(matches!(sentence.data, NmeaSentence::VDM(_)));
+
+    let mut writer = Nmea0183WriterBuilder::new()
+        .start_delimiter(StartDelimiter::Encapsulation)
+        .talker(sentence.talker.code())
+        .build(NmeaSentence::encode);
+
+    let mut output = String::new();
+    writer(&mut output, &sentence.data).unwrap();
+    assert_eq!(output, input);
+}
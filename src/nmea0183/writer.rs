@@ -0,0 +1,287 @@
+//! # NMEA 0183 Sentence Writer
+//!
+//! This module provides the encoding counterpart of the framing parser built by
+//! [`Nmea0183ParserBuilder`](crate::Nmea0183ParserBuilder).
+//!
+//! The [`Nmea0183WriterBuilder`] wraps content written by a content encoder in the
+//! standard NMEA 0183 frame: `$HHH,D1,D2,...,Dn*CC\r\n`. The `$` or `!` start delimiter,
+//! the optional talker prefix, the XOR checksum and the line ending are all
+//! handled by the writer, so content encoders only deal with the fields.
+
+use std::fmt::{self, Write};
+
+use crate::{ChecksumMode, Encoder, LineEndingMode, Precision};
+
+/// Defines the start delimiter of written sentences.
+///
+/// Parsers accept both delimiters, but encapsulation sentences, such as AIS `!AIVDM`
+/// sentences, must be written with `!`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum StartDelimiter {
+    #[default]
+    /// `$`, the start delimiter of parametric sentences, such as `$GPGGA`.
+    Parametric,
+
+    /// `!`, the start delimiter of encapsulation sentences, such as `!AIVDM`.
+    Encapsulation,
+}
+
+impl StartDelimiter {
+    /// Returns the character of the start delimiter.
+    pub fn as_char(&self) -> char {
+        match self {
+            StartDelimiter::Parametric => '$',
+            StartDelimiter::Encapsulation => '!',
+        }
+    }
+}
+
+/// Creates a configurable NMEA 0183-style writer factory.
+///
+/// This struct mirrors [`Nmea0183ParserBuilder`](crate::Nmea0183ParserBuilder):
+/// it configures the framing of written sentences before building the final writer.
+/// A sentence written with a given configuration is accepted by a parser built with
+/// the same [`ChecksumMode`] and [`LineEndingMode`].
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{Encoder, Nmea0183WriterBuilder};
+/// use std::fmt::{self, Write};
+///
+/// fn content_encoder(fields: &[&str], e: &mut Encoder<'_>) -> fmt::Result {
+///     e.write_str(&fields.join(","))
+/// }
+///
+/// let mut writer = Nmea0183WriterBuilder::new().build(content_encoder);
+///
+/// let mut output = String::new();
+/// writer(&mut output, &["GPGGA", "data"]).unwrap();
+/// assert_eq!(output, "$GPGGA,data*6A\r\n");
+/// ```
+///
+/// ## Configuration
+///
+/// ```rust
+/// use nmea0183_parser::{ChecksumMode, Encoder, LineEndingMode, Nmea0183WriterBuilder};
+/// use std::fmt::{self, Write};
+///
+/// fn content_encoder(content: &str, e: &mut Encoder<'_>) -> fmt::Result {
+///     e.write_str(content)
+/// }
+///
+/// // Checksum omitted, no CRLF, talker ID written by the writer
+/// let mut writer = Nmea0183WriterBuilder::new()
+///     .checksum_mode(ChecksumMode::Optional)
+///     .line_ending_mode(LineEndingMode::Forbidden)
+///     .talker(*b"GP")
+///     .build(content_encoder);
+///
+/// let mut output = String::new();
+/// writer(&mut output, "GGA,data").unwrap();
+/// assert_eq!(output, "$GPGGA,data");
+/// ```
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct Nmea0183WriterBuilder {
+    /// Checksum mode for the writer.
+    checksum_mode: ChecksumMode,
+
+    /// Line ending mode for the writer.
+    line_ending_mode: LineEndingMode,
+
+    /// Start delimiter of written sentences.
+    start_delimiter: StartDelimiter,
+
+    /// Optional talker ID written before the content.
+    talker: Option<[u8; 2]>,
+
+    /// Numeric precision for encoded fields.
+    precision: Precision,
+}
+
+impl Nmea0183WriterBuilder {
+    /// Creates a new NMEA 0183 writer builder with default settings.
+    ///
+    /// The default settings are:
+    /// - Checksum mode: [`ChecksumMode::Required`]
+    /// - Line ending mode: [`LineEndingMode::Required`]
+    /// - Start delimiter: [`StartDelimiter::Parametric`]
+    /// - Talker: none, the content encoder writes the talker ID
+    /// - Precision: [`Precision::default`]
+    pub fn new() -> Self {
+        Nmea0183WriterBuilder {
+            checksum_mode: ChecksumMode::Required,
+            line_ending_mode: LineEndingMode::Required,
+            start_delimiter: StartDelimiter::Parametric,
+            talker: None,
+            precision: Precision::default(),
+        }
+    }
+
+    /// Sets the checksum mode for the writer.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The desired checksum mode:
+    ///   - [`ChecksumMode::Required`]: The `*CC` checksum is written after the content
+    ///   - [`ChecksumMode::Optional`]: No checksum is written
+    pub fn checksum_mode(mut self, mode: ChecksumMode) -> Self {
+        self.checksum_mode = mode;
+        self
+    }
+
+    /// Sets the line ending mode for the writer.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The desired line ending mode:
+    ///   - [`LineEndingMode::Required`]: Sentences end with `\r\n`
+    ///   - [`LineEndingMode::Forbidden`]: Sentences end right after the checksum
+    pub fn line_ending_mode(mut self, mode: LineEndingMode) -> Self {
+        self.line_ending_mode = mode;
+        self
+    }
+
+    /// Sets the start delimiter of written sentences.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - The desired start delimiter:
+    ///   - [`StartDelimiter::Parametric`]: Sentences start with `$`
+    ///   - [`StartDelimiter::Encapsulation`]: Sentences start with `!`, as AIS sentences do
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "nmea-content")] {
+    /// use nmea0183_parser::{
+    ///     Nmea0183WriterBuilder, NmeaEncode, StartDelimiter,
+    ///     nmea_content::{AisChannel, NmeaSentence, VDM},
+    /// };
+    ///
+    /// let mut writer = Nmea0183WriterBuilder::new()
+    ///     .start_delimiter(StartDelimiter::Encapsulation)
+    ///     .talker(*b"AI")
+    ///     .build(NmeaSentence::encode);
+    ///
+    /// let vdm = VDM {
+    ///     fragment_count: 1,
+    ///     fragment_number: 1,
+    ///     sequential_message_id: None,
+    ///     channel: Some(AisChannel::B),
+    ///     payload: "177KQJ5000G?tO`K>RA1wUbN0TKH".try_into().unwrap(),
+    ///     fill_bits: 0,
+    /// };
+    ///
+    /// let mut output = String::new();
+    /// writer(&mut output, &NmeaSentence::VDM(vdm)).unwrap();
+    /// assert_eq!(output, "!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C\r\n");
+    /// # }
+    /// ```
+    pub fn start_delimiter(mut self, delimiter: StartDelimiter) -> Self {
+        self.start_delimiter = delimiter;
+        self
+    }
+
+    /// Sets the talker ID written between the start delimiter and the content.
+    ///
+    /// This is the counterpart of content parsers that skip the talker ID, such as
+    /// `NmeaSentence`, whose encoder writes the sentence type and fields only.
+    ///
+    /// # Arguments
+    ///
+    /// * `talker` - The two-character talker ID, such as `*b"GP"`.
+    pub fn talker(mut self, talker: [u8; 2]) -> Self {
+        self.talker = Some(talker);
+        self
+    }
+
+    /// Sets the numeric precision for encoded fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - The precision passed to the content encoder through [`Encoder`].
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Builds the NMEA 0183-style writer with the configured settings.
+    ///
+    /// The returned writer writes a complete sentence to any [`fmt::Write`]
+    /// implementor, such as a [`String`] or a `heapless::String`:
+    /// the start delimiter, the talker ID if configured, the content written by
+    /// `content_encoder`, and the checksum and line ending according to the configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_encoder` - An encoder for the content of the sentence, such as
+    ///   [`NmeaEncode::encode`](crate::NmeaEncode::encode).
+    ///
+    /// # Returns
+    ///
+    /// A writer function that takes an output and a value, and writes the sentence.
+    pub fn build<T, W, F>(self, mut content_encoder: F) -> impl FnMut(&mut W, &T) -> fmt::Result
+    where
+        T: ?Sized,
+        W: Write,
+        F: FnMut(&T, &mut Encoder<'_>) -> fmt::Result,
+    {
+        move |output: &mut W, value: &T| {
+            output.write_char(self.start_delimiter.as_char())?;
+
+            let mut content = ChecksumWriter {
+                output: &mut *output,
+                checksum: 0,
+            };
+
+            if let Some(talker) = self.talker {
+                talker
+                    .iter()
+                    .try_for_each(|&byte| content.write_char(byte as char))?;
+            }
+
+            content_encoder(
+                value,
+                &mut Encoder::with_precision(&mut content, self.precision),
+            )?;
+            let checksum = content.checksum;
+
+            if self.checksum_mode == ChecksumMode::Required {
+                write!(output, "*{checksum:02X}")?;
+            }
+
+            if self.line_ending_mode == LineEndingMode::Required {
+                output.write_str("\r\n")?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+impl Default for Nmea0183WriterBuilder {
+    fn default() -> Self {
+        Nmea0183WriterBuilder::new()
+    }
+}
+
+/// Forwards written content while calculating its XOR checksum.
+struct ChecksumWriter<'a, W> {
+    /// Output the content is forwarded to.
+    output: &'a mut W,
+
+    /// XOR of all bytes written so far.
+    checksum: u8,
+}
+
+impl<W> Write for ChecksumWriter<'_, W>
+where
+    W: Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.checksum = s.bytes().fold(self.checksum, |acc, byte| acc ^ byte);
+        self.output.write_str(s)
+    }
+}
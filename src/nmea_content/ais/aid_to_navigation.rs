@@ -0,0 +1,88 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::nmea_content::{
+    Location,
+    ais::{Dimensions, EpfdType, bits::BitReader},
+};
+
+/// Type 21 - Aid-to-Navigation Report
+///
+/// <https://gpsd.gitlab.io/gpsd/AIVDM.html#_type_21_aid_to_navigation_report>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AidToNavigationReport {
+    /// Number of times the message was repeated
+    pub repeat_indicator: u8,
+    /// MMSI of the aid-to-navigation
+    pub mmsi: u32,
+    /// Type of the aid-to-navigation, 0 when not specified
+    pub aid_type: u8,
+    /// Name of the aid-to-navigation, including the name extension
+    pub name: heapless::String<34>,
+    /// Position accuracy, `true` for a DGPS-quality fix better than 10 meters
+    pub position_accuracy: bool,
+    /// Location (latitude and longitude)
+    pub location: Option<Location>,
+    /// Dimensions of the aid-to-navigation relative to the position reference point
+    pub dimensions: Dimensions,
+    /// Type of electronic position fixing device
+    pub epfd: EpfdType,
+    /// Second of the UTC minute when the report was generated
+    pub timestamp: Option<u8>,
+    /// Whether a floating aid-to-navigation is off its charted position
+    pub off_position: bool,
+    /// Receiver autonomous integrity monitoring (RAIM) flag
+    pub raim: bool,
+    /// Whether the aid-to-navigation is virtual, rather than a physical one
+    pub virtual_aid: bool,
+    /// Whether the unit is in assigned mode
+    pub assigned: bool,
+}
+
+impl AidToNavigationReport {
+    pub(super) fn decode(reader: &mut BitReader<'_>, len: usize) -> Self {
+        reader.skip(6);
+        let repeat_indicator = reader.uint(2) as u8;
+        let mmsi = reader.uint(30);
+        let aid_type = reader.uint(5) as u8;
+        let name: heapless::String<20> = reader.text(20);
+        let position_accuracy = reader.flag();
+        let location = reader.location();
+        let dimensions = Dimensions::decode(reader);
+        let epfd = EpfdType::from(reader.uint(4) as u8);
+        let timestamp = reader.timestamp();
+        let off_position = reader.flag();
+        // Regional reserved bits
+        reader.skip(8);
+        let raim = reader.flag();
+        let virtual_aid = reader.flag();
+        let assigned = reader.flag();
+        reader.skip(1);
+
+        // The name extension fills the bits after the first 272 bits
+        let extension: heapless::String<14> = reader.text(len.saturating_sub(272) / 6);
+
+        let mut full_name = heapless::String::new();
+        // Both parts fit in the capacity of the full name
+        let _ = full_name.push_str(&name);
+        let _ = full_name.push_str(&extension);
+
+        AidToNavigationReport {
+            repeat_indicator,
+            mmsi,
+            aid_type,
+            name: full_name,
+            position_accuracy,
+            location,
+            dimensions,
+            epfd,
+            timestamp,
+            off_position,
+            raim,
+            virtual_aid,
+            assigned,
+        }
+    }
+}
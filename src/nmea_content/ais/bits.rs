@@ -0,0 +1,176 @@
+use crate::nmea_content::{Location, ais::AisError};
+
+/// Maximum length of an AIS message, in bits, which is five slots of the VHF data link.
+pub const MAX_PAYLOAD_BITS: usize = 1008;
+
+/// Bits of a de-armored AIS payload, stored as 6-bit values.
+pub(super) struct Payload {
+    /// 6-bit values of the payload characters
+    sixbits: heapless::Vec<u8, { MAX_PAYLOAD_BITS / 6 }>,
+
+    /// Number of valid bits, excluding the fill bits
+    len: usize,
+}
+
+impl Payload {
+    /// De-armors a payload made of 6-bit ASCII characters.
+    pub fn from_armored(payload: &[u8], fill_bits: u8) -> Result<Self, AisError> {
+        let mut sixbits = heapless::Vec::new();
+
+        for &c in payload {
+            let value = match c {
+                b'0'..=b'W' => c - b'0',
+                b'`'..=b'w' => c - b'0' - 8,
+                _ => return Err(AisError::InvalidCharacter(c)),
+            };
+            sixbits.push(value).or(Err(AisError::InvalidLength))?;
+        }
+
+        let len = (sixbits.len() * 6)
+            .checked_sub(fill_bits as usize)
+            .filter(|_| fill_bits < 6)
+            .ok_or(AisError::InvalidLength)?;
+
+        Ok(Payload { sixbits, len })
+    }
+
+    /// Returns the number of valid bits of the payload.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns a reader positioned at the first bit of the payload.
+    pub fn reader(&self) -> BitReader<'_> {
+        BitReader {
+            payload: self,
+            position: 0,
+        }
+    }
+}
+
+/// Sequential reader of payload fields.
+///
+/// Bits past the end of the payload read as zero, since some transmitters send
+/// messages a few bits shorter than the standard length.
+pub(super) struct BitReader<'a> {
+    /// Payload the bits are read from
+    payload: &'a Payload,
+
+    /// Index of the next bit to read
+    position: usize,
+}
+
+impl BitReader<'_> {
+    fn bit(&mut self) -> bool {
+        let position = self.position;
+        self.position += 1;
+
+        position < self.payload.len
+            && self.payload.sixbits[position / 6] & (0x20 >> (position % 6)) != 0
+    }
+
+    /// Skips `width` bits.
+    pub fn skip(&mut self, width: usize) {
+        self.position += width;
+    }
+
+    /// Reads an unsigned integer of `width` bits, at most 32.
+    pub fn uint(&mut self, width: usize) -> u32 {
+        (0..width).fold(0, |value, _| (value << 1) | self.bit() as u32)
+    }
+
+    /// Reads a two's complement signed integer of `width` bits, at most 32.
+    pub fn int(&mut self, width: usize) -> i32 {
+        let shift = 32 - width;
+        ((self.uint(width) << shift) as i32) >> shift
+    }
+
+    /// Reads a single bit flag.
+    pub fn flag(&mut self) -> bool {
+        self.bit()
+    }
+
+    /// Reads `chars` 6-bit ASCII characters, without the trailing `@` padding and spaces.
+    pub fn text<const N: usize>(&mut self, chars: usize) -> heapless::String<N> {
+        let mut text = heapless::String::new();
+
+        for _ in 0..chars {
+            let value = self.uint(6) as u8;
+            let c = if value < 32 { value + b'@' } else { value };
+            // Characters beyond the capacity can only be padding
+            let _ = text.push(c as char);
+        }
+
+        let len = text.trim_end_matches(['@', ' ']).len();
+        text.truncate(len);
+        text
+    }
+
+    /// Reads a longitude of 28 bits and a latitude of 27 bits, in 1/10000 minutes.
+    ///
+    /// Returns [`None`] for the "not available" values of 181° and 91°.
+    pub fn location(&mut self) -> Option<Location> {
+        let longitude = self.int(28) as f64 / 600_000.0;
+        let latitude = self.int(27) as f64 / 600_000.0;
+
+        (longitude != 181.0 && latitude != 91.0).then_some(Location {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// Reads a speed over ground of 10 bits, in 1/10 knots.
+    pub fn speed_over_ground(&mut self) -> Option<f32> {
+        let speed = self.uint(10);
+        (speed != 1023).then(|| speed as f32 / 10.0)
+    }
+
+    /// Reads a course over ground of 12 bits, in 1/10 degrees.
+    pub fn course_over_ground(&mut self) -> Option<f32> {
+        let course = self.uint(12);
+        (course < 3600).then(|| course as f32 / 10.0)
+    }
+
+    /// Reads a true heading of 9 bits, in degrees.
+    pub fn true_heading(&mut self) -> Option<u16> {
+        let heading = self.uint(9) as u16;
+        (heading < 360).then_some(heading)
+    }
+
+    /// Reads a UTC second time stamp of 6 bits.
+    pub fn timestamp(&mut self) -> Option<u8> {
+        let second = self.uint(6) as u8;
+        (second < 60).then_some(second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_bits() {
+        let payload = Payload::from_armored(b"0Ww`", 2).unwrap();
+        assert_eq!(payload.len(), 22);
+
+        let mut reader = payload.reader();
+        assert_eq!(reader.uint(6), 0);
+        assert_eq!(reader.uint(6), 39);
+        assert_eq!(reader.int(6), -1);
+        assert_eq!(reader.uint(4), 0b1010);
+        // Past the fill bits
+        assert_eq!(reader.uint(2), 0);
+
+        let cases = [
+            (&b"0X"[..], 0, AisError::InvalidCharacter(b'X')),
+            (&b"0,"[..], 0, AisError::InvalidCharacter(b',')),
+            (&b"0"[..], 6, AisError::InvalidLength),
+            (&b""[..], 1, AisError::InvalidLength),
+        ];
+
+        for (payload, fill_bits, expected) in cases {
+            let result = Payload::from_armored(payload, fill_bits).map(|payload| payload.len());
+            assert_eq!(result, Err(expected), "Failed: {payload:?}");
+        }
+    }
+}
@@ -0,0 +1,495 @@
+//! # AIS Message Decoding
+//!
+//! This module decodes the AIS messages carried by the 6-bit armored payload of
+//! [`VDM`](crate::nmea_content::VDM) and [`VDO`](crate::nmea_content::VDO) sentences.
+//!
+//! <https://gpsd.gitlab.io/gpsd/AIVDM.html>
+//!
+//! ```rust
+//! use nmea0183_parser::{
+//!     IResult, Nmea0183ParserBuilder, NmeaParse,
+//!     nmea_content::{NmeaSentence, ais::AisMessage},
+//! };
+//! use nom::Parser;
+//!
+//! let mut parser = Nmea0183ParserBuilder::new().build(NmeaSentence::parse);
+//!
+//! let result: IResult<_, _> = parser.parse("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C\r\n");
+//! let Ok((_, NmeaSentence::VDM(vdm))) = result else {
+//!     panic!("Expected a VDM sentence");
+//! };
+//!
+//! let Ok(AisMessage::PositionReport(report)) = vdm.message() else {
+//!     panic!("Expected a position report");
+//! };
+//! assert_eq!(report.mmsi, 477553000);
+//! assert_eq!(report.true_heading, Some(181));
+//! ```
+
+mod aid_to_navigation;
+mod bits;
+mod position;
+mod static_data;
+
+pub use aid_to_navigation::AidToNavigationReport;
+pub use bits::MAX_PAYLOAD_BITS;
+pub use position::{ClassBPositionReport, ExtendedClassBPositionReport, PositionReport};
+pub use static_data::{Eta, StaticAndVoyageData, StaticDataPart, StaticDataReport};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use bits::{BitReader, Payload};
+
+/// A decoded AIS message.
+///
+/// ## Supported Message Types
+///
+/// | Variant                      | Message Type | Description                            |
+/// |------------------------------|--------------|----------------------------------------|
+/// | PositionReport               | 1, 2, 3      | Position Report Class A                |
+/// | StaticAndVoyageData          | 5            | Static and Voyage Related Data         |
+/// | ClassBPositionReport         | 18           | Standard Class B CS Position Report    |
+/// | ExtendedClassBPositionReport | 19           | Extended Class B CS Position Report    |
+/// | AidToNavigationReport        | 21           | Aid-to-Navigation Report               |
+/// | StaticDataReport             | 24           | Static Data Report                     |
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum AisMessage {
+    /// Types 1, 2 and 3 - Position Report Class A
+    PositionReport(PositionReport),
+    /// Type 5 - Static and Voyage Related Data
+    StaticAndVoyageData(StaticAndVoyageData),
+    /// Type 18 - Standard Class B CS Position Report
+    ClassBPositionReport(ClassBPositionReport),
+    /// Type 19 - Extended Class B CS Position Report
+    ExtendedClassBPositionReport(ExtendedClassBPositionReport),
+    /// Type 21 - Aid-to-Navigation Report
+    AidToNavigationReport(AidToNavigationReport),
+    /// Type 24 - Static Data Report
+    StaticDataReport(StaticDataReport),
+}
+
+impl AisMessage {
+    /// Decodes an AIS message from its 6-bit armored payload.
+    ///
+    /// The payload of a multi-sentence message is the concatenation of the payloads
+    /// of all its sentences, and `fill_bits` is the fill bits count of the last one.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The armored payload, such as `b"177KQJ5000G?tO`K>RA1wUbN0TKH"`
+    /// * `fill_bits` - The number of fill bits at the end of the payload (0-5)
+    pub fn decode(payload: &[u8], fill_bits: u8) -> Result<Self, AisError> {
+        let payload = Payload::from_armored(payload, fill_bits)?;
+        let mut reader = payload.reader();
+        let message_type = payload.reader().uint(6) as u8;
+
+        let min_len = match message_type {
+            1..=3 | 18 => 168,
+            5 => 420,
+            19 => 312,
+            21 => 272,
+            24 => 160,
+            _ => return Err(AisError::UnsupportedMessageType(message_type)),
+        };
+
+        if payload.len() < min_len {
+            return Err(AisError::Truncated {
+                message_type,
+                len: payload.len(),
+            });
+        }
+
+        let message = match message_type {
+            1..=3 => AisMessage::PositionReport(PositionReport::decode(&mut reader)),
+            5 => AisMessage::StaticAndVoyageData(StaticAndVoyageData::decode(&mut reader)),
+            18 => AisMessage::ClassBPositionReport(ClassBPositionReport::decode(&mut reader)),
+            19 => AisMessage::ExtendedClassBPositionReport(ExtendedClassBPositionReport::decode(
+                &mut reader,
+            )),
+            21 => AisMessage::AidToNavigationReport(AidToNavigationReport::decode(
+                &mut reader,
+                payload.len(),
+            )),
+            _ => AisMessage::StaticDataReport(StaticDataReport::decode(&mut reader)),
+        };
+
+        Ok(message)
+    }
+
+    /// Returns the MMSI of the station that sent the message.
+    pub fn mmsi(&self) -> u32 {
+        match self {
+            AisMessage::PositionReport(report) => report.mmsi,
+            AisMessage::StaticAndVoyageData(data) => data.mmsi,
+            AisMessage::ClassBPositionReport(report) => report.mmsi,
+            AisMessage::ExtendedClassBPositionReport(report) => report.mmsi,
+            AisMessage::AidToNavigationReport(report) => report.mmsi,
+            AisMessage::StaticDataReport(report) => report.mmsi,
+        }
+    }
+}
+
+/// Errors that can occur while decoding an AIS message payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AisError {
+    /// The payload contains a character that is not part of the 6-bit armoring.
+    InvalidCharacter(u8),
+
+    /// The payload is longer than [`MAX_PAYLOAD_BITS`], or the fill bits count is invalid.
+    InvalidLength,
+
+    /// The payload is shorter than required by its message type.
+    Truncated {
+        /// The message type of the payload
+        message_type: u8,
+        /// The length of the payload in bits
+        len: usize,
+    },
+
+    /// The message type is not decoded by this crate.
+    UnsupportedMessageType(u8),
+
+    /// The sentence is only one fragment of a multi-sentence message.
+    ///
+    /// The fragments are joined by a [`Reassembler`](crate::nmea_content::Reassembler).
+    Fragmented,
+}
+
+/// Navigation status of a [`PositionReport`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NavigationStatus {
+    /// 0 - Under way using engine
+    UnderWayUsingEngine,
+    /// 1 - At anchor
+    AtAnchor,
+    /// 2 - Not under command
+    NotUnderCommand,
+    /// 3 - Restricted manoeuverability
+    RestrictedManoeuverability,
+    /// 4 - Constrained by her draught
+    ConstrainedByDraught,
+    /// 5 - Moored
+    Moored,
+    /// 6 - Aground
+    Aground,
+    /// 7 - Engaged in fishing
+    EngagedInFishing,
+    /// 8 - Under way sailing
+    UnderWaySailing,
+    /// 11 - Power-driven vessel towing astern
+    TowingAstern,
+    /// 12 - Power-driven vessel pushing ahead or towing alongside
+    PushingAhead,
+    /// 14 - AIS-SART, MOB-AIS or EPIRB-AIS active
+    AisSartActive,
+    #[default]
+    /// 15 - Not defined
+    NotDefined,
+    /// 9, 10 and 13 - Reserved for future use
+    Reserved(u8),
+}
+
+impl From<u8> for NavigationStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0 => NavigationStatus::UnderWayUsingEngine,
+            1 => NavigationStatus::AtAnchor,
+            2 => NavigationStatus::NotUnderCommand,
+            3 => NavigationStatus::RestrictedManoeuverability,
+            4 => NavigationStatus::ConstrainedByDraught,
+            5 => NavigationStatus::Moored,
+            6 => NavigationStatus::Aground,
+            7 => NavigationStatus::EngagedInFishing,
+            8 => NavigationStatus::UnderWaySailing,
+            11 => NavigationStatus::TowingAstern,
+            12 => NavigationStatus::PushingAhead,
+            14 => NavigationStatus::AisSartActive,
+            15 => NavigationStatus::NotDefined,
+            _ => NavigationStatus::Reserved(status),
+        }
+    }
+}
+
+/// Type of electronic position fixing device
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EpfdType {
+    #[default]
+    /// 0 - Undefined
+    Undefined,
+    /// 1 - GPS
+    Gps,
+    /// 2 - GLONASS
+    Glonass,
+    /// 3 - Combined GPS/GLONASS
+    CombinedGpsGlonass,
+    /// 4 - Loran-C
+    LoranC,
+    /// 5 - Chayka
+    Chayka,
+    /// 6 - Integrated navigation system
+    IntegratedNavigation,
+    /// 7 - Surveyed
+    Surveyed,
+    /// 8 - Galileo
+    Galileo,
+    /// 15 - Internal GNSS
+    InternalGnss,
+    /// 9 to 14 - Reserved for future use
+    Reserved(u8),
+}
+
+impl From<u8> for EpfdType {
+    fn from(epfd: u8) -> Self {
+        match epfd {
+            0 => EpfdType::Undefined,
+            1 => EpfdType::Gps,
+            2 => EpfdType::Glonass,
+            3 => EpfdType::CombinedGpsGlonass,
+            4 => EpfdType::LoranC,
+            5 => EpfdType::Chayka,
+            6 => EpfdType::IntegratedNavigation,
+            7 => EpfdType::Surveyed,
+            8 => EpfdType::Galileo,
+            15 => EpfdType::InternalGnss,
+            _ => EpfdType::Reserved(epfd),
+        }
+    }
+}
+
+/// Dimensions of a vessel or an aid-to-navigation, in meters from the position reference point
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Dimensions {
+    /// Distance to the bow
+    pub to_bow: u16,
+    /// Distance to the stern
+    pub to_stern: u16,
+    /// Distance to port
+    pub to_port: u8,
+    /// Distance to starboard
+    pub to_starboard: u8,
+}
+
+impl Dimensions {
+    fn decode(reader: &mut BitReader<'_>) -> Self {
+        Dimensions {
+            to_bow: reader.uint(9) as u16,
+            to_stern: reader.uint(9) as u16,
+            to_port: reader.uint(6) as u8,
+            to_starboard: reader.uint(6) as u8,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nmea_content::Location;
+
+    #[test]
+    fn test_position_report() {
+        let result = AisMessage::decode(b"177KQJ5000G?tO`K>RA1wUbN0TKH", 0);
+        let Ok(AisMessage::PositionReport(report)) = result else {
+            panic!("Failed: {result:?}");
+        };
+
+        assert_eq!(report.message_type, 1);
+        assert_eq!(report.mmsi, 477553000);
+        assert_eq!(report.navigation_status, NavigationStatus::Moored);
+        assert_eq!(report.rate_of_turn, Some(0.0));
+        assert_eq!(report.speed_over_ground, Some(0.0));
+        assert!(!report.position_accuracy);
+        let location = report.location.unwrap();
+        assert!((location.latitude - 47.582833).abs() < 1e-6, "{location:?}");
+        assert!(
+            (location.longitude - -122.345833).abs() < 1e-6,
+            "{location:?}"
+        );
+        assert_eq!(report.course_over_ground, Some(51.0));
+        assert_eq!(report.true_heading, Some(181));
+        assert_eq!(report.timestamp, Some(15));
+    }
+
+    #[test]
+    fn test_static_and_voyage_data() {
+        let payload = b"55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp888888888880";
+        let result = AisMessage::decode(payload, 2);
+        let Ok(AisMessage::StaticAndVoyageData(data)) = result else {
+            panic!("Failed: {result:?}");
+        };
+
+        assert_eq!(data.mmsi, 351759000);
+        assert_eq!(data.imo_number, Some(9134270));
+        assert_eq!(data.call_sign, "3FOF8");
+        assert_eq!(data.vessel_name, "EVER DIADEM");
+        assert_eq!(data.ship_type, 70);
+        assert_eq!(
+            data.dimensions,
+            Dimensions {
+                to_bow: 225,
+                to_stern: 70,
+                to_port: 1,
+                to_starboard: 31,
+            }
+        );
+        assert_eq!(data.epfd, EpfdType::Gps);
+        assert_eq!(
+            data.eta,
+            Eta {
+                month: Some(5),
+                day: Some(15),
+                hour: Some(14),
+                minute: Some(0),
+            }
+        );
+        assert_eq!(data.draught, Some(12.2));
+        assert_eq!(data.destination, "NEW YORK");
+        assert!(data.dte_ready);
+    }
+
+    #[test]
+    fn test_class_b_position_reports() {
+        let result = AisMessage::decode(b"B5NJ;PP005l4ot5Isbl03wsUkP06", 0);
+        let Ok(AisMessage::ClassBPositionReport(report)) = result else {
+            panic!("Failed: {result:?}");
+        };
+
+        assert_eq!(report.mmsi, 367430530);
+        assert_eq!(report.speed_over_ground, Some(0.0));
+        assert_eq!(report.true_heading, None);
+        assert!(report.cs_unit);
+
+        let payload = b"C5N3SRgPEnJGEBT>NhWAwwo862PaLELTBJ:V00000000S0D:R220";
+        let result = AisMessage::decode(payload, 0);
+        let Ok(AisMessage::ExtendedClassBPositionReport(report)) = result else {
+            panic!("Failed: {result:?}");
+        };
+
+        assert_eq!(report.mmsi, 367059850);
+        assert_eq!(report.speed_over_ground, Some(8.7));
+        assert_eq!(report.course_over_ground, Some(335.9));
+        assert_eq!(report.true_heading, None);
+        assert_eq!(report.timestamp, Some(46));
+        assert_eq!(report.vessel_name, "CAPT.J.RIMES");
+        assert_eq!(report.ship_type, 70);
+        assert_eq!(
+            report.dimensions,
+            Dimensions {
+                to_bow: 5,
+                to_stern: 21,
+                to_port: 4,
+                to_starboard: 4,
+            }
+        );
+        assert_eq!(report.epfd, EpfdType::Gps);
+        let location = report.location.unwrap();
+        assert!((location.latitude - 29.543695).abs() < 1e-6, "{location:?}");
+        assert!(
+            (location.longitude - -88.810392).abs() < 1e-6,
+            "{location:?}"
+        );
+    }
+
+    #[test]
+    fn test_aid_to_navigation_report() {
+        let payload = b"E>jfB@PW0c4SPb4WW@0TR@70VRpgtTAP>H6f050`@Cg011F51CQ1A0";
+        let result = AisMessage::decode(payload, 4);
+        let Ok(AisMessage::AidToNavigationReport(report)) = result else {
+            panic!("Failed: {result:?}");
+        };
+
+        assert_eq!(report.mmsi, 992711234);
+        assert_eq!(report.aid_type, 1);
+        assert_eq!(report.name, "NAVIGATION AID NAME1EXTENDED");
+        assert_eq!(
+            report.location,
+            Some(Location {
+                latitude: 50.25,
+                longitude: -1.5,
+            })
+        );
+        assert_eq!(
+            report.dimensions,
+            Dimensions {
+                to_bow: 5,
+                to_stern: 5,
+                to_port: 2,
+                to_starboard: 2,
+            }
+        );
+        assert_eq!(report.epfd, EpfdType::Surveyed);
+        assert_eq!(report.timestamp, Some(30));
+        assert!(!report.off_position);
+        assert!(report.virtual_aid);
+    }
+
+    #[test]
+    fn test_static_data_report() {
+        let result = AisMessage::decode(b"H42O55i18tMET00000000000000", 2);
+        let Ok(AisMessage::StaticDataReport(report)) = result else {
+            panic!("Failed: {result:?}");
+        };
+
+        assert_eq!(report.mmsi, 271041815);
+        assert_eq!(
+            report.part,
+            StaticDataPart::A {
+                vessel_name: "PROGUY".try_into().unwrap(),
+            }
+        );
+
+        let result = AisMessage::decode(b"H42O55lti4hhhilD3nink000?050", 0);
+        let Ok(AisMessage::StaticDataReport(report)) = result else {
+            panic!("Failed: {result:?}");
+        };
+
+        let StaticDataPart::B {
+            ship_type,
+            call_sign,
+            dimensions,
+            mothership_mmsi,
+            ..
+        } = report.part
+        else {
+            panic!("Failed: {report:?}");
+        };
+        assert_eq!(ship_type, 60);
+        assert_eq!(call_sign, "TC6163");
+        assert_eq!(
+            dimensions,
+            Dimensions {
+                to_bow: 0,
+                to_stern: 15,
+                to_port: 0,
+                to_starboard: 5,
+            }
+        );
+        assert_eq!(mothership_mmsi, None);
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let cases = [
+            (
+                &b"177KQJ5000G?tO`K>RA1wUbN0TK"[..],
+                0,
+                AisError::Truncated {
+                    message_type: 1,
+                    len: 162,
+                },
+            ),
+            (&b"4000"[..], 0, AisError::UnsupportedMessageType(4)),
+            (&b"177KQ!"[..], 0, AisError::InvalidCharacter(b'!')),
+        ];
+
+        for (payload, fill_bits, expected) in cases {
+            let result = AisMessage::decode(payload, fill_bits);
+            assert_eq!(result, Err(expected), "Failed: {payload:?}");
+        }
+    }
+}
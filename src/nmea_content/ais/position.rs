@@ -0,0 +1,244 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::nmea_content::{
+    Location,
+    ais::{Dimensions, EpfdType, NavigationStatus, bits::BitReader},
+};
+
+/// Types 1, 2 and 3 - Position Report Class A
+///
+/// <https://gpsd.gitlab.io/gpsd/AIVDM.html#_types_1_2_and_3_position_report_class_a>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PositionReport {
+    /// Message type, 1 and 2 for scheduled reports, 3 for reports in response to an interrogation
+    pub message_type: u8,
+    /// Number of times the message was repeated
+    pub repeat_indicator: u8,
+    /// MMSI of the vessel
+    pub mmsi: u32,
+    /// Navigation status
+    pub navigation_status: NavigationStatus,
+    /// Rate of turn in degrees per minute, positive to starboard
+    ///
+    /// Values of about ±720 indicate a turn faster than 5° per 30 seconds,
+    /// reported without a turn indicator.
+    pub rate_of_turn: Option<f32>,
+    /// Speed over ground in knots, 102.2 meaning 102.2 knots or higher
+    pub speed_over_ground: Option<f32>,
+    /// Position accuracy, `true` for a DGPS-quality fix better than 10 meters
+    pub position_accuracy: bool,
+    /// Location (latitude and longitude)
+    pub location: Option<Location>,
+    /// Course over ground in degrees true
+    pub course_over_ground: Option<f32>,
+    /// True heading in degrees
+    pub true_heading: Option<u16>,
+    /// Second of the UTC minute when the report was generated
+    pub timestamp: Option<u8>,
+    /// Receiver autonomous integrity monitoring (RAIM) flag
+    pub raim: bool,
+}
+
+impl PositionReport {
+    pub(super) fn decode(reader: &mut BitReader<'_>) -> Self {
+        let message_type = reader.uint(6) as u8;
+        let repeat_indicator = reader.uint(2) as u8;
+        let mmsi = reader.uint(30);
+        let navigation_status = NavigationStatus::from(reader.uint(4) as u8);
+        let rate_of_turn = match reader.int(8) {
+            -128 => None,
+            rot => Some((rot as f32 / 4.733).powi(2).copysign(rot as f32)),
+        };
+        let speed_over_ground = reader.speed_over_ground();
+        let position_accuracy = reader.flag();
+        let location = reader.location();
+        let course_over_ground = reader.course_over_ground();
+        let true_heading = reader.true_heading();
+        let timestamp = reader.timestamp();
+        // Maneuver indicator and spare bits
+        reader.skip(5);
+        let raim = reader.flag();
+
+        PositionReport {
+            message_type,
+            repeat_indicator,
+            mmsi,
+            navigation_status,
+            rate_of_turn,
+            speed_over_ground,
+            position_accuracy,
+            location,
+            course_over_ground,
+            true_heading,
+            timestamp,
+            raim,
+        }
+    }
+}
+
+/// Type 18 - Standard Class B CS Position Report
+///
+/// <https://gpsd.gitlab.io/gpsd/AIVDM.html#_type_18_standard_class_b_cs_position_report>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ClassBPositionReport {
+    /// Number of times the message was repeated
+    pub repeat_indicator: u8,
+    /// MMSI of the vessel
+    pub mmsi: u32,
+    /// Speed over ground in knots, 102.2 meaning 102.2 knots or higher
+    pub speed_over_ground: Option<f32>,
+    /// Position accuracy, `true` for a DGPS-quality fix better than 10 meters
+    pub position_accuracy: bool,
+    /// Location (latitude and longitude)
+    pub location: Option<Location>,
+    /// Course over ground in degrees true
+    pub course_over_ground: Option<f32>,
+    /// True heading in degrees
+    pub true_heading: Option<u16>,
+    /// Second of the UTC minute when the report was generated
+    pub timestamp: Option<u8>,
+    /// Carrier-sense (CS) unit, `false` for a SOTDMA unit
+    pub cs_unit: bool,
+    /// Whether the unit has a display for messages 12 and 14
+    pub display: bool,
+    /// Whether the unit is attached to a VHF radio with DSC capability
+    pub dsc: bool,
+    /// Whether the unit can use any part of the marine band
+    pub band: bool,
+    /// Whether the unit can accept channel assignment with message 22
+    pub message_22: bool,
+    /// Whether the unit is in assigned mode
+    pub assigned: bool,
+    /// Receiver autonomous integrity monitoring (RAIM) flag
+    pub raim: bool,
+}
+
+impl ClassBPositionReport {
+    pub(super) fn decode(reader: &mut BitReader<'_>) -> Self {
+        reader.skip(6);
+        let repeat_indicator = reader.uint(2) as u8;
+        let mmsi = reader.uint(30);
+        // Regional reserved bits
+        reader.skip(8);
+        let speed_over_ground = reader.speed_over_ground();
+        let position_accuracy = reader.flag();
+        let location = reader.location();
+        let course_over_ground = reader.course_over_ground();
+        let true_heading = reader.true_heading();
+        let timestamp = reader.timestamp();
+        // Regional reserved bits
+        reader.skip(2);
+        let cs_unit = reader.flag();
+        let display = reader.flag();
+        let dsc = reader.flag();
+        let band = reader.flag();
+        let message_22 = reader.flag();
+        let assigned = reader.flag();
+        let raim = reader.flag();
+
+        ClassBPositionReport {
+            repeat_indicator,
+            mmsi,
+            speed_over_ground,
+            position_accuracy,
+            location,
+            course_over_ground,
+            true_heading,
+            timestamp,
+            cs_unit,
+            display,
+            dsc,
+            band,
+            message_22,
+            assigned,
+            raim,
+        }
+    }
+}
+
+/// Type 19 - Extended Class B CS Position Report
+///
+/// <https://gpsd.gitlab.io/gpsd/AIVDM.html#_type_19_extended_class_b_cs_position_report>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExtendedClassBPositionReport {
+    /// Number of times the message was repeated
+    pub repeat_indicator: u8,
+    /// MMSI of the vessel
+    pub mmsi: u32,
+    /// Speed over ground in knots, 102.2 meaning 102.2 knots or higher
+    pub speed_over_ground: Option<f32>,
+    /// Position accuracy, `true` for a DGPS-quality fix better than 10 meters
+    pub position_accuracy: bool,
+    /// Location (latitude and longitude)
+    pub location: Option<Location>,
+    /// Course over ground in degrees true
+    pub course_over_ground: Option<f32>,
+    /// True heading in degrees
+    pub true_heading: Option<u16>,
+    /// Second of the UTC minute when the report was generated
+    pub timestamp: Option<u8>,
+    /// Name of the vessel
+    pub vessel_name: heapless::String<20>,
+    /// Ship and cargo type code
+    pub ship_type: u8,
+    /// Dimensions of the vessel relative to the position reference point
+    pub dimensions: Dimensions,
+    /// Type of electronic position fixing device
+    pub epfd: EpfdType,
+    /// Receiver autonomous integrity monitoring (RAIM) flag
+    pub raim: bool,
+    /// Whether the data terminal equipment is ready
+    pub dte_ready: bool,
+    /// Whether the unit is in assigned mode
+    pub assigned: bool,
+}
+
+impl ExtendedClassBPositionReport {
+    pub(super) fn decode(reader: &mut BitReader<'_>) -> Self {
+        reader.skip(6);
+        let repeat_indicator = reader.uint(2) as u8;
+        let mmsi = reader.uint(30);
+        // Regional reserved bits
+        reader.skip(8);
+        let speed_over_ground = reader.speed_over_ground();
+        let position_accuracy = reader.flag();
+        let location = reader.location();
+        let course_over_ground = reader.course_over_ground();
+        let true_heading = reader.true_heading();
+        let timestamp = reader.timestamp();
+        // Regional reserved bits
+        reader.skip(4);
+        let vessel_name = reader.text(20);
+        let ship_type = reader.uint(8) as u8;
+        let dimensions = Dimensions::decode(reader);
+        let epfd = EpfdType::from(reader.uint(4) as u8);
+        let raim = reader.flag();
+        let dte_ready = !reader.flag();
+        let assigned = reader.flag();
+
+        ExtendedClassBPositionReport {
+            repeat_indicator,
+            mmsi,
+            speed_over_ground,
+            position_accuracy,
+            location,
+            course_over_ground,
+            true_heading,
+            timestamp,
+            vessel_name,
+            ship_type,
+            dimensions,
+            epfd,
+            raim,
+            dte_ready,
+            assigned,
+        }
+    }
+}
@@ -0,0 +1,194 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::nmea_content::ais::{Dimensions, EpfdType, bits::BitReader};
+
+/// Type 5 - Static and Voyage Related Data
+///
+/// <https://gpsd.gitlab.io/gpsd/AIVDM.html#_type_5_static_and_voyage_related_data>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StaticAndVoyageData {
+    /// Number of times the message was repeated
+    pub repeat_indicator: u8,
+    /// MMSI of the vessel
+    pub mmsi: u32,
+    /// AIS version of the transmitter, 0 for ITU-R M.1371-1
+    pub ais_version: u8,
+    /// IMO ship identification number
+    pub imo_number: Option<u32>,
+    /// Radio call sign
+    pub call_sign: heapless::String<7>,
+    /// Name of the vessel
+    pub vessel_name: heapless::String<20>,
+    /// Ship and cargo type code
+    pub ship_type: u8,
+    /// Dimensions of the vessel relative to the position reference point
+    pub dimensions: Dimensions,
+    /// Type of electronic position fixing device
+    pub epfd: EpfdType,
+    /// Estimated time of arrival in UTC
+    pub eta: Eta,
+    /// Maximum present static draught in meters
+    pub draught: Option<f32>,
+    /// Destination
+    pub destination: heapless::String<20>,
+    /// Whether the data terminal equipment is ready
+    pub dte_ready: bool,
+}
+
+impl StaticAndVoyageData {
+    pub(super) fn decode(reader: &mut BitReader<'_>) -> Self {
+        reader.skip(6);
+        let repeat_indicator = reader.uint(2) as u8;
+        let mmsi = reader.uint(30);
+        let ais_version = reader.uint(2) as u8;
+        let imo_number = Some(reader.uint(30)).filter(|&imo| imo != 0);
+        let call_sign = reader.text(7);
+        let vessel_name = reader.text(20);
+        let ship_type = reader.uint(8) as u8;
+        let dimensions = Dimensions::decode(reader);
+        let epfd = EpfdType::from(reader.uint(4) as u8);
+        let eta = Eta::decode(reader);
+        let draught = Some(reader.uint(8)).filter(|&draught| draught != 0);
+        let destination = reader.text(20);
+        let dte_ready = !reader.flag();
+
+        StaticAndVoyageData {
+            repeat_indicator,
+            mmsi,
+            ais_version,
+            imo_number,
+            call_sign,
+            vessel_name,
+            ship_type,
+            dimensions,
+            epfd,
+            eta,
+            draught: draught.map(|draught| draught as f32 / 10.0),
+            destination,
+            dte_ready,
+        }
+    }
+}
+
+/// Estimated time of arrival of [`StaticAndVoyageData`], without a year
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Eta {
+    /// Month (1-12)
+    pub month: Option<u8>,
+    /// Day of the month (1-31)
+    pub day: Option<u8>,
+    /// Hour (0-23)
+    pub hour: Option<u8>,
+    /// Minute (0-59)
+    pub minute: Option<u8>,
+}
+
+impl Eta {
+    fn decode(reader: &mut BitReader<'_>) -> Self {
+        let month = reader.uint(4) as u8;
+        let day = reader.uint(5) as u8;
+        let hour = reader.uint(5) as u8;
+        let minute = reader.uint(6) as u8;
+
+        Eta {
+            month: Some(month).filter(|month| (1..=12).contains(month)),
+            day: Some(day).filter(|&day| day != 0),
+            hour: Some(hour).filter(|&hour| hour < 24),
+            minute: Some(minute).filter(|&minute| minute < 60),
+        }
+    }
+}
+
+/// Type 24 - Static Data Report
+///
+/// Class B units send their static data in two separate messages, part A and part B.
+///
+/// <https://gpsd.gitlab.io/gpsd/AIVDM.html#_type_24_static_data_report>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticDataReport {
+    /// Number of times the message was repeated
+    pub repeat_indicator: u8,
+    /// MMSI of the vessel
+    pub mmsi: u32,
+    /// Content of the report
+    pub part: StaticDataPart,
+}
+
+/// Content of a [`StaticDataReport`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum StaticDataPart {
+    /// Part A - Name of the vessel
+    A {
+        /// Name of the vessel
+        vessel_name: heapless::String<20>,
+    },
+    /// Part B - Type, vendor and dimensions of the vessel
+    B {
+        /// Ship and cargo type code
+        ship_type: u8,
+        /// Vendor ID of the unit manufacturer
+        vendor_id: heapless::String<3>,
+        /// Unit model code
+        unit_model: u8,
+        /// Unit serial number
+        serial_number: u32,
+        /// Radio call sign
+        call_sign: heapless::String<7>,
+        /// Dimensions of the vessel relative to the position reference point,
+        /// not reported by auxiliary craft
+        dimensions: Dimensions,
+        /// MMSI of the mothership, only reported by auxiliary craft
+        mothership_mmsi: Option<u32>,
+    },
+}
+
+impl StaticDataReport {
+    pub(super) fn decode(reader: &mut BitReader<'_>) -> Self {
+        reader.skip(6);
+        let repeat_indicator = reader.uint(2) as u8;
+        let mmsi = reader.uint(30);
+
+        let part = if reader.uint(2) == 0 {
+            StaticDataPart::A {
+                vessel_name: reader.text(20),
+            }
+        } else {
+            let ship_type = reader.uint(8) as u8;
+            let vendor_id = reader.text(3);
+            let unit_model = reader.uint(4) as u8;
+            let serial_number = reader.uint(20);
+            let call_sign = reader.text(7);
+
+            // Auxiliary craft, with an MMSI of the form 98XXXYYYY, report
+            // the MMSI of their mothership instead of their dimensions
+            let (dimensions, mothership_mmsi) = if mmsi / 10_000_000 == 98 {
+                (Dimensions::default(), Some(reader.uint(30)))
+            } else {
+                (Dimensions::decode(reader), None)
+            };
+
+            StaticDataPart::B {
+                ship_type,
+                vendor_id,
+                unit_model,
+                serial_number,
+                call_sign,
+                dimensions,
+                mothership_mmsi,
+            }
+        };
+
+        StaticDataReport {
+            repeat_indicator,
+            mmsi,
+            part,
+        }
+    }
+}
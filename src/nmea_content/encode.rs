@@ -0,0 +1,176 @@
+use std::fmt::{self, Write};
+
+use crate::{Encoder, NmeaEncode, encode::write_escaped, nmea_content::Location};
+
+pub fn write_with_unit<T>(unit: char) -> impl Fn(&Option<T>, &mut Encoder<'_>) -> fmt::Result
+where
+    T: NmeaEncode,
+{
+    move |value, e| {
+        value.encode(e)?;
+        e.write_char(',')?;
+        match value {
+            Some(_) => e.write_char(unit),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn write_location(location: &Option<Location>, e: &mut Encoder<'_>) -> fmt::Result {
+    let Some(location) = location else {
+        return e.write_str(",,,");
+    };
+
+    let lat_dir = if location.latitude.is_sign_negative() {
+        'S'
+    } else {
+        'N'
+    };
+    write_degrees_minutes(location.latitude, 2, e)?;
+    write!(e, ",{lat_dir},")?;
+
+    let lon_dir = if location.longitude.is_sign_negative() {
+        'W'
+    } else {
+        'E'
+    };
+    write_degrees_minutes(location.longitude, 3, e)?;
+    write!(e, ",{lon_dir}")
+}
+
+/// Writes the absolute value of an angle in the `DDDMM.MMMMM` format,
+/// with `width` digits for the degrees.
+fn write_degrees_minutes(angle: f64, width: usize, e: &mut Encoder<'_>) -> fmt::Result {
+    let precision = e.precision().minutes;
+    let scale = 10f64.powi(precision as i32);
+
+    let angle = angle.abs();
+    let mut degrees = angle.trunc();
+    let mut minutes = ((angle - degrees) * 60.0 * scale).round() / scale;
+
+    // Rounding may carry the minutes over into the next degree
+    if minutes >= 60.0 {
+        degrees += 1.0;
+        minutes -= 60.0;
+    }
+
+    let minutes_width = if precision == 0 { 2 } else { precision + 3 };
+    write!(
+        e,
+        "{degrees:0width$}{minutes:0minutes_width$.precision$}",
+        degrees = degrees as u16
+    )
+}
+
+impl<T, const N: usize> NmeaEncode for heapless::Vec<T, N>
+where
+    T: NmeaEncode,
+{
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        self.as_slice().encode(e)
+    }
+
+    fn encode_preceded(&self, separator: char, e: &mut Encoder<'_>) -> fmt::Result {
+        self.as_slice().encode_preceded(separator, e)
+    }
+}
+
+impl<const N: usize> NmeaEncode for heapless::String<N> {
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        write_escaped(self, e)
+    }
+}
+
+impl NmeaEncode for time::Time {
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        let precision = e.precision().seconds.min(3);
+        write!(
+            e,
+            "{:02}{:02}{:02}",
+            self.hour(),
+            self.minute(),
+            self.second()
+        )?;
+
+        if precision > 0 {
+            // Parsing truncates the seconds to whole milliseconds, so the fraction is
+            // rounded to keep round-trips stable, without carrying into the seconds
+            let scale = 10u16.pow(3 - precision as u32);
+            let fraction =
+                ((self.millisecond() + scale / 2) / scale).min(10u16.pow(precision as u32) - 1);
+
+            write!(e, ".{fraction:0precision$}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl NmeaEncode for time::Date {
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        write!(
+            e,
+            "{:02}{:02}{:02}",
+            self.day(),
+            self.month() as u8,
+            self.year().rem_euclid(100)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Encoder, NmeaEncode, Precision, nmea_content::Location};
+
+    use super::write_location;
+
+    #[test]
+    fn test_encode_location() {
+        let cases = [
+            (
+                Some(Location {
+                    latitude: 48.1173,
+                    longitude: 11.516666666666667,
+                }),
+                "4807.03800,N,01131.00000,E",
+            ),
+            (
+                Some(Location {
+                    latitude: -33.999999999,
+                    longitude: -151.5,
+                }),
+                "3400.00000,S,15130.00000,W",
+            ),
+            (None, ",,,"),
+        ];
+
+        for (location, expected) in cases {
+            let mut output = String::new();
+            write_location(&location, &mut Encoder::new(&mut output)).unwrap();
+            assert_eq!(output, expected, "Failed: {location:?}");
+        }
+    }
+
+    #[test]
+    fn test_encode_time() {
+        let cases = [
+            (0, "123456"),
+            (1, "123456.8"),
+            (2, "123456.78"),
+            (3, "123456.779"),
+        ];
+
+        let time = time::Time::from_hms_milli(12, 34, 56, 779).unwrap();
+        for (seconds, expected) in cases {
+            let precision = Precision {
+                seconds,
+                ..Precision::default()
+            };
+
+            let mut output = String::new();
+            time.encode(&mut Encoder::with_precision(&mut output, precision))
+                .unwrap();
+            assert_eq!(output, expected, "Failed: {seconds:?}");
+        }
+    }
+}
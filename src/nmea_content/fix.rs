@@ -0,0 +1,330 @@
+//! # Navigation Fix Aggregation
+//!
+//! A GNSS receiver reports each navigation epoch across several sentences: `GGA` carries the
+//! position and fix quality, `RMC` the velocity and date, `GSA` the dilutions of precision and
+//! used satellites, and so on. A [`FixAggregator`] merges them into a single [`NavigationFix`]
+//! per epoch, detecting the epoch boundaries by the fix time of the sentences.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::nmea_content::{FaaMode, FixMode, Location, NmeaSentence, Quality, Status};
+
+/// Maximum number of used satellites reported in a [`NavigationFix`].
+///
+/// Receivers send one `GSA` sentence of up to 12 satellites per GNSS system.
+pub const FIX_SATELLITES_CAPACITY: usize = 72;
+
+/// Navigation data of a single epoch, merged from all its sentences
+///
+/// Fields are [`None`] when no sentence of the epoch reported them.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NavigationFix {
+    /// Fix time in UTC, set for every fix returned by a [`FixAggregator`]
+    pub fix_time: Option<time::Time>,
+    /// Fix date and time in UTC, once a date was reported by `RMC` or `ZDA`
+    pub date_time: Option<time::OffsetDateTime>,
+    /// Location (latitude and longitude)
+    pub location: Option<Location>,
+    /// Altitude above/below mean sea level (geoid) in meters
+    pub altitude: Option<f32>,
+    /// Geoidal separation in meters
+    pub geoidal_separation: Option<f32>,
+    /// Speed over ground in knots
+    pub speed_over_ground: Option<f32>,
+    /// Course over ground in degrees true
+    pub course_over_ground: Option<f32>,
+    /// Course over ground in degrees magnetic
+    pub course_over_ground_magnetic: Option<f32>,
+    /// Magnetic variation in degrees, negative values indicate a westerly variation
+    pub magnetic_variation: Option<f32>,
+    /// Status of the fix, reported by `RMC` and `GLL`
+    pub status: Option<Status>,
+    /// GPS quality indicator, reported by `GGA`
+    pub fix_quality: Option<Quality>,
+    /// Fix mode, reported by `GSA`
+    pub fix_mode: Option<FixMode>,
+    /// FAA mode indicator, reported by `RMC`, `GLL` and `VTG`
+    pub faa_mode: Option<FaaMode>,
+    /// Number of satellites in use, reported by `GGA`
+    pub satellite_count: Option<u8>,
+    /// PRN numbers of the satellites used in the fix, from all the `GSA` sentences of the epoch
+    pub satellites_used: heapless::Vec<u8, FIX_SATELLITES_CAPACITY>,
+    /// Position Dilution of Precision
+    pub pdop: Option<f32>,
+    /// Horizontal Dilution of Precision
+    pub hdop: Option<f32>,
+    /// Vertical Dilution of Precision
+    pub vdop: Option<f32>,
+}
+
+/// Merges the sentences of each navigation epoch into a [`NavigationFix`].
+///
+/// A new epoch starts when a `GGA`, `RMC`, `GLL` or `ZDA` sentence reports a fix time that
+/// differs from the current one; the completed fix of the previous epoch is then returned.
+/// Sentences without a fix time, such as `GSA` and `VTG`, belong to the current epoch.
+///
+/// The last date reported is carried over to the following epochs, and advanced by a day
+/// when the fix time wraps around midnight.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     IResult, NmeaParse,
+///     nmea_content::{FixAggregator, NmeaSentence},
+/// };
+/// use time::{Date, Month};
+///
+/// let sentences = [
+///     "GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,",
+///     "GPZDA,092750.000,28,05,2011,00,00",
+///     "GPGGA,092751.000,5321.6802,N,00630.3371,W,1,8,1.03,61.7,M,55.3,M,,",
+/// ];
+///
+/// let mut aggregator = FixAggregator::new();
+/// let mut fixes = vec![];
+/// for input in sentences {
+///     let result: IResult<_, _> = NmeaSentence::parse(input);
+///     if let Some(fix) = aggregator.push(&result.unwrap().1) {
+///         fixes.push(fix);
+///     }
+/// }
+///
+/// let date = Date::from_calendar_date(2011, Month::May, 28).unwrap();
+///
+/// assert_eq!(fixes.len(), 1);
+/// assert_eq!(fixes[0].date_time, date.with_hms(9, 27, 50).ok().map(|dt| dt.assume_utc()));
+/// assert_eq!(fixes[0].satellite_count, Some(8));
+/// assert_eq!(fixes[0].altitude, Some(61.7));
+///
+/// // The last epoch is returned once the stream ends
+/// let fix = aggregator.flush().unwrap();
+/// assert_eq!(fix.date_time, date.with_hms(9, 27, 51).ok().map(|dt| dt.assume_utc()));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct FixAggregator {
+    /// Fix of the current epoch
+    current: NavigationFix,
+
+    /// Last date reported, carried over to the following epochs
+    date: Option<time::Date>,
+
+    /// Fix time of the previous epoch, to detect midnight
+    previous_time: Option<time::Time>,
+}
+
+impl FixAggregator {
+    /// Creates a new aggregator with no current epoch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merges a sentence into the current epoch.
+    ///
+    /// Returns the fix of the previous epoch if this sentence starts a new one.
+    pub fn push(&mut self, sentence: &NmeaSentence) -> Option<NavigationFix> {
+        let completed = self
+            .fix_time(sentence)
+            .and_then(|time| self.start_epoch(time));
+
+        let fix = &mut self.current;
+        match sentence {
+            NmeaSentence::GGA(gga) => {
+                merge(&mut fix.location, &gga.location);
+                merge(&mut fix.altitude, &gga.altitude);
+                merge(&mut fix.geoidal_separation, &gga.geoidal_separation);
+                merge(&mut fix.satellite_count, &gga.satellite_count);
+                merge(&mut fix.hdop, &gga.hdop);
+                fix.fix_quality = Some(gga.fix_quality.clone());
+            }
+            NmeaSentence::GLL(gll) => {
+                merge(&mut fix.location, &gll.location);
+                fix.status = Some(gll.status.clone());
+                merge(&mut fix.faa_mode, &gll.faa_mode);
+            }
+            NmeaSentence::GSA(gsa) => {
+                fix.fix_mode = Some(gsa.fix_mode.clone());
+                for &prn in &gsa.fix_sats_prn {
+                    // A valid epoch never exceeds the capacity
+                    let _ = fix.satellites_used.push(prn);
+                }
+                merge(&mut fix.pdop, &gsa.pdop);
+                merge(&mut fix.hdop, &gsa.hdop);
+                merge(&mut fix.vdop, &gsa.vdop);
+            }
+            NmeaSentence::RMC(rmc) => {
+                merge(&mut fix.location, &rmc.location);
+                merge(&mut fix.speed_over_ground, &rmc.speed_over_ground);
+                merge(&mut fix.course_over_ground, &rmc.course_over_ground);
+                merge(&mut fix.magnetic_variation, &rmc.magnetic_variation);
+                fix.status = Some(rmc.status.clone());
+                merge(&mut fix.faa_mode, &rmc.faa_mode);
+                merge(&mut self.date, &rmc.fix_date);
+            }
+            NmeaSentence::VTG(vtg) => {
+                merge(&mut fix.speed_over_ground, &vtg.speed_over_ground);
+                merge(&mut fix.course_over_ground, &vtg.course_over_ground_true);
+                merge(
+                    &mut fix.course_over_ground_magnetic,
+                    &vtg.course_over_ground_magnetic,
+                );
+                merge(&mut fix.faa_mode, &vtg.faa_mode);
+            }
+            NmeaSentence::ZDA(zda) => merge(&mut self.date, &zda.date),
+            _ => {}
+        }
+
+        completed
+    }
+
+    /// Returns the fix of the current epoch, ending it.
+    ///
+    /// Returns [`None`] if no sentence with a fix time was received since the last fix.
+    pub fn flush(&mut self) -> Option<NavigationFix> {
+        let mut fix = std::mem::take(&mut self.current);
+        let time = fix.fix_time?;
+        fix.date_time = self.date.map(|date| date.with_time(time).assume_utc());
+        self.previous_time = Some(time);
+        Some(fix)
+    }
+
+    fn fix_time(&self, sentence: &NmeaSentence) -> Option<time::Time> {
+        match sentence {
+            NmeaSentence::GGA(gga) => gga.fix_time,
+            NmeaSentence::GLL(gll) => gll.fix_time,
+            NmeaSentence::RMC(rmc) => rmc.fix_time,
+            NmeaSentence::ZDA(zda) => zda.time,
+            _ => None,
+        }
+    }
+
+    /// Starts the epoch of `time`, returning the fix of the previous one if it differs.
+    fn start_epoch(&mut self, time: time::Time) -> Option<NavigationFix> {
+        let completed = match self.current.fix_time {
+            Some(current) if current != time => self.flush(),
+            Some(_) => return None,
+            None => None,
+        };
+
+        // Fix times going back by more than 12 hours crossed midnight
+        let previous = self.previous_time.or(self.current.fix_time);
+        if previous.is_some_and(|previous| previous - time > time::Duration::hours(12)) {
+            self.date = self.date.and_then(time::Date::next_day);
+        }
+
+        self.current.fix_time = Some(time);
+        completed
+    }
+}
+
+/// Overwrites `field` with `value` if the sentence reported it.
+fn merge<T: Clone>(field: &mut Option<T>, value: &Option<T>) {
+    if value.is_some() {
+        field.clone_from(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month, OffsetDateTime, Time};
+
+    use super::*;
+    use crate::{IResult, NmeaParse};
+
+    fn date_time(year: i32, month: Month, day: u8, time: (u8, u8, u8)) -> OffsetDateTime {
+        let date = Date::from_calendar_date(year, month, day).unwrap();
+        date.with_hms(time.0, time.1, time.2).unwrap().assume_utc()
+    }
+
+    fn aggregate(aggregator: &mut FixAggregator, inputs: &[String]) -> Vec<NavigationFix> {
+        inputs
+            .iter()
+            .filter_map(|input| {
+                let result: IResult<_, _> = NmeaSentence::parse(input.as_str());
+                let (_, sentence) = result.unwrap();
+                aggregator.push(&sentence)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fix_aggregation() {
+        let mut aggregator = FixAggregator::new();
+
+        let fixes = aggregate(
+            &mut aggregator,
+            &[
+                // Before the first epoch, merged into it
+                "GPVTG,054.7,T,034.4,M,005.5,N,010.2,K,A".to_string(),
+                "GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,".to_string(),
+                "GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1,1".to_string(),
+                "GLGSA,A,3,65,66,,,,,,,,,,,2.5,1.3,2.1,2".to_string(),
+                "GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W,A,V".to_string(),
+                "GPGGA,123520,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,".to_string(),
+            ],
+        );
+
+        let [fix] = fixes.as_slice() else {
+            panic!("Expected a single fix: {fixes:?}");
+        };
+        assert_eq!(fix.fix_time, Some(Time::from_hms(12, 35, 19).unwrap()));
+        assert_eq!(
+            fix.date_time,
+            Some(date_time(1994, Month::March, 23, (12, 35, 19)))
+        );
+        assert!(fix.location.is_some());
+        assert_eq!(fix.altitude, Some(545.4));
+        assert_eq!(fix.speed_over_ground, Some(22.4));
+        assert_eq!(fix.course_over_ground, Some(84.4));
+        assert_eq!(fix.course_over_ground_magnetic, Some(34.4));
+        assert_eq!(fix.magnetic_variation, Some(-3.1));
+        assert_eq!(fix.status, Some(Status::Valid));
+        assert_eq!(fix.fix_quality, Some(Quality::GPSFix));
+        assert_eq!(fix.fix_mode, Some(FixMode::Fix3D));
+        assert_eq!(fix.satellite_count, Some(8));
+        assert_eq!(fix.satellites_used, [4, 5, 9, 12, 24, 65, 66]);
+        assert_eq!(
+            (fix.pdop, fix.hdop, fix.vdop),
+            (Some(2.5), Some(1.3), Some(2.1))
+        );
+
+        // The date is carried over to the next epoch
+        let fix = aggregator.flush().unwrap();
+        assert_eq!(
+            fix.date_time,
+            Some(date_time(1994, Month::March, 23, (12, 35, 20)))
+        );
+        assert_eq!(fix.speed_over_ground, None);
+        assert_eq!(aggregator.flush(), None);
+    }
+
+    #[test]
+    fn test_fix_aggregation_midnight() {
+        let mut aggregator = FixAggregator::new();
+
+        let fixes = aggregate(
+            &mut aggregator,
+            &[
+                "GPZDA,235959.00,31,12,2024,00,00".to_string(),
+                "GPGGA,000000,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,".to_string(),
+                "GPGGA,000001,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,".to_string(),
+            ],
+        );
+
+        let dates: Vec<_> = fixes.iter().map(|fix| fix.date_time).collect();
+        assert_eq!(
+            dates,
+            [
+                Some(date_time(2024, Month::December, 31, (23, 59, 59))),
+                Some(date_time(2025, Month::January, 1, (0, 0, 0))),
+            ]
+        );
+        assert_eq!(
+            aggregator.date,
+            Date::from_calendar_date(2025, Month::January, 1).ok()
+        );
+    }
+}
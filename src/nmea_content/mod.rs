@@ -1,4 +1,10 @@
+pub mod ais;
+mod encode;
+mod fix;
 mod parse;
+mod reassembly;
 mod sentences;
 
+pub use fix::*;
+pub use reassembly::*;
 pub use sentences::*;
@@ -2,13 +2,17 @@ use nom::{
     AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser, ToUsize,
     branch::alt,
     bytes::complete::{tag, take},
-    character::complete::{char, one_of},
-    combinator::{opt, value},
-    error::ParseError,
-    sequence::separated_pair,
+    character::complete::{char, none_of, one_of},
+    combinator::{not, opt, value},
+    error::{ErrorKind, ParseError},
+    sequence::{separated_pair, terminated},
 };
 
-use crate::{Error, IResult, NmeaParse, nmea_content::Location};
+use crate::{
+    AsStr, Error, IResult, NmeaParse,
+    nmea_content::Location,
+    parse::{text_field, unescape},
+};
 
 pub fn with_unit<I, E, T>(unit: char) -> impl Parser<I, Output = Option<T>, Error = Error<I, E>>
 where
@@ -17,8 +21,26 @@ where
     <I as Input>::Item: AsChar,
     E: ParseError<I>,
 {
-    separated_pair(<Option<T>>::parse, char(','), opt(char(unit)))
-        .map(|(value, unit)| unit.and(value))
+    // Any other unit must fail instead of being left for the next field
+    separated_pair(
+        <Option<T>>::parse,
+        char(','),
+        terminated(opt(char(unit)), not(none_of(","))),
+    )
+    .map(|(value, unit)| unit.and(value))
+}
+
+/// Parses a single hexadecimal digit, such as the NMEA 4.11 signal ID.
+pub fn hex_digit<I, E>(i: I) -> IResult<I, u8, E>
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    one_of("0123456789ABCDEFabcdef")
+        .map_opt(|digit: char| digit.to_digit(16))
+        .map(|digit| digit as u8)
+        .parse(i)
 }
 
 pub fn with_take<I, E, T, C>(count: C) -> impl Parser<I, Output = T, Error = Error<I, E>>
@@ -32,6 +54,34 @@ where
     take(count).and_then(T::parse)
 }
 
+/// Parses the three-character sentence type of the address field as a string slice.
+pub fn sentence_type<'a, I, E>(i: I) -> IResult<I, &'a str, E>
+where
+    I: Input + AsStr<'a>,
+    E: ParseError<I>,
+{
+    code(3).parse(i)
+}
+
+/// Parses a code of `count` characters as a string slice, so that it can be matched against
+/// string literals for both `&str` and `&[u8]` input.
+pub fn code<'a, I, E>(count: usize) -> impl Parser<I, Output = &'a str, Error = Error<I, E>>
+where
+    I: Input + AsStr<'a>,
+    E: ParseError<I>,
+{
+    move |i: I| {
+        let (i, code) = take(count).parse(i)?;
+        match code.as_str() {
+            Some(code) => Ok((i, code)),
+            None => Err(nom::Err::Error(nom::error::make_error(
+                code,
+                ErrorKind::Char,
+            ))),
+        }
+    }
+}
+
 pub fn location<I, E>(i: I) -> IResult<I, Option<Location>, E>
 where
     I: Input + Offset + ParseTo<f64> + AsBytes,
@@ -139,6 +189,24 @@ where
     }
 }
 
+/// Text fields are decoded, `^hh` escapes of reserved characters included.
+impl<'a, I, E, const N: usize> NmeaParse<I, E> for heapless::String<N>
+where
+    I: Input + AsStr<'a>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        let (rest, text) = text_field(i.clone())?;
+
+        let mut decoded = heapless::String::new();
+        match unescape(text, &mut decoded) {
+            Ok(()) => Ok((rest, decoded)),
+            Err(kind) => Err(nom::Err::Error(nom::error::make_error(i, kind))),
+        }
+    }
+}
+
 impl<I, E> NmeaParse<I, E> for time::Time
 where
     I: Input + Offset + ParseTo<f32> + AsBytes,
@@ -205,7 +273,11 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{IResult, NmeaParse};
-    use nom::{Parser, character::complete::char};
+    use nom::{
+        Parser,
+        character::complete::char,
+        error::{Error, ErrorKind},
+    };
 
     #[test]
     fn test_parse_heapless_vec() {
@@ -223,4 +295,29 @@ mod tests {
             .parse(input);
         assert_eq!(result, Ok(("", expected)));
     }
+
+    #[test]
+    fn test_parse_heapless_string() {
+        let result: IResult<_, heapless::String<4>> = heapless::String::parse("A^2CB,C");
+        assert_eq!(result, Ok((",C", "A,B".try_into().unwrap())));
+
+        // Malformed escapes and capacity overflow are reported as in `Cow<str>`
+        let cases = [
+            ("A^ZZ", ErrorKind::Escaped),
+            ("A^7F", ErrorKind::Escaped),
+            ("ABCDE", ErrorKind::TooLarge),
+            ("ABC^2CD", ErrorKind::TooLarge),
+        ];
+
+        for (input, kind) in cases {
+            let result: IResult<_, heapless::String<4>> = heapless::String::parse(input);
+            assert_eq!(
+                result,
+                Err(nom::Err::Error(crate::Error::ParsingError(Error::new(
+                    input, kind
+                )))),
+                "Failed: {input:?}"
+            );
+        }
+    }
 }
@@ -0,0 +1,475 @@
+//! # Multi-Sentence Reassembly
+//!
+//! Some messages are too long for a single sentence and are split across several
+//! fragments, each carrying the total number of fragments and its own number:
+//! AIS [`VDM`] payloads, [`GSV`] satellite lists, [`RTE`] routes, [`ALF`] alerts, [`TXT`] texts, and so on.
+//!
+//! A [`Reassembler`] buffers the fragments of such messages, keyed by talker ID and by
+//! [`Fragment::key`], and yields the complete logical message once all its fragments
+//! have been received, in any order. Fragments that do not complete their message
+//! within the configured timeout are dropped.
+//!
+//! [`Reassembler`] stores the fragments in a [`Vec`]; [`HeaplessReassembler`] uses a
+//! fixed-capacity `heapless::Vec` instead, for bounded memory on embedded targets.
+//!
+//! [`VDM`]: crate::nmea_content::VDM
+//! [`GSV`]: crate::nmea_content::GSV
+//! [`RTE`]: crate::nmea_content::RTE
+//! [`ALF`]: crate::nmea_content::ALF
+//! [`TXT`]: crate::nmea_content::TXT
+
+use std::time::Duration;
+
+use crate::nmea_content::TalkerId;
+
+/// A sentence that may be one fragment of a multi-sentence message.
+pub trait Fragment: Sized {
+    /// The complete message assembled from all the fragments.
+    type Message;
+
+    /// Identifies the message a fragment belongs to, among the messages of the same talker.
+    type Key: PartialEq;
+
+    /// Returns the key shared by all the fragments of the message.
+    fn key(&self) -> Self::Key;
+
+    /// Returns the total number of fragments of the message.
+    fn fragment_count(&self) -> u8;
+
+    /// Returns the number of this fragment within the message, starting at 1.
+    fn fragment_number(&self) -> u8;
+
+    /// Assembles the message from all its fragments, given in order.
+    fn assemble(fragments: impl Iterator<Item = Self>) -> Self::Message;
+}
+
+/// A fragment waiting for the other fragments of its message.
+#[derive(Debug, Clone)]
+pub struct BufferedFragment<F> {
+    /// Talker ID of the sentence
+    talker: TalkerId,
+
+    /// Time the fragment was received at
+    received_at: Duration,
+
+    /// The fragment itself
+    fragment: F,
+}
+
+impl<F> BufferedFragment<F>
+where
+    F: Fragment,
+{
+    fn belongs_to(&self, talker: TalkerId, key: &F::Key) -> bool {
+        self.talker == talker && self.fragment.key() == *key
+    }
+}
+
+/// Storage of the fragments buffered by a [`Reassembler`].
+///
+/// This trait is implemented for [`Vec`] and `heapless::Vec`.
+pub trait FragmentBuffer<F>: AsRef<[BufferedFragment<F>]> {
+    /// Appends a fragment, or gives it back if the buffer is full.
+    fn push(&mut self, fragment: BufferedFragment<F>) -> Result<(), BufferedFragment<F>>;
+
+    /// Removes the fragment at `index`, replacing it with the last one.
+    fn swap_remove(&mut self, index: usize) -> BufferedFragment<F>;
+
+    /// Keeps only the fragments for which `f` returns `true`.
+    fn retain(&mut self, f: impl FnMut(&BufferedFragment<F>) -> bool);
+}
+
+impl<F> FragmentBuffer<F> for Vec<BufferedFragment<F>> {
+    fn push(&mut self, fragment: BufferedFragment<F>) -> Result<(), BufferedFragment<F>> {
+        Vec::push(self, fragment);
+        Ok(())
+    }
+
+    fn swap_remove(&mut self, index: usize) -> BufferedFragment<F> {
+        Vec::swap_remove(self, index)
+    }
+
+    fn retain(&mut self, f: impl FnMut(&BufferedFragment<F>) -> bool) {
+        Vec::retain(self, f)
+    }
+}
+
+impl<F, const N: usize> FragmentBuffer<F> for heapless::Vec<BufferedFragment<F>, N> {
+    fn push(&mut self, fragment: BufferedFragment<F>) -> Result<(), BufferedFragment<F>> {
+        heapless::Vec::push(self, fragment)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> BufferedFragment<F> {
+        heapless::Vec::swap_remove(self, index)
+    }
+
+    fn retain(&mut self, mut f: impl FnMut(&BufferedFragment<F>) -> bool) {
+        heapless::Vec::retain(self, |fragment| f(fragment))
+    }
+}
+
+/// Reassembles multi-sentence messages from their fragments.
+///
+/// Fragments are pushed together with their talker ID, which is kept by
+/// [`Sentence`](crate::nmea_content::Sentence), and with the time they were received at,
+/// measured from any fixed origin, such as the start of the program. The reassembler:
+///
+/// - yields single-fragment messages right away,
+/// - accepts the fragments of a message in any order,
+/// - starts a message over when one of its fragments is received twice, or with a
+///   different total number of fragments, since the key is then being reused by a new message,
+/// - ignores fragments numbered 0 or above their total number of fragments,
+/// - drops fragments older than the timeout.
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     IResult, NmeaParse,
+///     nmea_content::{NmeaSentence, Reassembler, Sentence, VDM, ais::AisMessage},
+/// };
+/// use std::time::Duration;
+///
+/// let mut reassembler: Reassembler<VDM> = Reassembler::new(Duration::from_secs(2));
+///
+/// let fragments = [
+///     "AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0",
+///     "AIVDM,2,2,1,A,88888888880,2",
+/// ];
+///
+/// let mut messages = vec![];
+/// for (second, input) in fragments.into_iter().enumerate() {
+///     let result: IResult<_, _> = Sentence::parse(input);
+///     let Ok((_, Sentence { talker, data: NmeaSentence::VDM(vdm) })) = result else {
+///         continue;
+///     };
+///
+///     let now = Duration::from_secs(second as u64);
+///     if let Some(message) = reassembler.push(talker, vdm, now) {
+///         messages.push(message);
+///     }
+/// }
+///
+/// let [Ok(AisMessage::StaticAndVoyageData(data))] = messages.as_slice() else {
+///     panic!("Unexpected messages: {messages:?}");
+/// };
+/// assert_eq!(data.vessel_name, "EVER DIADEM");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Reassembler<F, B = Vec<BufferedFragment<F>>> {
+    /// Fragments waiting for the rest of their message
+    buffer: B,
+
+    /// Maximum age of a buffered fragment
+    timeout: Duration,
+
+    _fragment: std::marker::PhantomData<F>,
+}
+
+/// A [`Reassembler`] buffering at most `N` fragments, without heap allocation.
+///
+/// When the buffer is full, the oldest fragment is dropped to make room for a new one.
+pub type HeaplessReassembler<F, const N: usize> =
+    Reassembler<F, heapless::Vec<BufferedFragment<F>, N>>;
+
+impl<F, B> Reassembler<F, B>
+where
+    F: Fragment,
+    B: FragmentBuffer<F> + Default,
+{
+    /// Creates a new reassembler dropping fragments older than `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        Reassembler {
+            buffer: B::default(),
+            timeout,
+            _fragment: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<F, B> Reassembler<F, B>
+where
+    F: Fragment,
+    B: FragmentBuffer<F>,
+{
+    /// Adds a fragment received from `talker` at time `now`.
+    ///
+    /// Returns the complete message if this fragment was the last missing one.
+    pub fn push(&mut self, talker: TalkerId, fragment: F, now: Duration) -> Option<F::Message> {
+        self.expire(now);
+
+        let count = fragment.fragment_count();
+        let number = fragment.fragment_number();
+        if number == 0 || number > count {
+            return None;
+        }
+
+        if count == 1 {
+            return Some(F::assemble(std::iter::once(fragment)));
+        }
+
+        // A repeated fragment or a different count means a new message reuses the key
+        let key = fragment.key();
+        let restart = self.buffer.as_ref().iter().any(|buffered| {
+            buffered.belongs_to(talker, &key)
+                && (buffered.fragment.fragment_number() == number
+                    || buffered.fragment.fragment_count() != count)
+        });
+        if restart {
+            self.buffer
+                .retain(|buffered| !buffered.belongs_to(talker, &key));
+        }
+
+        let received = 1 + self
+            .buffer
+            .as_ref()
+            .iter()
+            .filter(|buffered| buffered.belongs_to(talker, &key))
+            .count();
+
+        if received < count as usize {
+            let mut buffered = BufferedFragment {
+                talker,
+                received_at: now,
+                fragment,
+            };
+
+            while let Err(rejected) = self.buffer.push(buffered) {
+                buffered = rejected;
+                let oldest = self
+                    .buffer
+                    .as_ref()
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, buffered)| buffered.received_at)
+                    .map(|(index, _)| index)?;
+                self.buffer.swap_remove(oldest);
+            }
+
+            return None;
+        }
+
+        let buffer = &mut self.buffer;
+        let mut last = Some(fragment);
+        let fragments = (1..=count).filter_map(move |n| {
+            if n == number {
+                return last.take();
+            }
+
+            let index = buffer.as_ref().iter().position(|buffered| {
+                buffered.belongs_to(talker, &key) && buffered.fragment.fragment_number() == n
+            })?;
+            Some(buffer.swap_remove(index).fragment)
+        });
+
+        Some(F::assemble(fragments))
+    }
+
+    /// Drops the fragments older than the timeout at time `now`.
+    pub fn expire(&mut self, now: Duration) {
+        let timeout = self.timeout;
+        self.buffer
+            .retain(|buffered| now.saturating_sub(buffered.received_at) <= timeout);
+    }
+
+    /// Returns the number of buffered fragments.
+    pub fn buffered(&self) -> usize {
+        self.buffer.as_ref().len()
+    }
+
+    /// Drops all buffered fragments.
+    pub fn clear(&mut self) {
+        self.buffer.retain(|_| false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nmea_content::{GSV, Satellite, VDM, ais::AisMessage};
+
+    fn gsv(total_messages: u8, message_number: u8, prns: &[u8]) -> GSV {
+        GSV {
+            total_messages,
+            message_number,
+            satellites_in_view: 6,
+            satellites: prns
+                .iter()
+                .map(|&prn| Satellite {
+                    prn,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn prns(message: Option<crate::nmea_content::SatellitesInView>) -> Option<Vec<u8>> {
+        message.map(|message| message.satellites.iter().map(|s| s.prn).collect())
+    }
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_reassembly_order() {
+        let mut reassembler: Reassembler<GSV> = Reassembler::new(secs(2));
+
+        let message = reassembler.push(TalkerId::Gps, gsv(1, 1, &[1, 2]), secs(0));
+        assert_eq!(prns(message), Some(vec![1, 2]));
+
+        // Out of order, interleaved with another talker
+        let cases = [
+            (TalkerId::Gps, gsv(2, 2, &[5, 6]), None),
+            (TalkerId::Glonass, gsv(2, 1, &[65, 66, 67, 68]), None),
+            (
+                TalkerId::Gps,
+                gsv(2, 1, &[1, 2, 3, 4]),
+                Some(vec![1, 2, 3, 4, 5, 6]),
+            ),
+            (
+                TalkerId::Glonass,
+                gsv(2, 2, &[69]),
+                Some(vec![65, 66, 67, 68, 69]),
+            ),
+        ];
+
+        for (talker, fragment, expected) in cases {
+            let message = reassembler.push(talker, fragment, secs(1));
+            assert_eq!(prns(message), expected, "Failed: {talker:?}");
+        }
+        assert_eq!(reassembler.buffered(), 0);
+    }
+
+    #[test]
+    fn test_reassembly_restart() {
+        let mut reassembler: Reassembler<GSV> = Reassembler::new(secs(2));
+
+        // A repeated fragment starts the message over
+        assert_eq!(
+            reassembler.push(TalkerId::Gps, gsv(3, 1, &[1]), secs(0)),
+            None
+        );
+        assert_eq!(
+            reassembler.push(TalkerId::Gps, gsv(3, 2, &[2]), secs(0)),
+            None
+        );
+        assert_eq!(
+            reassembler.push(TalkerId::Gps, gsv(3, 1, &[3]), secs(0)),
+            None
+        );
+        assert_eq!(reassembler.buffered(), 1);
+
+        // So does a different number of fragments
+        assert_eq!(
+            reassembler.push(TalkerId::Gps, gsv(2, 2, &[4]), secs(0)),
+            None
+        );
+        assert_eq!(reassembler.buffered(), 1);
+        let message = reassembler.push(TalkerId::Gps, gsv(2, 1, &[5]), secs(0));
+        assert_eq!(prns(message), Some(vec![5, 4]));
+
+        // Invalid fragment numbers are ignored
+        assert_eq!(
+            reassembler.push(TalkerId::Gps, gsv(2, 0, &[6]), secs(0)),
+            None
+        );
+        assert_eq!(
+            reassembler.push(TalkerId::Gps, gsv(2, 3, &[7]), secs(0)),
+            None
+        );
+        assert_eq!(
+            reassembler.push(TalkerId::Gps, gsv(0, 0, &[8]), secs(0)),
+            None
+        );
+        assert_eq!(reassembler.buffered(), 0);
+    }
+
+    #[test]
+    fn test_reassembly_timeout() {
+        let mut reassembler: Reassembler<GSV> = Reassembler::new(secs(2));
+
+        assert_eq!(
+            reassembler.push(TalkerId::Gps, gsv(2, 1, &[1]), secs(0)),
+            None
+        );
+        assert_eq!(
+            reassembler.push(TalkerId::Gps, gsv(2, 2, &[2]), secs(3)),
+            None
+        );
+        assert_eq!(reassembler.buffered(), 1);
+
+        let message = reassembler.push(TalkerId::Gps, gsv(2, 1, &[3]), secs(5));
+        assert_eq!(prns(message), Some(vec![3, 2]));
+
+        assert_eq!(
+            reassembler.push(TalkerId::Gps, gsv(2, 1, &[4]), secs(6)),
+            None
+        );
+        reassembler.expire(secs(9));
+        assert_eq!(reassembler.buffered(), 0);
+
+        assert_eq!(
+            reassembler.push(TalkerId::Gps, gsv(2, 1, &[5]), secs(10)),
+            None
+        );
+        reassembler.clear();
+        assert_eq!(reassembler.buffered(), 0);
+    }
+
+    #[test]
+    fn test_heapless_reassembly() {
+        let mut reassembler = HeaplessReassembler::<GSV, 2>::new(secs(10));
+
+        let talkers = [TalkerId::Gps, TalkerId::Glonass, TalkerId::Galileo];
+        for (second, talker) in talkers.into_iter().enumerate() {
+            let message = reassembler.push(talker, gsv(2, 1, &[1]), secs(second as u64));
+            assert_eq!(message, None, "Failed: {talker:?}");
+        }
+        assert_eq!(reassembler.buffered(), 2);
+
+        // The oldest fragment was dropped to make room
+        let message = reassembler.push(TalkerId::Gps, gsv(2, 2, &[2]), secs(3));
+        assert_eq!(message, None);
+        let message = reassembler.push(TalkerId::Galileo, gsv(2, 2, &[3]), secs(3));
+        assert_eq!(prns(message), Some(vec![1, 3]));
+    }
+
+    #[test]
+    fn test_ais_reassembly() {
+        let mut reassembler: Reassembler<VDM> = Reassembler::new(secs(2));
+
+        let fragments = [
+            VDM {
+                fragment_count: 2,
+                fragment_number: 2,
+                sequential_message_id: Some(3),
+                channel: Some(crate::nmea_content::AisChannel::B),
+                payload: "88888888880".try_into().unwrap(),
+                fill_bits: 2,
+            },
+            VDM {
+                fragment_count: 2,
+                fragment_number: 1,
+                sequential_message_id: Some(3),
+                channel: Some(crate::nmea_content::AisChannel::B),
+                payload: "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8"
+                    .try_into()
+                    .unwrap(),
+                fill_bits: 0,
+            },
+        ];
+
+        let mut messages = fragments
+            .into_iter()
+            .filter_map(|vdm| reassembler.push(TalkerId::Other(*b"AI"), vdm, secs(0)));
+
+        let Some(Ok(AisMessage::StaticAndVoyageData(data))) = messages.next() else {
+            panic!("Expected static and voyage data");
+        };
+        assert_eq!(data.mmsi, 351759000);
+        assert_eq!(data.vessel_name, "EVER DIADEM");
+        assert!(messages.next().is_none());
+    }
+}
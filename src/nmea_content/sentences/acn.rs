@@ -0,0 +1,163 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::alf::AlertId;
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse};
+
+/// ACN - Alert Command
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_acn_alert_command>
+///
+/// ```text
+///         1         2   3   4   5 6
+///         |         |   |   |   | |
+///  $--ACN,hhmmss.ss,aaa,x.x,x.x,c,a*hh<CR><LF>
+/// ```
+///
+/// Alerts are acknowledged by sending an ACN command back to the alert source:
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     Nmea0183WriterBuilder, NmeaEncode,
+///     nmea_content::{ACN, AlertCommand, AlertId, NmeaSentence},
+/// };
+///
+/// let mut writer = Nmea0183WriterBuilder::new()
+///     .talker(*b"II")
+///     .build(NmeaSentence::encode);
+///
+/// let acn = ACN {
+///     alert: AlertId {
+///         manufacturer: None,
+///         identifier: Some(192),
+///         instance: Some(1),
+///     },
+///     command: AlertCommand::Acknowledge,
+///     ..Default::default()
+/// };
+///
+/// let mut output = String::new();
+/// writer(&mut output, &NmeaSentence::ACN(acn)).unwrap();
+/// assert_eq!(output, "$IIACN,,,192,1,A,C*45\r\n");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct ACN {
+    /// Time of the command in UTC
+    pub time: Option<time::Time>,
+    /// Identification of the alert
+    pub alert: AlertId,
+    /// Command for the alert
+    pub command: AlertCommand,
+    /// Sentence status flag, always `C` for a command
+    pub status: CommandStatus,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("AQOS")))]
+/// Command for an alert, sent by [`ACN`] or refused by [`ARC`]
+///
+/// [`ARC`]: crate::nmea_content::ARC
+pub enum AlertCommand {
+    #[default]
+    #[nmea(selector('A'))]
+    /// A - Acknowledge
+    Acknowledge,
+    #[nmea(selector('Q'))]
+    /// Q - Request or repeat information
+    Request,
+    #[nmea(selector('O'))]
+    /// O - Responsibility transfer
+    ResponsibilityTransfer,
+    #[nmea(selector('S'))]
+    /// S - Silence
+    Silence,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("C")))]
+/// Sentence status flag of [`ACN`]
+pub enum CommandStatus {
+    #[default]
+    #[nmea(selector('C'))]
+    /// C - Command
+    Command,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Encoder, IResult};
+
+    #[test]
+    fn test_acn_parsing() {
+        let result: IResult<_, _> = ACN::parse("124304.50,,192,1,A,C");
+        let (rest, acn) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(acn.time, time::Time::from_hms_milli(12, 43, 4, 500).ok());
+        assert_eq!(
+            acn.alert,
+            AlertId {
+                manufacturer: None,
+                identifier: Some(192),
+                instance: Some(1),
+            }
+        );
+        assert_eq!(acn.command, AlertCommand::Acknowledge);
+        assert_eq!(acn.status, CommandStatus::Command);
+
+        let cases = [
+            ("Q", AlertCommand::Request),
+            ("O", AlertCommand::ResponsibilityTransfer),
+            ("S", AlertCommand::Silence),
+        ];
+
+        for (command, expected) in cases {
+            let input = format!(",SRD,3008,2,{command},C");
+            let result: IResult<_, _> = ACN::parse(input.as_str());
+            let (rest, acn) = result.unwrap();
+            assert_eq!(rest, "");
+            assert_eq!(acn.time, None);
+            assert_eq!(acn.alert.manufacturer.as_deref(), Some("SRD"));
+            assert_eq!(acn.alert.identifier, Some(3008));
+            assert_eq!(acn.alert.instance, Some(2));
+            assert_eq!(acn.command, expected, "Failed: {input:?}");
+        }
+
+        for input in [
+            "124304.50,,192,1,A,A",
+            "124304.50,,192,1,A,",
+            "124304.50,,192,1,X,C",
+            "124304.50,,192,1,,C",
+            "124304.50,,192,1,A",
+        ] {
+            let result: IResult<_, _> = ACN::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+
+    #[test]
+    fn test_acn_encoding() {
+        let cases = [
+            "124304.50,,192,1,A,C",
+            ",SRD,3008,2,Q,C",
+            ",,192,1,O,C",
+            "124305.00,SRD,3008,,S,C",
+        ];
+
+        for input in cases {
+            let result: IResult<_, ACN> = ACN::parse(input);
+            let (_, acn) = result.unwrap();
+            let mut output = String::new();
+            acn.encode(&mut Encoder::new(&mut output)).unwrap();
+            assert_eq!(output, input);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::alf::AlertId;
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse};
+
+/// Maximum number of alert entries listed by a single [`ALC`] sentence.
+pub const ALC_ENTRIES_CAPACITY: usize = 8;
+
+/// ALC - Cyclic Alert List
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_alc_cyclic_alert_list>
+///
+/// ```text
+///         1  2  3  4   5   6   7   8        x   x+1 x+2 x+3
+///         |  |  |  |   |   |   |   |        |   |   |   |
+///  $--ALC,xx,xx,xx,x.x,aaa,x.x,x.x,x.x, ... aaa,x.x,x.x,x.x*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+// The entries take the rest of the sentence, any unparsed input is a malformed entry
+#[nmea(exact)]
+pub struct ALC {
+    /// Total number of ALC sentences of the list
+    pub total_messages: u8,
+    /// Sentence number of this ALC message within the list
+    pub message_number: u8,
+    /// Sequential message identifier (0-99)
+    pub sequential_message_id: Option<u8>,
+    /// Number of alert entries in this sentence
+    pub entry_count: Option<u8>,
+    /// Alert entries, one per active alert
+    pub entries: heapless::Vec<AlertEntry, ALC_ENTRIES_CAPACITY>,
+}
+
+/// Active alert listed by [`ALC`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct AlertEntry {
+    /// Identification of the alert
+    pub alert: AlertId,
+    /// Revision counter of the alert
+    pub revision: Option<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_alc_parsing() {
+        let result: IResult<_, _> = ALC::parse("01,01,03,2,,192,1,1,SRD,3008,2,1");
+        let (rest, alc) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(alc.entry_count, Some(2));
+        assert_eq!(alc.entries.len(), 2);
+        assert_eq!(alc.entries[0].alert.identifier, Some(192));
+        assert_eq!(alc.entries[1].alert.manufacturer.as_deref(), Some("SRD"));
+        assert_eq!(alc.entries[1].revision, Some(1));
+
+        let result: IResult<_, _> = ALC::parse("01,01,03,0");
+        let (rest, alc) = result.unwrap();
+        assert_eq!(rest, "");
+        assert!(alc.entries.is_empty());
+
+        for input in ["01,01,03,2,,192,1", "01,01"] {
+            let result: IResult<_, _> = ALC::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse, nmea_content::Fragment};
+
+/// Maximum length of an [`AlertText`].
+pub const ALERT_TEXT_CAPACITY: usize = 64;
+
+/// Text of an alert or alarm, as used by [`ALF`] and [`ALR`]
+///
+/// Texts longer than [`ALERT_TEXT_CAPACITY`] fail to parse.
+///
+/// [`ALR`]: crate::nmea_content::ALR
+pub type AlertText = heapless::String<ALERT_TEXT_CAPACITY>;
+
+/// ALF - Alert Sentence
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_alf_alert_sentence>
+///
+/// ```text
+///         1 2 3 4         5 6 7 8   9   10  11  1213
+///         | | | |         | | | |   |   |   |   | |
+///  $--ALF,x,x,x,hhmmss.ss,a,a,a,aaa,x.x,x.x,x.x,x,c--c*hh<CR><LF>
+/// ```
+///
+/// An alert with an additional text is sent as two sentences: the second one only
+/// carries the additional text, and the two can be assembled into an [`Alert`] by a
+/// [`Reassembler`](crate::nmea_content::Reassembler).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct ALF {
+    /// Total number of ALF sentences for this alert (1 or 2)
+    pub total_messages: u8,
+    /// Sentence number of this ALF message within the alert
+    pub message_number: u8,
+    /// Sequential message identifier (0-9), shared by the sentences of the alert
+    pub sequential_message_id: Option<u8>,
+    /// Time of the last change of the alert in UTC
+    pub time: Option<time::Time>,
+    /// Category of the alert
+    pub category: Option<AlertCategory>,
+    /// Priority of the alert
+    pub priority: Option<AlertPriority>,
+    /// State of the alert
+    pub state: Option<AlertState>,
+    /// Identification of the alert
+    pub alert: AlertId,
+    /// Revision counter (1-99), incremented on each change of the alert
+    pub revision: Option<u8>,
+    /// Escalation counter (0-9)
+    pub escalation: Option<u8>,
+    /// Alert title in the first sentence, additional text in the second one
+    pub text: Option<AlertText>,
+}
+
+/// Identification of an alert, shared by the bridge alert management sentences
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct AlertId {
+    /// Manufacturer mnemonic code, empty for standardized alerts
+    pub manufacturer: Option<heapless::String<3>>,
+    /// Alert identifier
+    pub identifier: Option<u32>,
+    /// Alert instance, distinguishing alerts with the same identifier
+    pub instance: Option<u32>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("ABC")))]
+/// Category of an alert reported by [`ALF`]
+pub enum AlertCategory {
+    #[nmea(selector('A'))]
+    /// A - Graphical information is required to decide on the alert
+    A,
+    #[default]
+    #[nmea(selector('B'))]
+    /// B - No additional information is required to decide on the alert
+    B,
+    #[nmea(selector('C'))]
+    /// C - The alert cannot be acknowledged on the bridge
+    C,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("EAWC")))]
+/// Priority of an alert reported by [`ALF`]
+pub enum AlertPriority {
+    #[nmea(selector('E'))]
+    /// E - Emergency alarm
+    Emergency,
+    #[nmea(selector('A'))]
+    /// A - Alarm
+    Alarm,
+    #[default]
+    #[nmea(selector('W'))]
+    /// W - Warning
+    Warning,
+    #[nmea(selector('C'))]
+    /// C - Caution
+    Caution,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("VSAOUN")))]
+/// State of an alert reported by [`ALF`]
+pub enum AlertState {
+    #[nmea(selector('V'))]
+    /// V - Active, unacknowledged
+    ActiveUnacknowledged,
+    #[nmea(selector('S'))]
+    /// S - Active, silenced
+    ActiveSilenced,
+    #[nmea(selector('A'))]
+    /// A - Active, acknowledged
+    ActiveAcknowledged,
+    #[nmea(selector('O'))]
+    /// O - Active, responsibility transferred
+    ActiveResponsibilityTransferred,
+    #[nmea(selector('U'))]
+    /// U - Rectified, unacknowledged
+    RectifiedUnacknowledged,
+    #[default]
+    #[nmea(selector('N'))]
+    /// N - Normal, the alert is no longer active
+    Normal,
+}
+
+/// Alert reported by a complete group of [`ALF`] sentences
+///
+/// Assembled from the sentences of the group by a [`Reassembler`](crate::nmea_content::Reassembler).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Alert {
+    /// Time of the last change of the alert in UTC
+    pub time: Option<time::Time>,
+    /// Category of the alert
+    pub category: Option<AlertCategory>,
+    /// Priority of the alert
+    pub priority: Option<AlertPriority>,
+    /// State of the alert
+    pub state: Option<AlertState>,
+    /// Identification of the alert
+    pub alert: AlertId,
+    /// Revision counter (1-99), incremented on each change of the alert
+    pub revision: Option<u8>,
+    /// Escalation counter (0-9)
+    pub escalation: Option<u8>,
+    /// Alert title
+    pub title: Option<AlertText>,
+    /// Additional alert text, from the second sentence
+    pub description: Option<AlertText>,
+}
+
+impl Fragment for ALF {
+    type Message = Alert;
+
+    type Key = Option<u8>;
+
+    fn key(&self) -> Self::Key {
+        self.sequential_message_id
+    }
+
+    fn fragment_count(&self) -> u8 {
+        self.total_messages
+    }
+
+    fn fragment_number(&self) -> u8 {
+        self.message_number
+    }
+
+    fn assemble(fragments: impl Iterator<Item = Self>) -> Self::Message {
+        let mut message = Alert::default();
+
+        for alf in fragments {
+            if alf.message_number > 1 {
+                message.description = alf.text;
+                continue;
+            }
+
+            message.time = alf.time;
+            message.category = alf.category;
+            message.priority = alf.priority;
+            message.state = alf.state;
+            message.alert = alf.alert;
+            message.revision = alf.revision;
+            message.escalation = alf.escalation;
+            message.title = alf.text;
+        }
+
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        IResult,
+        nmea_content::{Reassembler, TalkerId},
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_alf_parsing() {
+        let result: IResult<_, _> = ALF::parse("1,1,0,124304.50,A,W,A,,192,1,1,0,LOST TARGET");
+        let (rest, alf) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(alf.category, Some(AlertCategory::A));
+        assert_eq!(alf.priority, Some(AlertPriority::Warning));
+        assert_eq!(alf.state, Some(AlertState::ActiveAcknowledged));
+        assert_eq!(
+            alf.alert,
+            AlertId {
+                manufacturer: None,
+                identifier: Some(192),
+                instance: Some(1),
+            }
+        );
+        assert_eq!(alf.text.as_deref(), Some("LOST TARGET"));
+
+        for input in [
+            "1,1,0,124304.50,X,W,A,,192,1,1,0,LOST TARGET",
+            "1,1,0,124304.50,A,W,X,,192,1,1,0,LOST TARGET",
+            "1,1,0,124304.50,A,W,A,ABCD,192,1,1,0,LOST TARGET",
+            "1,1,0,124304.50,A,W,A,,192,1,1,0",
+        ] {
+            let result: IResult<_, _> = ALF::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+
+    #[test]
+    fn test_alf_reassembly() {
+        let mut reassembler: Reassembler<ALF> = Reassembler::new(Duration::from_secs(1));
+
+        let sentences = [
+            "2,2,1,,,,,,,,,,TCPA 2.5 MIN",
+            "1,1,2,124305.00,B,C,V,SRD,3008,2,1,0,NO ECHO",
+            "2,1,1,124304.50,A,W,V,,192,1,1,0,LOST TARGET",
+        ];
+        let mut alerts = Vec::new();
+        for sentence in sentences {
+            let (_, alf) = (ALF::parse(sentence) as IResult<_, _>).unwrap();
+            alerts.extend(reassembler.push(TalkerId::Other(*b"RA"), alf, Duration::ZERO));
+        }
+
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[0].alert.manufacturer.as_deref(), Some("SRD"));
+        assert_eq!(alerts[0].title.as_deref(), Some("NO ECHO"));
+        assert_eq!(alerts[0].description, None);
+        assert_eq!(alerts[1].alert.identifier, Some(192));
+        assert_eq!(alerts[1].state, Some(AlertState::ActiveUnacknowledged));
+        assert_eq!(alerts[1].title.as_deref(), Some("LOST TARGET"));
+        assert_eq!(alerts[1].description.as_deref(), Some("TCPA 2.5 MIN"));
+    }
+}
@@ -0,0 +1,109 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::alf::AlertText;
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse};
+
+/// ALR - Set Alarm State
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_alr_set_alarm_state>
+///
+/// ```text
+///         1         2   3 4 5
+///         |         |   | | |
+///  $--ALR,hhmmss.ss,xxx,A,A,c--c*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct ALR {
+    /// Time of the alarm condition change in UTC
+    pub time: Option<time::Time>,
+    /// Unique alarm number (identifier) at the alarm source
+    pub alarm_number: Option<u16>,
+    /// Alarm condition
+    pub condition: AlarmCondition,
+    /// Alarm acknowledge state
+    pub acknowledgement: AlarmAcknowledgement,
+    /// Alarm description text
+    pub description: Option<AlertText>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("AV")))]
+/// Alarm condition reported by [`ALR`]
+pub enum AlarmCondition {
+    #[nmea(selector('A'))]
+    /// A - Threshold exceeded
+    Exceeded,
+    #[default]
+    #[nmea(selector('V'))]
+    /// V - Threshold not exceeded
+    NotExceeded,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("AV")))]
+/// Alarm acknowledge state reported by [`ALR`]
+pub enum AlarmAcknowledgement {
+    #[nmea(selector('A'))]
+    /// A - Acknowledged
+    Acknowledged,
+    #[default]
+    #[nmea(selector('V'))]
+    /// V - Unacknowledged
+    Unacknowledged,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_alr_parsing() {
+        let cases = [
+            (
+                "220516,001,A,V,Bilge pump alarm",
+                ALR {
+                    time: time::Time::from_hms(22, 5, 16).ok(),
+                    alarm_number: Some(1),
+                    condition: AlarmCondition::Exceeded,
+                    acknowledgement: AlarmAcknowledgement::Unacknowledged,
+                    description: Some("Bilge pump alarm".try_into().unwrap()),
+                },
+            ),
+            (
+                ",120,V,A,",
+                ALR {
+                    time: None,
+                    alarm_number: Some(120),
+                    condition: AlarmCondition::NotExceeded,
+                    acknowledgement: AlarmAcknowledgement::Acknowledged,
+                    description: None,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = ALR::parse(input);
+            assert_eq!(result, Ok(("", expected)), "Failed: {input:?}");
+        }
+
+        for input in [
+            "220516,001,X,V,Bilge pump alarm",
+            "220516,001,A,X,Bilge pump alarm",
+            "220516,001,,V,Bilge pump alarm",
+            "220516,001,A,V",
+        ] {
+            let result: IResult<_, _> = ALR::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
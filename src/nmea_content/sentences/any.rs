@@ -0,0 +1,279 @@
+use nom::{
+    AsChar, Input, Parser,
+    bytes::complete::take_while1,
+    character::complete::char,
+    combinator::rest,
+    error::{ErrorKind, ParseError},
+};
+
+use crate::{
+    Error, Fields, IResult, NmeaParse, VersionRange,
+    nmea_content::{ProprietarySentence, Query, Sentence, TalkerId},
+};
+
+/// Any NMEA 0183 sentence, whether its type is supported by [`NmeaSentence`] or not.
+///
+/// Sentences supported by [`NmeaSentence`] are parsed into [`AnySentence::Known`],
+/// proprietary sentences supported by [`ProprietarySentence`] into
+/// [`AnySentence::Proprietary`], and query sentences into [`AnySentence::Query`]. Other
+/// sentences are kept as [`AnySentence::Unknown`] instead of failing with
+/// [`Error::UnrecognizedMessage`], with their fields borrowed from the input, so that mixed
+/// streams can be processed through a single content parser.
+///
+/// Malformed sentences of a supported type still fail to parse.
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     IResult, Nmea0183ParserBuilder, NmeaParse,
+///     nmea_content::{AnySentence, NmeaSentence, TalkerId},
+/// };
+/// use nom::Parser;
+///
+/// let mut parser = Nmea0183ParserBuilder::new().build(AnySentence::parse);
+///
+/// let result: IResult<_, _> = parser.parse("$GPDBT,12.5,f,3.8,M,2.1,F*38\r\n");
+/// let Ok((_, AnySentence::Known(sentence))) = result else {
+///     panic!("Expected a known sentence");
+/// };
+/// assert!(matches!(sentence.data, NmeaSentence::DBT(_)));
+///
+/// let result: IResult<_, _> = parser.parse("$PFEC,GPatt,125.3,-2.4,1.2*65\r\n");
+/// let Ok((_, AnySentence::Unknown { talker, sentence_type, fields })) = result else {
+///     panic!("Expected an unknown sentence");
+/// };
+/// assert_eq!(talker, None);
+/// assert_eq!(sentence_type, "PFEC");
+/// assert_eq!(fields.collect::<Vec<_>>(), ["GPatt", "125.3", "-2.4", "1.2"]);
+///
+/// let result: IResult<_, _> = parser.parse("$IIXYZ,,1*6A\r\n");
+/// let Ok((_, AnySentence::Unknown { talker, sentence_type, fields })) = result else {
+///     panic!("Expected an unknown sentence");
+/// };
+/// assert_eq!(talker, Some(TalkerId::Other(*b"II")));
+/// assert_eq!(sentence_type, "XYZ");
+/// assert_eq!(fields.collect::<Vec<_>>(), ["", "1"]);
+/// ```
+///
+/// [`NmeaSentence`]: crate::nmea_content::NmeaSentence
+#[derive(Debug, Clone, PartialEq)]
+// Boxing `Known` would cost an allocation for every supported sentence
+#[allow(clippy::large_enum_variant)]
+pub enum AnySentence<I> {
+    /// A sentence of a type supported by [`NmeaSentence`](crate::nmea_content::NmeaSentence)
+    Known(Sentence),
+    /// A proprietary sentence supported by [`ProprietarySentence`]
+    Proprietary(ProprietarySentence),
+    /// A query sentence, such as `CCGPQ,GGA`
+    Query(Query),
+    /// A sentence of an unsupported type
+    Unknown {
+        /// Talker ID of the sentence, [`None`] for proprietary sentences
+        talker: Option<TalkerId>,
+        /// Sentence type, or the whole address field for proprietary sentences, such as `PFEC`
+        sentence_type: I,
+        /// Fields of the sentence, after the address field
+        fields: Fields<I>,
+    },
+}
+
+impl<I, E> NmeaParse<I, E> for AnySentence<I>
+where
+    Sentence: NmeaParse<I, E>,
+    ProprietarySentence: NmeaParse<I, E>,
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        Self::parse_version(i, VersionRange::default())
+    }
+
+    fn parse_version(i: I, versions: VersionRange) -> IResult<I, Self, E> {
+        if is_proprietary(&i) {
+            return match ProprietarySentence::parse(i.clone()) {
+                Err(nom::Err::Error(Error::UnrecognizedMessage(_))) => unknown(i),
+                result => result.map(|(i, sentence)| (i, AnySentence::Proprietary(sentence))),
+            };
+        }
+
+        match Sentence::parse_version(i.clone(), versions) {
+            Err(nom::Err::Error(Error::UnrecognizedMessage(_))) => {
+                match <Query as NmeaParse<I, E>>::parse(i.clone()) {
+                    Ok((i, query)) => Ok((i, AnySentence::Query(query))),
+                    Err(_) => unknown(i),
+                }
+            }
+            result => result.map(|(i, sentence)| (i, AnySentence::Known(sentence))),
+        }
+    }
+}
+
+/// Proprietary sentences have an address field starting with `P`, followed by a manufacturer
+/// code and a sentence type of any length.
+fn is_proprietary<I>(i: &I) -> bool
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+{
+    i.iter_elements().next().map(AsChar::as_char) == Some('P')
+}
+
+fn unknown<I, E>(i: I) -> IResult<I, AnySentence<I>, E>
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    let (i, address) =
+        take_while1(|item: <I as Input>::Item| item.as_char().is_ascii_alphanumeric()).parse(i)?;
+
+    let (talker, sentence_type) =
+        if address.iter_elements().next().map(AsChar::as_char) == Some('P') {
+            (None, address)
+        } else {
+            let (sentence_type, talker) = TalkerId::parse(address)?;
+            if sentence_type.input_len() == 0 {
+                return Err(nom::Err::Error(nom::error::make_error(
+                    sentence_type,
+                    ErrorKind::Verify,
+                )));
+            }
+            (Some(talker), sentence_type)
+        };
+
+    let (i, fields) = if i.input_len() == 0 {
+        (i, Fields::empty())
+    } else {
+        let (i, _) = char(',').parse(i)?;
+        let (i, fields) = rest.parse(i)?;
+        (i, Fields::new(fields))
+    };
+
+    Ok((
+        i,
+        AnySentence::Unknown {
+            talker,
+            sentence_type,
+            fields,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nmea_content::NmeaSentence;
+
+    #[test]
+    fn test_any_sentence_parsing() {
+        let result: IResult<_, _> = AnySentence::parse("GPDBT,12.5,f,3.8,M,2.1,F");
+        assert!(
+            matches!(
+                result,
+                Ok((
+                    "",
+                    AnySentence::Known(Sentence {
+                        talker: TalkerId::Gps,
+                        data: NmeaSentence::DBT(_)
+                    })
+                ))
+            ),
+            "{result:?}"
+        );
+
+        #[cfg(feature = "ublox")]
+        {
+            let result: IResult<_, _> = AnySentence::parse("PUBX,40,GLL,1,0,0,0,0,0");
+            assert!(
+                matches!(
+                    result,
+                    Ok(("", AnySentence::Proprietary(ProprietarySentence::PUBX(_))))
+                ),
+                "{result:?}"
+            );
+        }
+
+        #[cfg(feature = "garmin")]
+        {
+            let result: IResult<_, _> = AnySentence::parse("PGRME,15.0,M,45.0,M,25.0,M");
+            assert!(
+                matches!(
+                    result,
+                    Ok(("", AnySentence::Proprietary(ProprietarySentence::PGRME(_))))
+                ),
+                "{result:?}"
+            );
+        }
+
+        let result: IResult<_, _> = AnySentence::parse("CCGPQ,GGA");
+        assert!(
+            matches!(
+                result,
+                Ok((
+                    "",
+                    AnySentence::Query(Query {
+                        requester: TalkerId::Other(_),
+                        target_talker: TalkerId::Gps,
+                        ref sentence,
+                    })
+                )) if sentence == "GGA"
+            ),
+            "{result:?}"
+        );
+
+        let cases = [
+            ("PFEC,GPatt,125.3", None, "PFEC", vec!["GPatt", "125.3"]),
+            (
+                "CCGPQ,GGA,1",
+                Some(TalkerId::Other(*b"CC")),
+                "GPQ",
+                vec!["GGA", "1"],
+            ),
+            ("PUBX,05,1", None, "PUBX", vec!["05", "1"]),
+            ("PSRF", None, "PSRF", vec![]),
+            ("IIXYZ,", Some(TalkerId::Other(*b"II")), "XYZ", vec![""]),
+            (
+                "GPABC,A,,,N",
+                Some(TalkerId::Gps),
+                "ABC",
+                vec!["A", "", "", "N"],
+            ),
+        ];
+
+        for (input, expected_talker, expected_type, expected_fields) in cases {
+            let result: IResult<_, _> = AnySentence::parse(input.as_bytes());
+            let Ok((
+                b"",
+                AnySentence::Unknown {
+                    talker,
+                    sentence_type,
+                    fields,
+                },
+            )) = result
+            else {
+                panic!("Failed: {input:?}\n\t{result:?}");
+            };
+            assert_eq!(talker, expected_talker, "Failed: {input:?}");
+            assert_eq!(sentence_type, expected_type.as_bytes(), "Failed: {input:?}");
+            assert!(
+                fields.eq(expected_fields.iter().map(|field| field.as_bytes())),
+                "Failed: {input:?}"
+            );
+        }
+
+        let cases = [
+            // Malformed sentences of supported types are not unknown
+            "GPDBT,invalid",
+            "GP",
+            "GPXYZ;1",
+            #[cfg(feature = "ublox")]
+            "PUBX,40,GLL",
+            "",
+        ];
+
+        for input in cases {
+            let result: IResult<_, _> = AnySentence::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
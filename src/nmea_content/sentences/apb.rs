@@ -0,0 +1,168 @@
+use nom::{
+    AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser,
+    branch::alt,
+    character::complete::{char, one_of},
+    combinator::value,
+    error::ParseError,
+    sequence::separated_pair,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+
+use super::xte::{cross_track_error_with_unit, write_cross_track_error_with_unit};
+use crate::{
+    self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{ArrivalStatus, FaaMode, Status, WaypointId},
+};
+
+/// APB - Autopilot Sentence "B"
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_apb_autopilot_sentence_b>
+///
+/// ```text
+///         1 2 3   4 5 6 7 8   9 10   11  12 13  14
+///         | | |   | | | | |   | |    |   |  |   |
+///  $--APB,A,A,x.x,a,N,A,A,x.x,a,c--c,x.x,a,x.x,a*hh<CR><LF>
+/// ```
+///
+/// NMEA 2.3:
+///
+/// ```text
+///         1 2 3   4 5 6 7 8   9 10   11  12 13  14 15
+///         | | |   | | | | |   | |    |   |  |   |  |
+///  $--APB,A,A,x.x,a,N,A,A,x.x,a,c--c,x.x,a,x.x,a,m*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct APB {
+    /// Status, `Invalid` for a Loran-C blink or SNR warning
+    pub status: Status,
+    /// Status, `Invalid` for a Loran-C cycle lock warning
+    pub cycle_lock_status: Status,
+    #[nmea(
+        parser(cross_track_error_with_unit),
+        writer(write_cross_track_error_with_unit)
+    )]
+    /// Cross-track error in nautical miles, negative values indicate to steer left
+    pub cross_track_error: Option<f32>,
+    /// Whether the arrival circle was entered
+    pub arrival_circle: ArrivalStatus,
+    /// Whether the perpendicular at the destination waypoint was passed
+    pub perpendicular_passed: ArrivalStatus,
+    #[nmea(parser(bearing), writer(write_bearing))]
+    /// Bearing from origin to destination
+    pub bearing_origin_to_destination: Option<Bearing>,
+    /// Destination waypoint identifier
+    pub destination_waypoint_id: Option<WaypointId>,
+    #[nmea(parser(bearing), writer(write_bearing))]
+    /// Bearing from present position to destination
+    pub bearing_to_destination: Option<Bearing>,
+    #[nmea(parser(bearing), writer(write_bearing))]
+    /// Heading to steer to the destination waypoint
+    pub heading_to_steer: Option<Bearing>,
+    #[nmea(version(NmeaVersion::V2_3))]
+    /// FAA Mode Indicator
+    pub faa_mode: Option<FaaMode>,
+}
+
+/// Bearing either true or magnetic, as reported by [`APB`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Bearing {
+    /// Bearing in degrees
+    pub degrees: f32,
+    /// Whether the bearing is true or magnetic
+    pub reference: BearingReference,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("TM")))]
+/// Reference of a [`Bearing`]
+pub enum BearingReference {
+    #[default]
+    #[nmea(selector('T'))]
+    /// T - True
+    True,
+    #[nmea(selector('M'))]
+    /// M - Magnetic
+    Magnetic,
+}
+
+fn bearing<I, E>(i: I) -> IResult<I, Option<Bearing>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    alt((
+        value(None, char(',')),
+        separated_pair(f32::parse, char(','), one_of("TM")).map(|(degrees, reference)| {
+            let reference = if reference == 'M' {
+                BearingReference::Magnetic
+            } else {
+                BearingReference::True
+            };
+            Some(Bearing { degrees, reference })
+        }),
+    ))
+    .parse(i)
+}
+
+fn write_bearing(bearing: &Option<Bearing>, e: &mut Encoder<'_>) -> fmt::Result {
+    match bearing {
+        Some(bearing) => {
+            bearing.degrees.encode(e)?;
+            e.write_char(',')?;
+            bearing.reference.encode(e)
+        }
+        None => e.write_char(','),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apb_parsing() {
+        let input = "A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M,A";
+        let result: IResult<_, _> = APB::parse(input);
+        let (rest, apb) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(apb.cross_track_error, Some(0.1));
+        assert_eq!(apb.arrival_circle, ArrivalStatus::NotArrived);
+        assert_eq!(
+            apb.bearing_origin_to_destination,
+            Some(Bearing {
+                degrees: 11.0,
+                reference: BearingReference::Magnetic
+            })
+        );
+        assert_eq!(apb.destination_waypoint_id.as_deref(), Some("DEST"));
+        assert_eq!(apb.faa_mode, Some(FaaMode::Autonomous));
+
+        let input = "V,V,,,,A,A,,,,,,,";
+        let result: IResult<_, _> = APB::parse(input);
+        let (rest, apb) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(apb.cross_track_error, None);
+        assert_eq!(apb.perpendicular_passed, ArrivalStatus::Arrived);
+        assert_eq!(apb.heading_to_steer, None);
+        assert_eq!(apb.faa_mode, None);
+
+        for input in [
+            "A,A,0.10,R,N,V,V,011,X,DEST,011,M,011,M,A",
+            "A,A,0.10,R,N,V,V,011,,DEST,011,M,011,M,A",
+        ] {
+            let result: IResult<_, _> = APB::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{acn::AlertCommand, alf::AlertId};
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse};
+
+/// ARC - Alert Command Refused
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_arc_alert_command_refused>
+///
+/// ```text
+///         1         2   3   4   5
+///         |         |   |   |   |
+///  $--ARC,hhmmss.ss,aaa,x.x,x.x,c*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct ARC {
+    /// Time of the refusal in UTC
+    pub time: Option<time::Time>,
+    /// Identification of the alert
+    pub alert: AlertId,
+    /// Refused command
+    pub command: AlertCommand,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_arc_parsing() {
+        let cases = [
+            (
+                "124304.50,,192,1,A",
+                ARC {
+                    time: time::Time::from_hms_milli(12, 43, 4, 500).ok(),
+                    alert: AlertId {
+                        manufacturer: None,
+                        identifier: Some(192),
+                        instance: Some(1),
+                    },
+                    command: AlertCommand::Acknowledge,
+                },
+            ),
+            (
+                ",SRD,3008,,O",
+                ARC {
+                    time: None,
+                    alert: AlertId {
+                        manufacturer: Some("SRD".try_into().unwrap()),
+                        identifier: Some(3008),
+                        instance: None,
+                    },
+                    command: AlertCommand::ResponsibilityTransfer,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = ARC::parse(input);
+            assert_eq!(result, Ok(("", expected)), "Failed: {input:?}");
+        }
+
+        for input in [
+            "124304.50,,192,1,X",
+            "124304.50,,192,1,",
+            "124304.50,ABCD,192,1,A",
+            "124304.50,,192,1",
+        ] {
+            let result: IResult<_, _> = ARC::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
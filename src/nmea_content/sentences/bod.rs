@@ -0,0 +1,71 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{WaypointId, encode::write_with_unit, parse::with_unit},
+};
+
+/// BOD - Bearing - Waypoint to Waypoint
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_bod_bearing_waypoint_to_waypoint>
+///
+/// ```text
+///         1   2 3   4 5    6
+///         |   | |   | |    |
+///  $--BOD,x.x,T,x.x,M,c--c,c--c*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct BOD {
+    #[nmea(parser(with_unit('T')), writer(write_with_unit('T')))]
+    /// Bearing from origin to destination in degrees true
+    pub bearing_true: Option<f32>,
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
+    /// Bearing from origin to destination in degrees magnetic
+    pub bearing_magnetic: Option<f32>,
+    /// Destination waypoint identifier
+    pub destination_waypoint_id: Option<WaypointId>,
+    /// Origin waypoint identifier
+    pub origin_waypoint_id: Option<WaypointId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_bod_parsing() {
+        let result: IResult<_, _> = BOD::parse("097.0,T,103.2,M,POINTB,POINTA");
+        let (rest, bod) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(bod.bearing_true, Some(97.0));
+        assert_eq!(bod.bearing_magnetic, Some(103.2));
+        assert_eq!(bod.destination_waypoint_id.as_deref(), Some("POINTB"));
+        assert_eq!(bod.origin_waypoint_id.as_deref(), Some("POINTA"));
+
+        let result: IResult<_, _> = BOD::parse("097.0,T,,M,POINTB,");
+        let (rest, bod) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(bod.bearing_true, Some(97.0));
+        assert_eq!(bod.bearing_magnetic, None);
+        assert_eq!(bod.destination_waypoint_id.as_deref(), Some("POINTB"));
+        assert_eq!(bod.origin_waypoint_id, None);
+
+        let result: IResult<_, _> = BOD::parse(",,,,,");
+        assert_eq!(result, Ok(("", BOD::default())));
+
+        for input in [
+            "097.0,M,103.2,M,POINTB,POINTA",
+            "097.0,T,103.2,T,POINTB,POINTA",
+            "097.0,T,103.2,M,WAYPOINT0123,POINTA",
+            "097.0,T,103.2,M,POINTB",
+        ] {
+            let result: IResult<_, _> = BOD::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{
+        FaaMode, Location, WaypointId,
+        encode::{write_location, write_with_unit},
+        parse::{location, with_unit},
+    },
+};
+
+/// BWC - Bearing & Distance to Waypoint - Great Circle
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_bwc_bearing_distance_to_waypoint_great_circle>
+///
+/// ```text
+///         1         2       3 4        5 6   7 8   9 10  11 12
+///         |         |       | |        | |   | |   | |   |  |
+///  $--BWC,hhmmss.ss,llll.ll,a,yyyyy.yy,a,x.x,T,x.x,M,x.x,N,c--c*hh<CR><LF>
+/// ```
+///
+/// NMEA 2.3:
+///
+/// ```text
+///         1         2       3 4        5 6   7 8   9 10  11 12   13
+///         |         |       | |        | |   | |   | |   |  |    |
+///  $--BWC,hhmmss.ss,llll.ll,a,yyyyy.yy,a,x.x,T,x.x,M,x.x,N,c--c,m*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct BWC {
+    /// Time of the observation in UTC
+    pub fix_time: Option<time::Time>,
+    #[nmea(parser(location), writer(write_location))]
+    /// Waypoint location (latitude and longitude)
+    pub waypoint_location: Option<Location>,
+    #[nmea(parser(with_unit('T')), writer(write_with_unit('T')))]
+    /// Bearing to the waypoint in degrees true
+    pub bearing_true: Option<f32>,
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
+    /// Bearing to the waypoint in degrees magnetic
+    pub bearing_magnetic: Option<f32>,
+    #[nmea(parser(with_unit('N')), writer(write_with_unit('N')))]
+    /// Great circle distance to the waypoint in nautical miles
+    pub distance: Option<f32>,
+    /// Waypoint identifier
+    pub waypoint_id: Option<WaypointId>,
+    #[nmea(version(NmeaVersion::V2_3))]
+    /// FAA Mode Indicator
+    pub faa_mode: Option<FaaMode>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_bwc_parsing() {
+        let input = "225444,4917.24,N,12309.57,W,051.9,T,031.6,M,001.3,N,004,A";
+        let result: IResult<_, _> = BWC::parse(input);
+        let (rest, bwc) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(bwc.fix_time, time::Time::from_hms(22, 54, 44).ok());
+        assert_eq!(bwc.bearing_true, Some(51.9));
+        assert_eq!(bwc.bearing_magnetic, Some(31.6));
+        assert_eq!(bwc.distance, Some(1.3));
+        assert_eq!(bwc.waypoint_id.as_deref(), Some("004"));
+        assert_eq!(bwc.faa_mode, Some(FaaMode::Autonomous));
+
+        let input = ",,,,,,T,,M,,N,";
+        let result: IResult<_, _> = BWC::parse(input);
+        let (rest, bwc) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(bwc.waypoint_location, None);
+        assert_eq!(bwc.distance, None);
+    }
+}
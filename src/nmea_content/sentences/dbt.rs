@@ -4,8 +4,12 @@ use nom::{
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
 
-use crate::{self as nmea0183_parser, IResult, NmeaParse, nmea_content::parse::with_unit};
+use crate::{
+    self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse,
+    nmea_content::{encode::write_with_unit, parse::with_unit},
+};
 
 /// DBT - Depth Below Transducer
 ///
@@ -18,9 +22,10 @@ use crate::{self as nmea0183_parser, IResult, NmeaParse, nmea_content::parse::wi
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 pub struct DBT {
-    #[nmea(parser(water_depth))]
+    #[nmea(parser(water_depth), writer(write_water_depth))]
     /// Water depth in meters
     pub water_depth: Option<f32>,
 }
@@ -45,3 +50,11 @@ where
 
     Ok((i, water_depth))
 }
+
+fn write_water_depth(water_depth: &Option<f32>, e: &mut Encoder<'_>) -> fmt::Result {
+    write_with_unit('f')(&water_depth.map(|meters| meters / 0.3048), e)?;
+    e.write_char(',')?;
+    write_with_unit('M')(water_depth, e)?;
+    e.write_char(',')?;
+    write_with_unit('F')(&water_depth.map(|meters| meters / 1.8288), e)
+}
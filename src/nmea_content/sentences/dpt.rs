@@ -1,7 +1,7 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{self as nmea0183_parser, NmeaParse};
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse, NmeaVersion};
 
 /// DPT - Depth of Water
 ///
@@ -21,7 +21,8 @@ use crate::{self as nmea0183_parser, NmeaParse};
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 pub struct DPT {
     /// Water depth relative to transducer in meters
     pub water_depth: Option<f32>,
@@ -29,8 +30,7 @@ pub struct DPT {
     /// positive means distance from transducer to water line,
     /// negative means distance from transducer to keel
     pub offset_from_transducer: Option<f32>,
-    #[cfg(feature = "nmea-v3-0")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v3-0")))]
+    #[nmea(version(NmeaVersion::V3_0))]
     /// Maximum range scale in used for the measurement in meters
     pub max_range_scale: Option<f32>,
 }
@@ -40,7 +40,6 @@ mod tests {
     use super::*;
     use crate::IResult;
 
-    #[cfg(not(feature = "nmea-v3-0"))]
     #[test]
     fn test_dpt_parsing() {
         let compares = [
@@ -49,7 +48,6 @@ mod tests {
                 DPT {
                     water_depth: Some(10.0),
                     offset_from_transducer: Some(2.0),
-                    #[cfg(feature = "nmea-v3-0")]
                     max_range_scale: None,
                 },
             ),
@@ -58,7 +56,6 @@ mod tests {
                 DPT {
                     water_depth: None,
                     offset_from_transducer: Some(2.0),
-                    #[cfg(feature = "nmea-v3-0")]
                     max_range_scale: None,
                 },
             ),
@@ -67,7 +64,6 @@ mod tests {
                 DPT {
                     water_depth: Some(10.0),
                     offset_from_transducer: None,
-                    #[cfg(feature = "nmea-v3-0")]
                     max_range_scale: None,
                 },
             ),
@@ -94,7 +90,6 @@ mod tests {
         }
     }
 
-    #[cfg(feature = "nmea-v3-0")]
     #[test]
     fn test_dpt_parsing_v3_0() {
         let compares = [
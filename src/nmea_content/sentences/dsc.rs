@@ -0,0 +1,299 @@
+use nom::{
+    AsChar, Input, Parser,
+    character::complete::{char, one_of},
+    combinator::opt,
+    error::ParseError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+
+use crate::{
+    self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse,
+    nmea_content::{Location, parse::code},
+};
+
+/// Maximum length of the position and time fields of [`DSC`].
+pub const DSC_FIELD_CAPACITY: usize = 16;
+
+/// DSC - Digital Selective Calling Information
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_dsc_digital_selective_calling_information>
+///
+/// ```text
+///         1  2          3  4  5  6   7   8          9  1011
+///         |  |          |  |  |  |   |   |          |  | |
+///  $--DSC,xx,xxxxxxxxxx,xx,xx,xx,x.x,x.x,xxxxxxxxxx,xx,a,a*hh<CR><LF>
+/// ```
+///
+/// The position and time fields are kept as sent, as they may carry a working
+/// channel and a telephone number instead; [`DSC::position`] and [`DSC::time`]
+/// decode them.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct DSC {
+    /// Format specifier of the call
+    pub format: Option<DscFormat>,
+    #[nmea(writer(write_digits(10)))]
+    /// Address of the call, the MMSI of the called station followed by `0`, or a
+    /// geographic area
+    pub address: Option<u64>,
+    /// Category of the call
+    pub category: Option<DscCategory>,
+    #[nmea(writer(write_digits(2)))]
+    /// Nature of distress or first telecommand
+    pub first_telecommand: Option<u8>,
+    #[nmea(writer(write_digits(2)))]
+    /// Type of communication or second telecommand
+    pub second_telecommand: Option<u8>,
+    /// Position as `qddmmdddmm` digits, or working channel or frequency
+    pub position_or_channel: Option<heapless::String<DSC_FIELD_CAPACITY>>,
+    /// Time in UTC as `hhmm` digits, or telephone number
+    pub time_or_phone: Option<heapless::String<DSC_FIELD_CAPACITY>>,
+    #[nmea(map(|address: Option<u64>| address.map(|address| (address / 10) as u32)))]
+    #[nmea(parse_as(Option<u64>), writer(write_mmsi))]
+    /// MMSI of the vessel in distress
+    pub distress_mmsi: Option<u32>,
+    /// Nature of distress
+    pub nature_of_distress: Option<DistressNature>,
+    /// Acknowledgement
+    pub acknowledgement: Option<DscAcknowledgement>,
+    #[nmea(parser(expansion), writer(write_expansion))]
+    /// Whether the call is followed by [`DSE`](crate::nmea_content::DSE) expansion sentences
+    pub expansion: bool,
+}
+
+impl DSC {
+    /// Returns the MMSI of the called station, unless the call is addressed to a geographic area.
+    pub fn mmsi(&self) -> Option<u32> {
+        match self.format {
+            Some(DscFormat::GeographicArea) => None,
+            _ => self.address.map(|address| (address / 10) as u32),
+        }
+    }
+
+    /// Decodes the position of the `qddmmdddmm` position field, where the quadrant `q`
+    /// is 0 for NE, 1 for NW, 2 for SE and 3 for SW.
+    pub fn position(&self) -> Option<Location> {
+        let digits = self.position_or_channel.as_deref()?;
+        if digits.len() != 10 || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let value = |range: std::ops::Range<usize>| digits[range].parse::<f64>().ok();
+        let latitude = value(1..3)? + value(3..5)? / 60.0;
+        let longitude = value(5..8)? + value(8..10)? / 60.0;
+
+        let (latitude, longitude) = match &digits[..1] {
+            "0" => (latitude, longitude),
+            "1" => (latitude, -longitude),
+            "2" => (-latitude, longitude),
+            "3" => (-latitude, -longitude),
+            _ => return None,
+        };
+
+        Some(Location {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// Decodes the time of the `hhmm` time field.
+    pub fn time(&self) -> Option<time::Time> {
+        let digits = self.time_or_phone.as_deref()?;
+        if digits.len() != 4 {
+            return None;
+        }
+
+        let hour = digits[..2].parse().ok()?;
+        let minute = digits[2..].parse().ok()?;
+        time::Time::from_hms(hour, minute, 0).ok()
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(code(2)))]
+/// Format specifier of a [`DSC`] call
+pub enum DscFormat {
+    #[nmea(selector("02"))]
+    /// 02 - Geographic area call
+    GeographicArea,
+    #[nmea(selector("12"))]
+    /// 12 - Distress alert
+    Distress,
+    #[nmea(selector("14"))]
+    /// 14 - Group call
+    Group,
+    #[nmea(selector("16"))]
+    /// 16 - All ships call
+    AllShips,
+    #[default]
+    #[nmea(selector("20"))]
+    /// 20 - Individual station call
+    Individual,
+    #[nmea(selector("23"))]
+    /// 23 - Individual station semi-automatic or automatic service
+    AutomaticService,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(code(2)))]
+/// Category of a [`DSC`] call
+pub enum DscCategory {
+    #[default]
+    #[nmea(selector("00"))]
+    /// 00 - Routine
+    Routine,
+    #[nmea(selector("08"))]
+    /// 08 - Safety
+    Safety,
+    #[nmea(selector("10"))]
+    /// 10 - Urgency
+    Urgency,
+    #[nmea(selector("12"))]
+    /// 12 - Distress
+    Distress,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(code(2)))]
+/// Nature of distress of a [`DSC`] distress alert
+pub enum DistressNature {
+    #[nmea(selector("00"))]
+    /// 00 - Fire, explosion
+    Fire,
+    #[nmea(selector("01"))]
+    /// 01 - Flooding
+    Flooding,
+    #[nmea(selector("02"))]
+    /// 02 - Collision
+    Collision,
+    #[nmea(selector("03"))]
+    /// 03 - Grounding
+    Grounding,
+    #[nmea(selector("04"))]
+    /// 04 - Listing, in danger of capsizing
+    Listing,
+    #[nmea(selector("05"))]
+    /// 05 - Sinking
+    Sinking,
+    #[nmea(selector("06"))]
+    /// 06 - Disabled and adrift
+    Adrift,
+    #[default]
+    #[nmea(selector("07"))]
+    /// 07 - Undesignated distress
+    Undesignated,
+    #[nmea(selector("08"))]
+    /// 08 - Abandoning ship
+    AbandoningShip,
+    #[nmea(selector("09"))]
+    /// 09 - Piracy, armed robbery attack
+    Piracy,
+    #[nmea(selector("10"))]
+    /// 10 - Man overboard
+    ManOverboard,
+    #[nmea(selector("12"))]
+    /// 12 - EPIRB emission
+    Epirb,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("RBS")))]
+/// Acknowledgement of a [`DSC`] call
+pub enum DscAcknowledgement {
+    #[nmea(selector('R'))]
+    /// R - Acknowledgement requested
+    Requested,
+    #[nmea(selector('B'))]
+    /// B - Acknowledgement
+    Acknowledgement,
+    #[default]
+    #[nmea(selector('S'))]
+    /// S - Neither, end of sequence
+    EndOfSequence,
+}
+
+/// Writes an integer padded with zeros to `width` digits.
+fn write_digits<T>(width: usize) -> impl Fn(&Option<T>, &mut Encoder<'_>) -> fmt::Result
+where
+    T: fmt::Display,
+{
+    move |value, e| match value {
+        Some(value) => write!(e, "{value:0width$}"),
+        None => Ok(()),
+    }
+}
+
+/// Writes an MMSI as the ten digits of a DSC address.
+pub fn write_mmsi(mmsi: &Option<u32>, e: &mut Encoder<'_>) -> fmt::Result {
+    match mmsi {
+        Some(mmsi) => write!(e, "{mmsi:09}0"),
+        None => Ok(()),
+    }
+}
+
+fn expansion<I, E>(i: I) -> IResult<I, bool, E>
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    opt(char('E')).map(|flag| flag.is_some()).parse(i)
+}
+
+fn write_expansion(expansion: &bool, e: &mut Encoder<'_>) -> fmt::Result {
+    if *expansion {
+        e.write_char('E')?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dsc_parsing() {
+        let result: IResult<_, _> = DSC::parse("12,3380400790,12,06,00,1423108312,2019,,,S,E");
+        let (rest, dsc) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(dsc.format, Some(DscFormat::Distress));
+        assert_eq!(dsc.mmsi(), Some(338040079));
+        assert_eq!(dsc.category, Some(DscCategory::Distress));
+        assert_eq!(dsc.first_telecommand, Some(6));
+        assert_eq!(dsc.acknowledgement, Some(DscAcknowledgement::EndOfSequence));
+        assert!(dsc.expansion);
+        assert_eq!(dsc.time(), time::Time::from_hms(20, 19, 0).ok());
+        let location = dsc.position().unwrap();
+        assert!((location.latitude - 42.516_667).abs() < 1e-6);
+        assert!((location.longitude + 83.2).abs() < 1e-6);
+
+        let result: IResult<_, _> = DSC::parse("20,0023100000,00,,,9999999999,8888,,,R,");
+        let (rest, dsc) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(dsc.mmsi(), Some(2310000));
+        assert_eq!(dsc.position(), None);
+        assert_eq!(dsc.time(), None);
+
+        for input in [
+            "13,3380400790,12,06,00,1423108312,2019,,,S,E",
+            "12,3380400790,11,06,00,1423108312,2019,,,S,E",
+            "12,3380400790,12,06,00,1423108312,2019,,,X,E",
+            "12,3380400790,12,06,00,1423108312,2019,,,S",
+        ] {
+            let result: IResult<_, _> = DSC::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::dsc::{DSC_FIELD_CAPACITY, write_mmsi};
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse, nmea_content::parse::code};
+
+/// Maximum number of data sets carried by a single [`DSE`] sentence.
+pub const DSE_DATA_SETS_CAPACITY: usize = 4;
+
+/// DSE - Expanded Digital Selective Calling
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_dse_expanded_digital_selective_calling>
+///
+/// ```text
+///         1 2 3 4          5  6        x  x+1
+///         | | | |          |  |        |  |
+///  $--DSE,x,x,a,xxxxxxxxxx,xx,c--c,...,xx,c--c*hh<CR><LF>
+/// ```
+///
+/// Expands the [`DSC`](crate::nmea_content::DSC) call of the same vessel with data sets,
+/// such as the enhanced resolution of its position.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+// The data sets take the whole sentence, any unparsed input is a malformed data set
+#[nmea(exact)]
+pub struct DSE {
+    /// Total number of DSE sentences for this call
+    pub total_messages: u8,
+    /// Sentence number of this DSE message within the call
+    pub message_number: u8,
+    /// Query or reply flag
+    pub flag: DseFlag,
+    #[nmea(map(|address: Option<u64>| address.map(|address| (address / 10) as u32)))]
+    #[nmea(parse_as(Option<u64>), writer(write_mmsi))]
+    /// MMSI of the vessel
+    pub mmsi: Option<u32>,
+    /// Data sets of the expansion
+    pub data_sets: heapless::Vec<DseDataSet, DSE_DATA_SETS_CAPACITY>,
+}
+
+/// Data set of a [`DSE`] expansion
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct DseDataSet {
+    /// Code of the data set
+    pub code: DseCode,
+    /// Data, as sent
+    pub data: Option<heapless::String<DSC_FIELD_CAPACITY>>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("QRA")))]
+/// Query or reply flag of [`DSE`]
+pub enum DseFlag {
+    #[nmea(selector('Q'))]
+    /// Q - Query
+    Query,
+    #[nmea(selector('R'))]
+    /// R - Reply
+    Reply,
+    #[default]
+    #[nmea(selector('A'))]
+    /// A - Automatic
+    Automatic,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(code(2)))]
+/// Code of a [`DSE`] data set
+pub enum DseCode {
+    #[default]
+    #[nmea(selector("00"))]
+    /// 00 - Enhanced position resolution, the decimals of the latitude and longitude minutes
+    EnhancedPosition,
+    #[nmea(selector("01"))]
+    /// 01 - Source and datum of the position
+    SourceAndDatum,
+    #[nmea(selector("02"))]
+    /// 02 - Speed over ground
+    SpeedOverGround,
+    #[nmea(selector("03"))]
+    /// 03 - Course over ground
+    CourseOverGround,
+    #[nmea(selector("04"))]
+    /// 04 - Additional station identification
+    StationIdentification,
+    #[nmea(selector("05"))]
+    /// 05 - Enhanced geographic area
+    EnhancedGeographicArea,
+    #[nmea(selector("06"))]
+    /// 06 - Number of persons on board
+    PersonsOnBoard,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_dse_parsing() {
+        let result: IResult<_, _> = DSE::parse("1,1,A,3380400790,00,45894494,06,12");
+        let (rest, dse) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(dse.flag, DseFlag::Automatic);
+        assert_eq!(dse.mmsi, Some(338040079));
+        assert_eq!(dse.data_sets.len(), 2);
+        assert_eq!(dse.data_sets[0].code, DseCode::EnhancedPosition);
+        assert_eq!(dse.data_sets[0].data.as_deref(), Some("45894494"));
+        assert_eq!(dse.data_sets[1].code, DseCode::PersonsOnBoard);
+
+        for input in [
+            "1,1,X,3380400790,00,45894494",
+            "1,1,A,3380400790,99,45894494",
+        ] {
+            let result: IResult<_, _> = DSE::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
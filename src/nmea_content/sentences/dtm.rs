@@ -0,0 +1,149 @@
+use nom::{
+    AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser,
+    branch::alt,
+    character::complete::{char, none_of, one_of},
+    combinator::{opt, value},
+    error::ParseError,
+    sequence::separated_pair,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+
+use crate::{self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse};
+
+/// Maximum length of a datum code reported by [`DTM`].
+pub const DATUM_CODE_CAPACITY: usize = 5;
+
+/// DTM - Datum Reference
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_dtm_datum_reference>
+///
+/// ```text
+///         1   2 3   4 5   6 7   8
+///         |   | |   | |   | |   |
+///  $--DTM,ccc,a,x.x,a,x.x,a,x.x,ccc*hh<CR><LF>
+/// ```
+///
+/// Datum codes are `W84` for WGS-84, `W72` for WGS-72, `S85` for SGS-85, `P90` for PE-90,
+/// `999` for a user defined datum, or an IHO datum code.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct DTM {
+    /// Local datum code
+    pub local_datum: Option<heapless::String<DATUM_CODE_CAPACITY>>,
+    #[nmea(parser(opt(none_of(","))), writer(NmeaEncode::encode))]
+    /// Local datum subdivision code
+    pub local_datum_subdivision: Option<char>,
+    #[nmea(parser(latitude_offset), writer(write_latitude_offset))]
+    /// Latitude offset in minutes, negative values indicate an offset to the south
+    pub latitude_offset: Option<f32>,
+    #[nmea(parser(longitude_offset), writer(write_longitude_offset))]
+    /// Longitude offset in minutes, negative values indicate an offset to the west
+    pub longitude_offset: Option<f32>,
+    /// Altitude offset in meters
+    pub altitude_offset: Option<f32>,
+    /// Reference datum code
+    pub reference_datum: Option<heapless::String<DATUM_CODE_CAPACITY>>,
+}
+
+fn latitude_offset<I, E>(i: I) -> IResult<I, Option<f32>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    alt((
+        value(None, char(',')),
+        separated_pair(f32::parse, char(','), one_of("NS")).map(|(value, dir)| {
+            if dir == 'S' {
+                Some(-value)
+            } else {
+                Some(value)
+            }
+        }),
+    ))
+    .parse(i)
+}
+
+fn write_latitude_offset(latitude_offset: &Option<f32>, e: &mut Encoder<'_>) -> fmt::Result {
+    match latitude_offset {
+        Some(value) => {
+            value.abs().encode(e)?;
+            let dir = if value.is_sign_negative() { 'S' } else { 'N' };
+            write!(e, ",{dir}")
+        }
+        None => e.write_char(','),
+    }
+}
+
+fn longitude_offset<I, E>(i: I) -> IResult<I, Option<f32>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    alt((
+        value(None, char(',')),
+        separated_pair(f32::parse, char(','), one_of("EW")).map(|(value, dir)| {
+            if dir == 'W' {
+                Some(-value)
+            } else {
+                Some(value)
+            }
+        }),
+    ))
+    .parse(i)
+}
+
+fn write_longitude_offset(longitude_offset: &Option<f32>, e: &mut Encoder<'_>) -> fmt::Result {
+    match longitude_offset {
+        Some(value) => {
+            value.abs().encode(e)?;
+            let dir = if value.is_sign_negative() { 'W' } else { 'E' };
+            write!(e, ",{dir}")
+        }
+        None => e.write_char(','),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dtm_parsing() {
+        let result: IResult<_, _> = DTM::parse("999,,0.08,N,0.07,W,-47.7,W84");
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                DTM {
+                    local_datum: Some("999".try_into().unwrap()),
+                    local_datum_subdivision: None,
+                    latitude_offset: Some(0.08),
+                    longitude_offset: Some(-0.07),
+                    altitude_offset: Some(-47.7),
+                    reference_datum: Some("W84".try_into().unwrap()),
+                }
+            ))
+        );
+
+        let result: IResult<_, _> = DTM::parse("W84,A,0.5,S,,,,W84");
+        let (_, dtm) = result.unwrap();
+        assert_eq!(dtm.local_datum_subdivision, Some('A'));
+        assert_eq!(dtm.latitude_offset, Some(-0.5));
+        assert_eq!(dtm.longitude_offset, None);
+
+        for input in ["W84,,0.08,E,0.07,W,-47.7,W84", "W84,,0.08,N,0.07,W,-47.7"] {
+            let result: IResult<_, _> = DTM::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use nom::combinator::opt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{SignalId, SystemId, parse::hex_digit},
+};
+
+/// GBS - GPS Satellite Fault Detection
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_gbs_gps_satellite_fault_detection>
+///
+/// ```text
+///         1         2   3   4   5   6   7   8
+///         |         |   |   |   |   |   |   |
+///  $--GBS,hhmmss.ss,x.x,x.x,x.x,x.x,x.x,x.x,x.x*hh<CR><LF>
+/// ```
+///
+/// NMEA 4.11:
+/// ```text
+///         1         2   3   4   5   6   7   8   9 10
+///         |         |   |   |   |   |   |   |   | |
+///  $--GBS,hhmmss.ss,x.x,x.x,x.x,x.x,x.x,x.x,x.x,h,h*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct GBS {
+    /// Fix time in UTC
+    pub fix_time: Option<time::Time>,
+    /// Expected error in latitude in meters
+    pub latitude_error: Option<f32>,
+    /// Expected error in longitude in meters
+    pub longitude_error: Option<f32>,
+    /// Expected error in altitude in meters
+    pub altitude_error: Option<f32>,
+    /// PRN number of the most likely failed satellite
+    pub failed_satellite: Option<u8>,
+    /// Probability of missed detection of the most likely failed satellite
+    pub missed_detection_probability: Option<f32>,
+    /// Estimate of the bias on the most likely failed satellite in meters
+    pub bias: Option<f32>,
+    /// Standard deviation of the bias estimate in meters
+    pub bias_deviation: Option<f32>,
+    #[nmea(version(NmeaVersion::V4_11))]
+    /// System ID of the GNSS system of the most likely failed satellite
+    pub system_id: Option<SystemId>,
+    #[nmea(version(NmeaVersion::V4_11))]
+    #[nmea(map(|id: Option<u8>| id.map(|id| signal_id(system_id, id))))]
+    #[nmea(parser(opt(hex_digit)), writer(NmeaEncode::encode))]
+    /// Signal ID of the most likely failed satellite
+    pub signal_id: Option<SignalId>,
+}
+
+/// Decodes a signal ID with the system ID reported in the same sentence.
+pub fn signal_id(system_id: Option<SystemId>, id: u8) -> SignalId {
+    match system_id {
+        Some(system_id) => SignalId::new(system_id, id),
+        None => SignalId::Unknown(id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        IResult,
+        nmea_content::{GpsSignalId, NmeaSentence},
+    };
+
+    #[test]
+    fn test_gbs_parsing() {
+        let input = "015509.00,-0.031,-0.186,0.219,19,0.000,-0.354,6.972,1,1";
+        let result: IResult<_, _> = GBS::parse(input);
+        let (rest, gbs) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(gbs.latitude_error, Some(-0.031));
+        assert_eq!(gbs.failed_satellite, Some(19));
+        assert_eq!(gbs.bias_deviation, Some(6.972));
+        assert_eq!(gbs.system_id, Some(SystemId::Gps));
+        assert_eq!(gbs.signal_id, Some(SignalId::Gps(GpsSignalId::L1CA)));
+
+        let result: IResult<_, _> = GBS::parse(",,,,,,,,,");
+        assert_eq!(result, Ok(("", GBS::default())));
+
+        let result: IResult<_, _> = GBS::parse(",,,,,,,");
+        assert_eq!(result, Ok(("", GBS::default())));
+
+        // The signal ID is a single hexadecimal digit
+        let input = "GPGBS,015509.00,-0.031,-0.186,0.219,19,0.000,-0.354,6.972,1,101";
+        let result: IResult<_, _> = NmeaSentence::parse(input);
+        assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+    }
+}
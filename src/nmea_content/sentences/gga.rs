@@ -1,12 +1,13 @@
-use std::time::Duration;
+use std::{fmt, time::Duration};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    self as nmea0183_parser, NmeaParse,
+    self as nmea0183_parser, Encoder, NmeaEncode, NmeaParse,
     nmea_content::{
         Location, Quality,
+        encode::{write_location, write_with_unit},
         parse::{location, with_unit},
     },
 };
@@ -23,11 +24,12 @@ use crate::{
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 pub struct GGA {
     /// Fix time in UTC
     pub fix_time: Option<time::Time>,
-    #[nmea(parser(location))]
+    #[nmea(parser(location), writer(write_location))]
     /// Location (latitude and longitude)
     pub location: Option<Location>,
     /// GPS Quality Indicator
@@ -36,20 +38,25 @@ pub struct GGA {
     pub satellite_count: Option<u8>,
     /// Horizontal Dilution of Precision
     pub hdop: Option<f32>,
-    #[nmea(parser(with_unit('M')))]
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
     /// Altitude above/below mean sea level (geoid) in meters
     pub altitude: Option<f32>,
-    #[nmea(parser(with_unit('M')))]
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
     /// Geoidal separation in meters, the difference between the WGS-84 earth ellipsoid and mean sea level (geoid),
     /// negative values indicate that the geoid is below the ellipsoid
     pub geoidal_separation: Option<f32>,
     #[nmea(map(|value| value.map(|sec| Duration::from_millis((sec * 1000.0) as u64))), parse_as(Option<f32>))]
+    #[nmea(writer(write_age_of_dgps))]
     /// Age of Differential GPS data in seconds, time since last SC104 type 1 or 9 update, null field when DGPS is not used
     pub age_of_dgps: Option<Duration>,
     /// Differential reference station ID
     pub ref_station_id: Option<u16>,
 }
 
+pub fn write_age_of_dgps(age_of_dgps: &Option<Duration>, e: &mut Encoder<'_>) -> fmt::Result {
+    age_of_dgps.map(|age| age.as_secs_f32()).encode(e)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
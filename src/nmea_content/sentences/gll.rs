@@ -1,11 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "nmea-v2-3")]
-use crate::nmea_content::FaaMode;
 use crate::{
-    self as nmea0183_parser, NmeaParse,
-    nmea_content::{Location, Status, parse::location},
+    self as nmea0183_parser, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{FaaMode, Location, Status, encode::write_location, parse::location},
 };
 
 /// GLL - Geographic Position - Latitude/Longitude
@@ -26,17 +24,17 @@ use crate::{
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 pub struct GLL {
-    #[nmea(parser(location))]
+    #[nmea(parser(location), writer(write_location))]
     /// Location (latitude and longitude)
     pub location: Option<Location>,
     /// Fix time in UTC
     pub fix_time: Option<time::Time>,
     /// Status Mode Indicator
     pub status: Status,
-    #[cfg(feature = "nmea-v2-3")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
+    #[nmea(version(NmeaVersion::V2_3))]
     /// FAA Mode Indicator
     pub faa_mode: Option<FaaMode>,
 }
@@ -44,11 +42,11 @@ pub struct GLL {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::IResult;
+    use crate::{IResult, nmea_content::NmeaSentence};
 
     #[test]
     fn test_gll_parsing() {
-        let cases = [",", ",A"];
+        let cases = ["", ",", ",A"];
 
         for &input in &cases {
             let i = format!("4404.14012,N,12118.85993,W,001037.00,A{}", input);
@@ -60,7 +58,6 @@ mod tests {
         }
     }
 
-    #[cfg(feature = "nmea-v2-3")]
     #[test]
     fn test_gll_parsing_v2_3() {
         let cases = ["", "Z", ",Z"];
@@ -68,8 +65,15 @@ mod tests {
         for &input in &cases {
             let i = format!("4404.14012,N,12118.85993,W,001037.00,A{}", input);
 
-            let result: IResult<_, _> = GLL::parse(i.as_str());
+            let result: IResult<_, _> = GLL::parse_version(i.as_str(), NmeaVersion::V2_3.into());
             assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
         }
+
+        // The FAA mode is left unparsed, and rejected by the sentence
+        let result: IResult<_, _> = NmeaSentence::parse_version(
+            "GPGLL,4404.14012,N,12118.85993,W,001037.00,A,A",
+            NmeaVersion::V2_0.into(),
+        );
+        assert!(result.is_err(), "{result:?}");
     }
 }
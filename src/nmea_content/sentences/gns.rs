@@ -0,0 +1,168 @@
+use nom::{AsChar, Input, Parser, character::complete::one_of, error::ParseError, multi::many_m_n};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{fmt, time::Duration};
+
+use super::gga::write_age_of_dgps;
+use crate::{
+    self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{Location, NavStatus, SystemId, encode::write_location, parse::location},
+};
+
+/// Maximum number of GNSS systems reported by the mode indicator of a [`GNS`] sentence.
+pub const GNS_MODES_CAPACITY: usize = 8;
+
+/// GNS - Fix data
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_gns_fix_data>
+///
+/// ```text
+///         1         2       3 4        5 6    7  8   9   10  11  12
+///         |         |       | |        | |    |  |   |   |   |   |
+///  $--GNS,hhmmss.ss,ddmm.mm,a,dddmm.mm,a,c--c,xx,x.x,x.x,x.x,x.x,x.x*hh<CR><LF>
+/// ```
+///
+/// NMEA 4.11:
+/// ```text
+///         1         2       3 4        5 6    7  8   9   10  11  12  13
+///         |         |       | |        | |    |  |   |   |   |   |   |
+///  $--GNS,hhmmss.ss,ddmm.mm,a,dddmm.mm,a,c--c,xx,x.x,x.x,x.x,x.x,x.x,a*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct GNS {
+    /// Fix time in UTC
+    pub fix_time: Option<time::Time>,
+    #[nmea(parser(location), writer(write_location))]
+    /// Location (latitude and longitude)
+    pub location: Option<Location>,
+    #[nmea(parser(system_modes), writer(write_system_modes))]
+    /// Mode indicator of each GNSS system, in the order GPS, GLONASS, Galileo, BeiDou, QZSS and NavIC
+    pub modes: heapless::Vec<GnsMode, GNS_MODES_CAPACITY>,
+    /// Number of satellites in use
+    pub satellite_count: Option<u8>,
+    /// Horizontal Dilution of Precision
+    pub hdop: Option<f32>,
+    /// Altitude above/below mean sea level (geoid) in meters
+    pub altitude: Option<f32>,
+    /// Geoidal separation in meters, the difference between the WGS-84 earth ellipsoid and mean sea level (geoid),
+    /// negative values indicate that the geoid is below the ellipsoid
+    pub geoidal_separation: Option<f32>,
+    #[nmea(map(|value| value.map(|sec| Duration::from_millis((sec * 1000.0) as u64))), parse_as(Option<f32>))]
+    #[nmea(writer(write_age_of_dgps))]
+    /// Age of differential data in seconds, null field when differential corrections are not used
+    pub age_of_differential: Option<Duration>,
+    /// Differential reference station ID
+    pub ref_station_id: Option<u16>,
+    #[nmea(version(NmeaVersion::V4_11))]
+    /// Navigation status
+    pub nav_status: Option<NavStatus>,
+}
+
+impl GNS {
+    /// Returns the mode indicator of the given GNSS system, if reported.
+    pub fn mode(&self, system_id: SystemId) -> Option<&GnsMode> {
+        let index = match system_id {
+            SystemId::Gps => 0,
+            SystemId::Glonass => 1,
+            SystemId::Galileo => 2,
+            SystemId::Beidou => 3,
+            SystemId::Qzss => 4,
+            SystemId::Navic => 5,
+        };
+        self.modes.get(index)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("ADEFMNPRS")))]
+/// GNS mode indicator of a single GNSS system
+pub enum GnsMode {
+    #[nmea(selector('A'))]
+    /// A - Autonomous mode
+    Autonomous,
+    #[nmea(selector('D'))]
+    /// D - Differential mode
+    Differential,
+    #[nmea(selector('E'))]
+    /// E - Estimated (dead-reckoning) mode
+    Estimated,
+    #[nmea(selector('F'))]
+    /// F - RTK Float mode
+    FloatRtk,
+    #[nmea(selector('M'))]
+    /// M - Manual input mode
+    Manual,
+    #[default]
+    #[nmea(selector('N'))]
+    /// N - No fix, the system is not used
+    NoFix,
+    #[nmea(selector('P'))]
+    /// P - Precise mode
+    Precise,
+    #[nmea(selector('R'))]
+    /// R - RTK Integer mode
+    FixedRtk,
+    #[nmea(selector('S'))]
+    /// S - Simulator mode
+    Simulator,
+}
+
+/// Parses the mode indicator field, one character per GNSS system without separators.
+fn system_modes<I, E>(i: I) -> IResult<I, heapless::Vec<GnsMode, GNS_MODES_CAPACITY>, E>
+where
+    GnsMode: NmeaParse<I, E>,
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    many_m_n(0, GNS_MODES_CAPACITY, GnsMode::parse)
+        .map(|modes| modes.into_iter().collect())
+        .parse(i)
+}
+
+fn write_system_modes(
+    modes: &heapless::Vec<GnsMode, GNS_MODES_CAPACITY>,
+    e: &mut Encoder<'_>,
+) -> fmt::Result {
+    modes.iter().try_for_each(|mode| mode.encode(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gns_parsing() {
+        let input = "014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,";
+        let result: IResult<_, _> = GNS::parse(input);
+        let (rest, gns) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(gns.modes, [GnsMode::FixedRtk, GnsMode::FixedRtk]);
+        assert_eq!(gns.satellite_count, Some(13));
+        assert_eq!(gns.altitude, Some(25.63));
+        assert_eq!(gns.age_of_differential, None);
+        assert_eq!(gns.nav_status, None);
+
+        let input = "094620,5157.19,N,00045.61,W,ANNNNN,10,0.8,99.9,45.3,1.5,0042,V";
+        let result: IResult<_, _> = GNS::parse(input);
+        let (_, gns) = result.unwrap();
+        assert_eq!(gns.modes.len(), 6);
+        assert_eq!(gns.age_of_differential, Some(Duration::from_millis(1500)));
+        assert_eq!(gns.mode(SystemId::Gps), Some(&GnsMode::Autonomous));
+        assert_eq!(gns.mode(SystemId::Navic), Some(&GnsMode::NoFix));
+        assert_eq!(gns.nav_status, Some(NavStatus::Valid));
+
+        for input in [
+            "094620,5157.19,N,00045.61,W,AX,10,0.8,99.9,45.3,,,V",
+            "094620,5157.19,N,00045.61,W,AAAAAAAAA,10,0.8,99.9,45.3,,,V",
+        ] {
+            let result: IResult<_, _> = GNS::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use nom::{character::complete::one_of, combinator::opt};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::gbs::signal_id;
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{SignalId, SystemId, parse::hex_digit},
+};
+
+/// GRS - GPS Range Residuals
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_grs_gps_range_residuals>
+///
+/// ```text
+///         1         2 3   4   5   6   7   8   9   10  11  12  13  14
+///         |         | |   |   |   |   |   |   |   |   |   |   |   |
+///  $--GRS,hhmmss.ss,m,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x*hh<CR><LF>
+/// ```
+///
+/// NMEA 4.11:
+/// ```text
+///         1         2 3   4   5   6   7   8   9   10  11  12  13  14  15 16
+///         |         | |   |   |   |   |   |   |   |   |   |   |   |   | |
+///  $--GRS,hhmmss.ss,m,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,h,h*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct GRS {
+    /// Fix time in UTC
+    pub fix_time: Option<time::Time>,
+    /// How the residuals were calculated
+    pub mode: GrsMode,
+    /// Range residuals in meters, in the order of the satellites of the matching GSA sentence
+    pub residuals: [Option<f32>; 12],
+    #[nmea(version(NmeaVersion::V4_11))]
+    /// System ID of the GNSS system of the satellites
+    pub system_id: Option<SystemId>,
+    #[nmea(version(NmeaVersion::V4_11))]
+    #[nmea(map(|id: Option<u8>| id.map(|id| signal_id(system_id, id))))]
+    #[nmea(parser(opt(hex_digit)), writer(NmeaEncode::encode))]
+    /// Signal ID of the satellites
+    pub signal_id: Option<SignalId>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("01")))]
+/// GRS residuals mode
+pub enum GrsMode {
+    #[default]
+    #[nmea(selector('0'))]
+    /// 0 - Residuals were used to calculate the position given in the matching GGA or GNS sentence
+    UsedInFix,
+    #[nmea(selector('1'))]
+    /// 1 - Residuals were recomputed after the position was calculated
+    Recomputed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        IResult,
+        nmea_content::{GpsSignalId, NmeaSentence},
+    };
+
+    #[test]
+    fn test_grs_parsing() {
+        let input = "024603.00,1,-1.8,-2.7,0.3,,,,,,,,,,1,1";
+        let result: IResult<_, _> = GRS::parse(input);
+        let (rest, grs) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(grs.mode, GrsMode::Recomputed);
+        assert_eq!(
+            grs.residuals[..4],
+            [Some(-1.8), Some(-2.7), Some(0.3), None]
+        );
+        assert_eq!(grs.system_id, Some(SystemId::Gps));
+        assert_eq!(grs.signal_id, Some(SignalId::Gps(GpsSignalId::L1CA)));
+
+        let result: IResult<_, _> = GRS::parse("024603.00,1,-1.8,-2.7,0.3,,,,,,,,,");
+        let (rest, grs) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(grs.system_id, None);
+        assert_eq!(grs.signal_id, None);
+
+        for input in [
+            "024603.00,2,-1.8,-2.7,0.3,,,,,,,,,",
+            "024603.00,1,-1.8,-2.7",
+        ] {
+            let result: IResult<_, _> = GRS::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+
+        // The signal ID is a single hexadecimal digit
+        let input = "GPGRS,024603.00,1,-1.8,-2.7,0.3,,,,,,,,,,1,101";
+        let result: IResult<_, _> = NmeaSentence::parse(input);
+        assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+    }
+}
@@ -1,11 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[cfg(feature = "nmea-v4-11")]
-use crate::nmea_content::SystemId;
 use crate::{
-    self as nmea0183_parser, NmeaParse,
-    nmea_content::{FixMode, SelectionMode},
+    self as nmea0183_parser, Encoder, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{FixMode, SelectionMode, SystemId},
 };
 
 /// GSA - GPS DOP and active satellites
@@ -26,13 +25,15 @@ use crate::{
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 pub struct GSA {
     /// Selection mode
     pub selection_mode: SelectionMode,
     /// Fix mode
     pub fix_mode: FixMode,
     #[nmea(map(|sats| sats.into_iter().flatten().collect()), parse_as([Option<u8>; 12]))]
+    #[nmea(writer(write_fix_sats_prn))]
     /// PRN numbers of the satellites used in the fix, up to 12
     pub fix_sats_prn: heapless::Vec<u8, 12>,
     /// Position Dilution of Precision
@@ -41,12 +42,16 @@ pub struct GSA {
     pub hdop: Option<f32>,
     /// Vertical Dilution of Precision
     pub vdop: Option<f32>,
-    #[cfg(feature = "nmea-v4-11")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+    #[nmea(version(NmeaVersion::V4_11))]
     /// System ID of the GNSS system used for the fix
     pub system_id: Option<SystemId>,
 }
 
+fn write_fix_sats_prn(fix_sats_prn: &heapless::Vec<u8, 12>, e: &mut Encoder<'_>) -> fmt::Result {
+    let fix_sats_prn: [Option<&u8>; 12] = std::array::from_fn(|i| fix_sats_prn.get(i));
+    fix_sats_prn.encode(e)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -62,14 +67,12 @@ mod tests {
             pdop: Some(1.0),
             hdop: None,
             vdop: Some(3.0),
-            #[cfg(feature = "nmea-v4-11")]
             system_id: None,
         };
         let result: IResult<_, _> = GSA::parse(input);
-        if cfg!(feature = "nmea-v4-11") {
-            assert_eq!(result, Ok(("", expected)));
-        } else {
-            assert_eq!(result, Ok((",", expected)));
-        }
+        assert_eq!(result, Ok(("", expected.clone())));
+
+        let result: IResult<_, _> = GSA::parse_version(input, NmeaVersion::V2_0.into());
+        assert_eq!(result, Ok((",", expected)));
     }
 }
@@ -0,0 +1,79 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse};
+
+/// GST - GPS Pseudorange Noise Statistics
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_gst_gps_pseudorange_noise_statistics>
+///
+/// ```text
+///         1         2   3   4   5   6   7   8
+///         |         |   |   |   |   |   |   |
+///  $--GST,hhmmss.ss,x.x,x.x,x.x,x.x,x.x,x.x,x.x*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct GST {
+    /// Fix time in UTC
+    pub fix_time: Option<time::Time>,
+    /// RMS value of the standard deviation of the range inputs to the navigation process
+    pub rms_deviation: Option<f32>,
+    /// Standard deviation of the semi-major axis of the error ellipse in meters
+    pub semi_major_deviation: Option<f32>,
+    /// Standard deviation of the semi-minor axis of the error ellipse in meters
+    pub semi_minor_deviation: Option<f32>,
+    /// Orientation of the semi-major axis of the error ellipse in degrees from true north
+    pub semi_major_orientation: Option<f32>,
+    /// Standard deviation of the latitude error in meters
+    pub latitude_deviation: Option<f32>,
+    /// Standard deviation of the longitude error in meters
+    pub longitude_deviation: Option<f32>,
+    /// Standard deviation of the altitude error in meters
+    pub altitude_deviation: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_gst_parsing() {
+        let result: IResult<_, _> =
+            GST::parse("172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031");
+        let (rest, gst) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(gst.fix_time, time::Time::from_hms(17, 28, 14).ok());
+        assert_eq!(gst.rms_deviation, Some(0.006));
+        assert_eq!(gst.semi_major_deviation, Some(0.023));
+        assert_eq!(gst.semi_minor_deviation, Some(0.020));
+        assert_eq!(gst.semi_major_orientation, Some(273.6));
+        assert_eq!(gst.latitude_deviation, Some(0.023));
+        assert_eq!(gst.longitude_deviation, Some(0.020));
+        assert_eq!(gst.altitude_deviation, Some(0.031));
+
+        let result: IResult<_, _> = GST::parse("172814.0,0.006,,,,1.5,1.2,");
+        let (rest, gst) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(gst.rms_deviation, Some(0.006));
+        assert_eq!(gst.semi_major_deviation, None);
+        assert_eq!(gst.semi_major_orientation, None);
+        assert_eq!(gst.latitude_deviation, Some(1.5));
+        assert_eq!(gst.altitude_deviation, None);
+
+        let result: IResult<_, _> = GST::parse(",,,,,,,");
+        assert_eq!(result, Ok(("", GST::default())));
+
+        for input in [
+            "172814.0,0.006,0.023,0.020,273.6,0.023,0.020",
+            "172814.0,0.006,0.023,0.020,north,0.023,0.020,0.031",
+            "251000.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031",
+        ] {
+            let result: IResult<_, _> = GST::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -1,11 +1,19 @@
-#[cfg(feature = "nmea-v4-11")]
-use nom::{Input, combinator::opt, number::complete::hex_u32};
+use nom::{Input, combinator::opt};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
 
-#[cfg(feature = "nmea-v4-11")]
-use crate::nmea_content::SignalId;
-use crate::{self as nmea0183_parser, NmeaParse, nmea_content::Satellite};
+use crate::{
+    self as nmea0183_parser, Encoder, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{Fragment, Satellite, SignalId, TalkerId, parse::hex_digit},
+};
+
+use super::gbs::signal_id;
+
+/// Maximum number of satellites reported by a group of [`GSV`] sentences.
+///
+/// A group is made of at most 9 sentences of 4 satellites each.
+pub const GSV_SATELLITES_CAPACITY: usize = 36;
 
 /// GSV - Satellites in View
 ///
@@ -19,6 +27,7 @@ use crate::{self as nmea0183_parser, NmeaParse, nmea_content::Satellite};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 pub struct GSV {
     /// Total number of GSV sentences to be transmitted in this group
     pub total_messages: u8,
@@ -28,14 +37,98 @@ pub struct GSV {
     pub satellites_in_view: u8,
     /// Satellite information
     pub satellites: heapless::Vec<Satellite, 4>,
-    #[cfg(feature = "nmea-v4-11")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+    #[nmea(version(NmeaVersion::V4_11))]
     #[nmea(map(Option::flatten))]
     #[nmea(cond(!satellites.is_empty() || nmea_input.input_len() > 0))]
-    #[nmea(map(|id| id.map(|hex| hex as u8)))]
-    #[nmea(parser(opt(hex_u32)))]
-    /// Signal ID of the GNSS system used for the fix
-    pub signal_id: Option<SignalId>,
+    #[nmea(parser(opt(hex_digit)))]
+    /// Raw signal ID of the GNSS system used for the fix
+    ///
+    /// The signal ID can only be decoded together with the talker ID of the sentence,
+    /// see [`GSV::signal_id`].
+    pub signal_id_raw: Option<u8>,
+}
+
+impl NmeaEncode for GSV {
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        self.total_messages.encode(e)?;
+        self.message_number.encode_preceded(',', e)?;
+        self.satellites_in_view.encode_preceded(',', e)?;
+        self.satellites.encode_preceded(',', e)?;
+        match self.signal_id_raw {
+            Some(id) => write!(e, ",{id:X}")?,
+            None if !self.satellites.is_empty() => e.write_char(',')?,
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+impl GSV {
+    /// Decodes the signal ID using the GNSS system of the given talker ID.
+    ///
+    /// The signal ID is reported as [`SignalId::Unknown`] if it is not defined for the
+    /// system, or if the talker does not belong to a single GNSS system, such as `GN`.
+    pub fn signal_id(&self, talker: TalkerId) -> Option<SignalId> {
+        self.signal_id_raw
+            .map(|id| signal_id(talker.system_id(), id))
+    }
+}
+
+/// Satellites in view reported by a complete group of [`GSV`] sentences
+///
+/// Assembled from the sentences of the group by a [`Reassembler`](crate::nmea_content::Reassembler).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SatellitesInView {
+    /// Total number of satellites in view
+    pub satellites_in_view: u8,
+    /// Satellite information of all the sentences of the group
+    pub satellites: heapless::Vec<Satellite, GSV_SATELLITES_CAPACITY>,
+    /// Raw signal ID of the GNSS system used for the fix, see [`SatellitesInView::signal_id`]
+    pub signal_id_raw: Option<u8>,
+}
+
+impl SatellitesInView {
+    /// Decodes the signal ID using the GNSS system of the given talker ID.
+    ///
+    /// See [`GSV::signal_id`].
+    pub fn signal_id(&self, talker: TalkerId) -> Option<SignalId> {
+        self.signal_id_raw
+            .map(|id| signal_id(talker.system_id(), id))
+    }
+}
+
+impl Fragment for GSV {
+    type Message = SatellitesInView;
+
+    /// Groups of different signals are sent at the same time since NMEA 4.11
+    type Key = Option<u8>;
+
+    fn key(&self) -> Self::Key {
+        self.signal_id_raw
+    }
+
+    fn fragment_count(&self) -> u8 {
+        self.total_messages
+    }
+
+    fn fragment_number(&self) -> u8 {
+        self.message_number
+    }
+
+    fn assemble(fragments: impl Iterator<Item = Self>) -> Self::Message {
+        let mut message = SatellitesInView::default();
+
+        for gsv in fragments {
+            message.satellites_in_view = gsv.satellites_in_view;
+            message.signal_id_raw = gsv.signal_id_raw;
+            // A valid group never exceeds the capacity
+            let _ = message.satellites.extend_from_slice(&gsv.satellites);
+        }
+
+        message
+    }
 }
 
 #[cfg(test)]
@@ -69,7 +162,7 @@ mod tests {
             println!("Parsed: {input:?} -> {result:?}");
         }
 
-        #[cfg(feature = "nmea-v4-11")]
+        // The signal ID field is only required from NMEA 4.11 devices
         {
             let cases = [
                 "1,1,01,05,45,120,38",
@@ -88,8 +181,34 @@ mod tests {
 
             for &input in &cases {
                 let result: IResult<_, _> = GSV::parse(input);
+                assert!(result.is_ok(), "Failed: {input:?}\n\t{result:?}");
+
+                let result: IResult<_, _> = GSV::parse_version(input, NmeaVersion::V4_11.into());
                 assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
             }
         }
     }
+
+    #[test]
+    fn test_gsv_signal_id() {
+        let result: IResult<_, _> = GSV::parse("1,1,01,05,45,120,38,B");
+        let (rest, gsv) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(gsv.signal_id_raw, Some(0xB));
+        assert_eq!(
+            gsv.signal_id(TalkerId::Beidou),
+            Some(SignalId::Beidou(crate::nmea_content::BeidouSignalId::B2I))
+        );
+        assert_eq!(gsv.signal_id(TalkerId::Gps), Some(SignalId::Unknown(0xB)));
+
+        let mut output = String::new();
+        gsv.encode(&mut Encoder::new(&mut output)).unwrap();
+        assert_eq!(output, "1,1,1,5,45,120,38,B");
+
+        // Only the first hexadecimal digit belongs to the signal ID
+        let result: IResult<_, _> = GSV::parse("1,1,01,05,45,120,38,101");
+        let (rest, gsv) = result.unwrap();
+        assert_eq!(rest, "01");
+        assert_eq!(gsv.signal_id_raw, Some(0x1));
+    }
 }
@@ -0,0 +1,145 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::rmc::{magnetic_variation, write_magnetic_variation};
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse};
+
+/// HDG - Heading - Deviation & Variation
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_hdg_heading_deviation_variation>
+///
+/// ```text
+///         1   2   3 4   5
+///         |   |   | |   |
+///  $--HDG,x.x,x.x,a,x.x,a*hh<CR><LF>
+/// ```
+///
+/// Adding the deviation to the magnetic sensor heading gives the magnetic heading,
+/// and adding the variation to the magnetic heading gives the true heading. A missing
+/// correction is unknown rather than zero, so the corrected headings are only available
+/// when all of the fields they depend on are present.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct HDG {
+    /// Magnetic sensor heading in degrees
+    pub heading: Option<f32>,
+    #[nmea(parser(magnetic_variation), writer(write_magnetic_variation))]
+    /// Magnetic deviation in degrees, negative values indicate a westerly deviation
+    pub deviation: Option<f32>,
+    #[nmea(parser(magnetic_variation), writer(write_magnetic_variation))]
+    /// Magnetic variation in degrees, negative values indicate a westerly variation
+    pub variation: Option<f32>,
+}
+
+impl HDG {
+    /// Returns the magnetic heading in degrees, from 0 to 360, the sensor heading corrected
+    /// by the deviation.
+    ///
+    /// Returns [`None`] if the heading or the deviation is missing.
+    pub fn magnetic_heading(&self) -> Option<f32> {
+        self.heading
+            .zip(self.deviation)
+            .map(|(heading, deviation)| (heading + deviation).rem_euclid(360.0))
+    }
+
+    /// Returns the true heading in degrees, from 0 to 360, the magnetic heading corrected by
+    /// the variation.
+    ///
+    /// Returns [`None`] if the heading, the deviation or the variation is missing.
+    pub fn true_heading(&self) -> Option<f32> {
+        self.magnetic_heading()
+            .zip(self.variation)
+            .map(|(heading, variation)| (heading + variation).rem_euclid(360.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_hdg_parsing() {
+        let cases = [
+            (
+                "238.5,,,3.0,W",
+                HDG {
+                    heading: Some(238.5),
+                    deviation: None,
+                    variation: Some(-3.0),
+                },
+            ),
+            (
+                "101.1,2.5,E,6.2,E",
+                HDG {
+                    heading: Some(101.1),
+                    deviation: Some(2.5),
+                    variation: Some(6.2),
+                },
+            ),
+            (
+                ",1.0,W,,",
+                HDG {
+                    heading: None,
+                    deviation: Some(-1.0),
+                    variation: None,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = HDG::parse(input);
+            assert_eq!(
+                result,
+                Ok(("", expected)),
+                "Failed: {input:?}\n\t{result:?}"
+            );
+        }
+
+        let hdg = HDG {
+            heading: Some(101.1),
+            deviation: Some(-1.1),
+            variation: Some(-6.0),
+        };
+        assert_eq!(hdg.magnetic_heading(), Some(100.0));
+        assert_eq!(hdg.true_heading(), Some(94.0));
+
+        let hdg = HDG {
+            heading: Some(355.0),
+            deviation: Some(2.0),
+            variation: Some(10.0),
+        };
+        assert_eq!(hdg.magnetic_heading(), Some(357.0));
+        assert_eq!(hdg.true_heading(), Some(7.0));
+
+        let hdg = HDG {
+            heading: Some(3.0),
+            deviation: Some(-1.0),
+            variation: Some(-5.0),
+        };
+        assert_eq!(hdg.true_heading(), Some(357.0));
+
+        let hdg = HDG {
+            heading: Some(238.5),
+            deviation: None,
+            variation: Some(-3.0),
+        };
+        assert_eq!(hdg.magnetic_heading(), None);
+        assert_eq!(hdg.true_heading(), None);
+
+        let hdg = HDG {
+            heading: Some(238.5),
+            deviation: Some(0.0),
+            variation: None,
+        };
+        assert_eq!(hdg.magnetic_heading(), Some(238.5));
+        assert_eq!(hdg.true_heading(), None);
+
+        for input in ["238.5,,,3.0,N", "238.5,,,3.0", "238.5,1.0,,,"] {
+            let result: IResult<_, _> = HDG::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
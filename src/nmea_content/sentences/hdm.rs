@@ -0,0 +1,71 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{encode::write_with_unit, parse::with_unit},
+};
+
+/// HDM - Heading - Magnetic
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_hdm_heading_magnetic>
+///
+/// ```text
+///         1   2
+///         |   |
+///  $--HDM,x.x,M*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct HDM {
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
+    /// Heading in degrees magnetic
+    pub heading: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_hdm_parsing() {
+        let cases = [
+            (
+                "238.5,M",
+                HDM {
+                    heading: Some(238.5),
+                },
+            ),
+            (",M", HDM { heading: None }),
+            (",", HDM { heading: None }),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = HDM::parse(input);
+            assert_eq!(
+                result,
+                Ok(("", expected)),
+                "Failed: {input:?}\n\t{result:?}"
+            );
+        }
+
+        let result: IResult<_, _> = HDM::parse("238.5,M".as_bytes());
+        assert_eq!(
+            result,
+            Ok((
+                &b""[..],
+                HDM {
+                    heading: Some(238.5)
+                }
+            ))
+        );
+
+        for input in ["238.5,T", "238.5", "abc,M"] {
+            let result: IResult<_, _> = HDM::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
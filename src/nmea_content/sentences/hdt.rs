@@ -0,0 +1,60 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{encode::write_with_unit, parse::with_unit},
+};
+
+/// HDT - Heading - True
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_hdt_heading_true>
+///
+/// ```text
+///         1   2
+///         |   |
+///  $--HDT,x.x,T*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct HDT {
+    #[nmea(parser(with_unit('T')), writer(write_with_unit('T')))]
+    /// Heading in degrees true
+    pub heading: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_hdt_parsing() {
+        let cases = [
+            (
+                "274.07,T",
+                HDT {
+                    heading: Some(274.07),
+                },
+            ),
+            (",T", HDT { heading: None }),
+            (",", HDT { heading: None }),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = HDT::parse(input);
+            assert_eq!(
+                result,
+                Ok(("", expected)),
+                "Failed: {input:?}\n\t{result:?}"
+            );
+        }
+
+        for input in ["274.07,M", "274.07", "abc,T"] {
+            let result: IResult<_, _> = HDT::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
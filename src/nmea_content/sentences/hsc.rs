@@ -0,0 +1,86 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{encode::write_with_unit, parse::with_unit},
+};
+
+/// HSC - Heading Steering Command
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_hsc_heading_steering_command>
+///
+/// ```text
+///         1   2 3   4
+///         |   | |   |
+///  $--HSC,x.x,T,x.x,M*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct HSC {
+    #[nmea(parser(with_unit('T')), writer(write_with_unit('T')))]
+    /// Commanded heading in degrees true
+    pub heading_true: Option<f32>,
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
+    /// Commanded heading in degrees magnetic
+    pub heading_magnetic: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_hsc_parsing() {
+        let cases = [
+            (
+                "40.1,T,38.6,M",
+                HSC {
+                    heading_true: Some(40.1),
+                    heading_magnetic: Some(38.6),
+                },
+            ),
+            (
+                "40.1,T,,M",
+                HSC {
+                    heading_true: Some(40.1),
+                    heading_magnetic: None,
+                },
+            ),
+            (
+                "40.1,T,38.6,",
+                HSC {
+                    heading_true: Some(40.1),
+                    heading_magnetic: None,
+                },
+            ),
+            (
+                "40.1,,38.6,",
+                HSC {
+                    heading_true: None,
+                    heading_magnetic: None,
+                },
+            ),
+            (
+                ",,,",
+                HSC {
+                    heading_true: None,
+                    heading_magnetic: None,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = HSC::parse(input);
+            assert_eq!(result, Ok(("", expected)), "Failed: {input:?}");
+        }
+
+        for input in ["40.1,M,38.6,M", "40.1,T,38.6,T", "40.1,T"] {
+            let result: IResult<_, _> = HSC::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
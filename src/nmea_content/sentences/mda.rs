@@ -0,0 +1,119 @@
+use nom::{
+    AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser, character::complete::char,
+    error::ParseError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+
+use super::mwd::{wind_speed, write_wind_speed};
+use crate::{
+    self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse,
+    nmea_content::{encode::write_with_unit, parse::with_unit},
+};
+
+/// MDA - Meteorological Composite
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mda_meteorological_composite>
+///
+/// ```text
+///         1   2 3   4 5   6 7   8 9   10  11  12 13 14 15 16 17 18 19 20
+///         |   | |   | |   | |   | |   |   |   |  |   | |   | |   | |   |
+///  $--MDA,x.x,I,x.x,B,x.x,C,x.x,C,x.x,x.x,x.x,C,x.x,T,x.x,M,x.x,N,x.x,M*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct MDA {
+    #[nmea(parser(barometric_pressure), writer(write_barometric_pressure))]
+    /// Barometric pressure in bars
+    pub barometric_pressure: Option<f32>,
+    #[nmea(parser(with_unit('C')), writer(write_with_unit('C')))]
+    /// Air temperature in degrees Celsius
+    pub air_temperature: Option<f32>,
+    #[nmea(parser(with_unit('C')), writer(write_with_unit('C')))]
+    /// Water temperature in degrees Celsius
+    pub water_temperature: Option<f32>,
+    /// Relative humidity in percent
+    pub relative_humidity: Option<f32>,
+    /// Absolute humidity in percent
+    pub absolute_humidity: Option<f32>,
+    #[nmea(parser(with_unit('C')), writer(write_with_unit('C')))]
+    /// Dew point in degrees Celsius
+    pub dew_point: Option<f32>,
+    #[nmea(parser(with_unit('T')), writer(write_with_unit('T')))]
+    /// Direction the wind blows from in degrees true
+    pub wind_direction_true: Option<f32>,
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
+    /// Direction the wind blows from in degrees magnetic
+    pub wind_direction_magnetic: Option<f32>,
+    #[nmea(parser(wind_speed), writer(write_wind_speed))]
+    /// Wind speed in knots
+    pub wind_speed: Option<f32>,
+}
+
+fn barometric_pressure<I, E>(i: I) -> IResult<I, Option<f32>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: for<'a> Compare<&'a [u8]> + Compare<&'static str>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    let (i, pressure_inches) = with_unit('I').parse(i)?;
+    let (i, _) = char(',').parse(i)?;
+    let (i, pressure_bars) = with_unit('B').parse(i)?;
+
+    Ok((
+        i,
+        pressure_bars.or(pressure_inches.map(|inches: f32| inches * 0.033_863_9)),
+    ))
+}
+
+fn write_barometric_pressure(pressure: &Option<f32>, e: &mut Encoder<'_>) -> fmt::Result {
+    write_with_unit('I')(&pressure.map(|bars| bars / 0.033_863_9), e)?;
+    e.write_char(',')?;
+    write_with_unit('B')(pressure, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mda_parsing() {
+        let result: IResult<_, _> =
+            MDA::parse("30.12,I,1.0200,B,18.5,C,14.2,C,65.0,,11.8,C,245.0,T,241.0,M,8.5,N,4.4,M");
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                MDA {
+                    barometric_pressure: Some(1.02),
+                    air_temperature: Some(18.5),
+                    water_temperature: Some(14.2),
+                    relative_humidity: Some(65.0),
+                    absolute_humidity: None,
+                    dew_point: Some(11.8),
+                    wind_direction_true: Some(245.0),
+                    wind_direction_magnetic: Some(241.0),
+                    wind_speed: Some(8.5),
+                }
+            ))
+        );
+
+        let result: IResult<_, _> = MDA::parse("29.53,I,,,,,,,,,,,,,,,,,,");
+        let (_, mda) = result.unwrap();
+        assert_eq!(mda.barometric_pressure, Some(29.53 * 0.033_863_9));
+
+        for input in [
+            "30.12,X,1.0200,B,18.5,C,14.2,C,65.0,,11.8,C,245.0,T,241.0,M,8.5,N,4.4,M",
+            "30.12,I,1.0200,B,18.5,F,14.2,C,65.0,,11.8,C,245.0,T,241.0,M,8.5,N,4.4,M",
+            "30.12,I,1.0200,B",
+        ] {
+            let result: IResult<_, _> = MDA::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
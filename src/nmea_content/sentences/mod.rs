@@ -1,28 +1,164 @@
+mod acn;
+mod alc;
+mod alf;
+mod alr;
+mod any;
+mod apb;
+mod arc;
+mod bod;
+mod bwc;
+mod bwr;
 mod dbt;
 mod dpt;
+mod dsc;
+mod dse;
+mod dtm;
+mod gbs;
 mod gga;
 mod gll;
+mod gns;
+mod grs;
 mod gsa;
+mod gst;
 mod gsv;
+mod hdg;
+mod hdm;
+mod hdt;
+mod hsc;
+mod mda;
+mod mtw;
+mod mwd;
+mod mwv;
+mod osd;
+#[cfg(feature = "garmin")]
+mod pgrm;
+#[cfg(feature = "mediatek")]
+mod pmtk;
+mod proprietary;
+#[cfg(feature = "sirf")]
+mod psrf;
+#[cfg(feature = "ublox")]
+mod pubx;
+mod query;
+mod rmb;
 mod rmc;
+mod rot;
+mod rpm;
+mod rsa;
+mod rsd;
+mod rte;
+mod ths;
+mod tll;
+mod ttm;
+mod txt;
+mod vbw;
+mod vdm;
+mod vdr;
+mod vhw;
+mod vlw;
 mod vtg;
+mod vwr;
+mod vwt;
+mod wpl;
+mod xdr;
+mod xte;
 mod zda;
 
+pub use acn::{ACN, AlertCommand, CommandStatus};
+pub use alc::{ALC, ALC_ENTRIES_CAPACITY, AlertEntry};
+pub use alf::{
+    ALERT_TEXT_CAPACITY, ALF, Alert, AlertCategory, AlertId, AlertPriority, AlertState, AlertText,
+};
+pub use alr::{ALR, AlarmAcknowledgement, AlarmCondition};
+pub use any::AnySentence;
+pub use apb::{APB, Bearing, BearingReference};
+pub use arc::ARC;
+pub use bod::BOD;
+pub use bwc::BWC;
+pub use bwr::BWR;
 pub use dbt::DBT;
 pub use dpt::DPT;
+pub use dsc::{
+    DSC, DSC_FIELD_CAPACITY, DistressNature, DscAcknowledgement, DscCategory, DscFormat,
+};
+pub use dse::{DSE, DSE_DATA_SETS_CAPACITY, DseCode, DseDataSet, DseFlag};
+pub use dtm::{DATUM_CODE_CAPACITY, DTM};
+pub use gbs::GBS;
 pub use gga::GGA;
 pub use gll::GLL;
+pub use gns::{GNS, GNS_MODES_CAPACITY, GnsMode};
+pub use grs::{GRS, GrsMode};
 pub use gsa::GSA;
-pub use gsv::GSV;
+pub use gst::GST;
+pub use gsv::{GSV, GSV_SATELLITES_CAPACITY, SatellitesInView};
+pub use hdg::HDG;
+pub use hdm::HDM;
+pub use hdt::HDT;
+pub use hsc::HSC;
+pub use mda::MDA;
+pub use mtw::MTW;
+pub use mwd::MWD;
+pub use mwv::{MWV, WindReference};
+pub use osd::{MotionReference, OSD};
+#[cfg(feature = "garmin")]
+#[cfg_attr(docsrs, doc(cfg(feature = "garmin")))]
+pub use pgrm::{MAP_DATUM_CAPACITY, OutputMode, PGRME, PGRMM, PGRMO, PGRMZ};
+#[cfg(feature = "mediatek")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mediatek")))]
+pub use pmtk::{AckFlag, PMTK001, PMTK220, PMTK251};
+pub use proprietary::ProprietarySentence;
+#[cfg(feature = "sirf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sirf")))]
+pub use psrf::{PSRF100, PSRF103, Parity, RateMode, SerialProtocol, SirfMessage};
+#[cfg(feature = "ublox")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ublox")))]
+pub use pubx::{
+    LeapSeconds, PUBX, PUBX_SATELLITES_CAPACITY, PubxConfig, PubxNavStatus, PubxPosition, PubxRate,
+    PubxSatellite, PubxSatelliteStatus, PubxSatellites, PubxTime,
+};
+pub use query::Query;
+pub use rmb::{ArrivalStatus, RMB};
 pub use rmc::RMC;
+pub use rot::ROT;
+pub use rpm::{RPM, RpmSource};
+pub use rsa::RSA;
+pub use rsd::{DisplayRotation, RSD, RadarOrigin};
+pub use rte::{ROUTE_WAYPOINTS_CAPACITY, RTE, RTE_WAYPOINTS_CAPACITY, Route, RouteMode};
+pub use ths::{THS, ThsMode};
+pub use tll::TLL;
+pub use ttm::{
+    DistanceUnit, ReferenceTarget, TARGET_NAME_CAPACITY, TTM, TargetAcquisition, TargetName,
+    TargetReference, TargetStatus,
+};
+pub use txt::{TEXT_MESSAGE_CAPACITY, TXT, TXT_TEXT_CAPACITY, TextMessage};
+pub use vbw::VBW;
+pub use vdm::{AisChannel, VDM, VDM_PAYLOAD_CAPACITY, VDO};
+pub use vdr::VDR;
+pub use vhw::VHW;
+pub use vlw::VLW;
 pub use vtg::VTG;
+pub use vwr::VWR;
+pub use vwt::VWT;
+pub use wpl::{WAYPOINT_ID_CAPACITY, WPL, WaypointId};
+pub use xdr::{Measurement, TransducerType, XDR, XDR_MEASUREMENTS_CAPACITY};
+pub use xte::XTE;
 pub use zda::ZDA;
 
-use nom::{bytes::complete::take, character::complete::one_of};
+use nom::{
+    AsChar, Input, Parser,
+    bytes::complete::take,
+    character::complete::one_of,
+    combinator::peek,
+    error::{ErrorKind, ParseError},
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
 
-use crate::{self as nmea0183_parser, Error, NmeaParse};
+use crate::{
+    self as nmea0183_parser, Encoder, Error, IResult, NmeaEncode, NmeaParse, VersionRange,
+    nmea_content::parse::sentence_type,
+};
 
 /// A unified enum representing all supported NMEA 0183 sentence types.
 ///
@@ -111,26 +247,71 @@ use crate::{self as nmea0183_parser, Error, NmeaParse};
 ///
 /// | Variant | Sentence Type                                           | Description                      |
 /// |---------|---------------------------------------------------------|----------------------------------|
+/// | ACN     | Alert Command                                           | Alert acknowledgement            |
+/// | ALC     | Cyclic Alert List                                       | Active alerts                    |
+/// | ALF     | Alert Sentence                                          | Bridge alert state and text      |
+/// | ALR     | Set Alarm State                                         | Legacy alarm state               |
+/// | APB     | Autopilot Sentence "B"                                  | Autopilot steering information   |
+/// | ARC     | Alert Command Refused                                   | Refused alert commands           |
+/// | BOD     | Bearing - Waypoint to Waypoint                          | Bearing between two waypoints    |
+/// | BWC     | Bearing & Distance to Waypoint - Great Circle           | Great circle course to waypoint  |
+/// | BWR     | Bearing and Distance to Waypoint - Rhumb Line           | Rhumb line course to waypoint    |
 /// | DBT     | Depth Below Transducer                                  | Water depth measurements         |
 /// | DPT     | Depth of Water                                          | Water depth with offset          |
+/// | DSC     | Digital Selective Calling Information                   | DSC calls and distress alerts    |
+/// | DSE     | Expanded Digital Selective Calling                      | DSC expansion data sets          |
+/// | DTM     | Datum Reference                                         | Local datum offsets              |
+/// | GBS     | GPS Satellite Fault Detection                           | RAIM fault detection             |
 /// | GGA     | Global Positioning System Fix Data                      | GPS position and fix quality     |
 /// | GLL     | Geographic Position - Latitude/Longitude                | Latitude/longitude with time     |
+/// | GNS     | Fix data                                                | Multi-constellation fix          |
+/// | GRS     | GPS Range Residuals                                     | Per-satellite range residuals    |
 /// | GSA     | GPS DOP and active satellites                           | Satellite constellation info     |
+/// | GST     | GPS Pseudorange Noise Statistics                        | Position error statistics        |
 /// | GSV     | Satellites in View                                      | Individual satellite details     |
+/// | HDG     | Heading - Deviation & Variation                         | Magnetic sensor heading          |
+/// | HDM     | Heading - Magnetic                                      | Magnetic heading                 |
+/// | HDT     | Heading - True                                          | True heading                     |
+/// | HSC     | Heading Steering Command                                | Commanded heading                |
+/// | MDA     | Meteorological Composite                                | Pressure, temperatures and wind  |
+/// | MTW     | Mean Temperature of Water                               | Water temperature                |
+/// | MWD     | Wind Direction & Speed                                  | True and magnetic wind direction |
+/// | MWV     | Wind Speed and Angle                                    | Relative or true wind            |
+/// | OSD     | Own Ship Data                                           | Own ship heading, course, speed  |
+/// | RMB     | Recommended Minimum Navigation Information              | Steering to destination waypoint |
 /// | RMC     | Recommended Minimum Navigation Information              | Essential navigation data        |
+/// | ROT     | Rate Of Turn                                            | Rate of turn in degrees/minute   |
+/// | RPM     | Revolutions                                             | Engine or shaft speed and pitch  |
+/// | RSA     | Rudder Sensor Angle                                     | Rudder angles                    |
+/// | RSD     | Radar System Data                                       | Radar display settings           |
+/// | RTE     | Routes                                                  | Waypoints of a route             |
+/// | THS     | True Heading and Status                                 | True heading with mode (3.0+)    |
+/// | TLL     | Target Latitude and Longitude                           | Radar target position            |
+/// | TTM     | Tracked Target Message                                  | Radar target tracking, CPA/TCPA  |
+/// | TXT     | Text Transmission                                       | Receiver status text             |
+/// | VBW     | Dual Ground/Water Speed                                 | Ground and water speeds          |
+/// | VDM     | AIS VHF Data-link Message                               | AIS messages from other vessels  |
+/// | VDO     | AIS VHF Data-link Own-vessel report                     | AIS messages from own vessel     |
+/// | VDR     | Set and Drift                                           | Direction and speed of current   |
+/// | VHW     | Water Speed and Heading                                 | Speed through water              |
+/// | VLW     | Distance Traveled through Water                         | Speed log distances              |
 /// | VTG     | Track made good and Ground speed                        | Velocity information             |
+/// | VWR     | Relative Wind Speed and Angle                           | Apparent wind (legacy)           |
+/// | VWT     | True Wind Speed and Angle                               | True wind (legacy)               |
+/// | WPL     | Waypoint Location                                       | Waypoint identifier and location |
+/// | XDR     | Transducer Measurement                                  | Generic transducer readings      |
+/// | XTE     | Cross-Track Error, Measured                             | Distance off the intended track  |
 /// | ZDA     | Time & Date - UTC, day, month, year and local time zone | UTC time and date with time zone |
 ///
 /// ## NMEA Version Support
 ///
-/// Different NMEA versions may include additional fields in certain sentence types. You can choose the version that matches your equipment by enabling the appropriate feature flags.
+/// Different NMEA versions may include additional fields in certain sentence types. The fields
+/// of all versions are always compiled in, and [`NmeaParse::parse`] accepts the sentences of
+/// every version, the fields added after NMEA 2.0 being optional. The fields expected from a
+/// device are selected at runtime with [`NmeaParse::parse_version`], see [`VersionRange`].
 ///
-/// | Feature Flag   | NMEA Version | When to Use                |
-/// | -------------- | ------------ | -------------------------- |
-/// | `nmea-content` | Pre-2.3      | Standard NMEA parsing      |
-/// | `nmea-v2-3`    | NMEA 2.3     | Older GPS/marine equipment |
-/// | `nmea-v3-0`    | NMEA 3.0     | Mid-range equipment        |
-/// | `nmea-v4-11`   | NMEA 4.11    | Modern equipment           |
+/// The `nmea-v2-3`, `nmea-v3-0` and `nmea-v4-11` features are kept for compatibility and only
+/// enable `nmea-content`.
 ///
 /// ## Error Handling
 ///
@@ -151,45 +332,333 @@ use crate::{self as nmea0183_parser, Error, NmeaParse};
 /// assert!(result.is_err());
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 #[nmea(pre_exec(let msg = nmea_input;))]
-// TODO: Handle talker ID
+// The talker ID is skipped here, use `Sentence` to keep it
 #[nmea(skip_before(2))]
-#[nmea(selector(take(3u8)))]
+#[nmea(selector(sentence_type))]
 #[nmea(selection_error(Error::UnrecognizedMessage(msg)))]
 #[nmea(exact)]
 pub enum NmeaSentence {
+    #[nmea(selector("ACN"))]
+    /// Alert Command
+    ACN(ACN),
+    #[nmea(selector("ALC"))]
+    /// Cyclic Alert List
+    ALC(ALC),
+    #[nmea(selector("ALF"))]
+    /// Alert Sentence
+    ALF(ALF),
+    #[nmea(selector("ALR"))]
+    /// Set Alarm State
+    ALR(ALR),
+    #[nmea(selector("APB"))]
+    /// Autopilot Sentence "B"
+    APB(APB),
+    #[nmea(selector("ARC"))]
+    /// Alert Command Refused
+    ARC(ARC),
+    #[nmea(selector("BOD"))]
+    /// Bearing - Waypoint to Waypoint
+    BOD(BOD),
+    #[nmea(selector("BWC"))]
+    /// Bearing & Distance to Waypoint - Great Circle
+    BWC(BWC),
+    #[nmea(selector("BWR"))]
+    /// Bearing and Distance to Waypoint - Rhumb Line
+    BWR(BWR),
     #[nmea(selector("DBT"))]
     /// Depth Below Transducer
     DBT(DBT),
     #[nmea(selector("DPT"))]
     /// Depth of Water
     DPT(DPT),
+    #[nmea(selector("DSC"))]
+    /// Digital Selective Calling Information
+    DSC(DSC),
+    #[nmea(selector("DSE"))]
+    /// Expanded Digital Selective Calling
+    DSE(DSE),
+    #[nmea(selector("DTM"))]
+    /// Datum Reference
+    DTM(DTM),
+    #[nmea(selector("GBS"))]
+    /// GPS Satellite Fault Detection
+    GBS(GBS),
     #[nmea(selector("GGA"))]
     /// Global Positioning System Fix Data
     GGA(GGA),
     #[nmea(selector("GLL"))]
     /// Geographic Position - Latitude/Longitude
     GLL(GLL),
+    #[nmea(selector("GNS"))]
+    /// Fix data
+    GNS(GNS),
+    #[nmea(selector("GRS"))]
+    /// GPS Range Residuals
+    GRS(GRS),
     #[nmea(selector("GSA"))]
     /// GPS DOP and active satellites
     GSA(GSA),
+    #[nmea(selector("GST"))]
+    /// GPS Pseudorange Noise Statistics
+    GST(GST),
     #[nmea(selector("GSV"))]
     /// Satellites in View
     GSV(GSV),
+    #[nmea(selector("HDG"))]
+    /// Heading - Deviation & Variation
+    HDG(HDG),
+    #[nmea(selector("HDM"))]
+    /// Heading - Magnetic
+    HDM(HDM),
+    #[nmea(selector("HDT"))]
+    /// Heading - True
+    HDT(HDT),
+    #[nmea(selector("HSC"))]
+    /// Heading Steering Command
+    HSC(HSC),
+    #[nmea(selector("MDA"))]
+    /// Meteorological Composite
+    MDA(MDA),
+    #[nmea(selector("MTW"))]
+    /// Mean Temperature of Water
+    MTW(MTW),
+    #[nmea(selector("MWD"))]
+    /// Wind Direction & Speed
+    MWD(MWD),
+    #[nmea(selector("MWV"))]
+    /// Wind Speed and Angle
+    MWV(MWV),
+    #[nmea(selector("OSD"))]
+    /// Own Ship Data
+    OSD(OSD),
+    #[nmea(selector("RMB"))]
+    /// Recommended Minimum Navigation Information
+    RMB(RMB),
     #[nmea(selector("RMC"))]
     /// Recommended Minimum Navigation Information
     RMC(RMC),
+    #[nmea(selector("ROT"))]
+    /// Rate Of Turn
+    ROT(ROT),
+    #[nmea(selector("RPM"))]
+    /// Revolutions
+    RPM(RPM),
+    #[nmea(selector("RSA"))]
+    /// Rudder Sensor Angle
+    RSA(RSA),
+    #[nmea(selector("RSD"))]
+    /// Radar System Data
+    RSD(RSD),
+    #[nmea(selector("RTE"))]
+    /// Routes
+    RTE(RTE),
+    #[nmea(selector("THS"))]
+    /// True Heading and Status
+    THS(THS),
+    #[nmea(selector("TLL"))]
+    /// Target Latitude and Longitude
+    TLL(TLL),
+    #[nmea(selector("TTM"))]
+    /// Tracked Target Message
+    TTM(TTM),
+    #[nmea(selector("TXT"))]
+    /// Text Transmission
+    TXT(TXT),
+    #[nmea(selector("VBW"))]
+    /// Dual Ground/Water Speed
+    VBW(VBW),
+    #[nmea(selector("VDM"))]
+    /// AIS VHF Data-link Message
+    VDM(VDM),
+    #[nmea(selector("VDO"))]
+    /// AIS VHF Data-link Own-vessel report
+    VDO(VDO),
+    #[nmea(selector("VDR"))]
+    /// Set and Drift
+    VDR(VDR),
+    #[nmea(selector("VHW"))]
+    /// Water Speed and Heading
+    VHW(VHW),
+    #[nmea(selector("VLW"))]
+    /// Distance Traveled through Water
+    VLW(VLW),
     #[nmea(selector("VTG"))]
     /// Track made good and Ground speed
     VTG(VTG),
+    #[nmea(selector("VWR"))]
+    /// Relative Wind Speed and Angle
+    VWR(VWR),
+    #[nmea(selector("VWT"))]
+    /// True Wind Speed and Angle
+    VWT(VWT),
+    #[nmea(selector("WPL"))]
+    /// Waypoint Location
+    WPL(WPL),
+    #[nmea(selector("XDR"))]
+    /// Transducer Measurement
+    XDR(XDR),
+    #[nmea(selector("XTE"))]
+    /// Cross-Track Error, Measured
+    XTE(XTE),
     #[nmea(selector("ZDA"))]
     /// Time & Date - UTC, day, month, year and local time zone
     ZDA(ZDA),
 }
 
+/// An NMEA 0183 sentence together with the talker ID that sent it.
+///
+/// [`NmeaSentence`] only looks at the sentence type, so a `GPGGA` and a `GNGGA`
+/// sentence parse to the same value. `Sentence` keeps the [`TalkerId`] from the
+/// address field, which is needed when merging data from multi-constellation
+/// receivers or routing sentences by their source.
+///
+/// ## Example Usage
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     IResult, Nmea0183ParserBuilder, NmeaParse,
+///     nmea_content::{NmeaSentence, Sentence, TalkerId},
+/// };
+/// use nom::Parser;
+///
+/// let mut parser = Nmea0183ParserBuilder::new().build(Sentence::parse);
+///
+/// let result: IResult<_, _> =
+///     parser.parse("$GNGGA,123456.00,4916.29,N,12311.76,W,1,08,0.9,545.4,M,46.9,M,,*6D\r\n");
+/// let (_, sentence) = result.unwrap();
+///
+/// assert_eq!(sentence.talker, TalkerId::Gnss);
+/// assert!(matches!(sentence.data, NmeaSentence::GGA(_)));
+/// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sentence {
+    /// Talker ID of the sentence
+    pub talker: TalkerId,
+    /// Sentence content
+    pub data: NmeaSentence,
+}
+
+impl<I, E> NmeaParse<I, E> for Sentence
+where
+    NmeaSentence: NmeaParse<I, E>,
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        Self::parse_version(i, VersionRange::default())
+    }
+
+    fn parse_version(i: I, versions: VersionRange) -> IResult<I, Self, E> {
+        let (i, talker) = peek(TalkerId::parse).parse(i)?;
+        let (i, data) = NmeaSentence::parse_version(i, versions)?;
+
+        Ok((i, Sentence { talker, data }))
+    }
+}
+
+/// Talker ID, the first two characters of the sentence address field
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_talker_ids>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TalkerId {
+    /// GP - GPS
+    Gps,
+    /// GL - GLONASS
+    Glonass,
+    /// GA - Galileo
+    Galileo,
+    /// GB/BD - BeiDou
+    Beidou,
+    /// GQ - QZSS
+    Qzss,
+    /// GI - NavIC
+    Navic,
+    /// GN - Combined GNSS, multiple constellations
+    Gnss,
+    /// Any other talker ID
+    Other([u8; 2]),
+}
+
+impl TalkerId {
+    /// Returns the two-character code of the talker ID.
+    ///
+    /// BeiDou is always reported as `GB`, the code used since NMEA 4.11.
+    pub fn code(&self) -> [u8; 2] {
+        match self {
+            TalkerId::Gps => *b"GP",
+            TalkerId::Glonass => *b"GL",
+            TalkerId::Galileo => *b"GA",
+            TalkerId::Beidou => *b"GB",
+            TalkerId::Qzss => *b"GQ",
+            TalkerId::Navic => *b"GI",
+            TalkerId::Gnss => *b"GN",
+            TalkerId::Other(code) => *code,
+        }
+    }
+
+    /// Returns the GNSS system of the talker ID, if it belongs to a single system.
+    pub fn system_id(&self) -> Option<SystemId> {
+        match self {
+            TalkerId::Gps => Some(SystemId::Gps),
+            TalkerId::Glonass => Some(SystemId::Glonass),
+            TalkerId::Galileo => Some(SystemId::Galileo),
+            TalkerId::Beidou => Some(SystemId::Beidou),
+            TalkerId::Qzss => Some(SystemId::Qzss),
+            TalkerId::Navic => Some(SystemId::Navic),
+            TalkerId::Gnss | TalkerId::Other(_) => None,
+        }
+    }
+}
+
+impl From<[u8; 2]> for TalkerId {
+    fn from(code: [u8; 2]) -> Self {
+        match &code {
+            b"GP" => TalkerId::Gps,
+            b"GL" => TalkerId::Glonass,
+            b"GA" => TalkerId::Galileo,
+            b"GB" | b"BD" => TalkerId::Beidou,
+            b"GQ" => TalkerId::Qzss,
+            b"GI" => TalkerId::Navic,
+            b"GN" => TalkerId::Gnss,
+            _ => TalkerId::Other(code),
+        }
+    }
+}
+
+impl<I, E> NmeaParse<I, E> for TalkerId
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        let (i, code) = take(2u8).parse(i)?;
+
+        let mut talker = [0u8; 2];
+        for (byte, item) in talker.iter_mut().zip(code.iter_elements()) {
+            let c = item.as_char();
+            if !c.is_ascii_alphanumeric() {
+                return Err(nom::Err::Error(nom::error::make_error(
+                    code,
+                    ErrorKind::AlphaNumeric,
+                )));
+            }
+            *byte = c as u8;
+        }
+
+        Ok((i, talker.into()))
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 #[nmea(selector(one_of("AV")))]
 /// Status Mode Indicator
 pub enum Status {
@@ -202,12 +671,10 @@ pub enum Status {
     Invalid,
 }
 
-#[cfg(feature = "nmea-v2-3")]
-#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
-#[cfg_attr(not(feature = "nmea-v4-11"), nmea(selector(one_of("ACDEFMNRSU"))))]
-#[cfg_attr(feature = "nmea-v4-11", nmea(selector(one_of("ACDEFMNPRSU"))))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("ACDEFMNPRSU")))]
 /// FAA Mode Indicator
 ///
 /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_sentence_mixes_and_nmea_variations>
@@ -234,8 +701,6 @@ pub enum FaaMode {
     #[nmea(selector('N'))]
     /// N - Data Not Valid
     DataNotValid,
-    #[cfg(feature = "nmea-v4-11")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
     #[nmea(selector('P'))]
     /// P - Precise
     Precise,
@@ -250,10 +715,9 @@ pub enum FaaMode {
     Unsafe,
 }
 
-#[cfg(feature = "nmea-v4-11")]
-#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 #[nmea(selector(one_of("ADEMNSV")))]
 /// Navigation Status
 pub enum NavStatus {
@@ -282,9 +746,9 @@ pub enum NavStatus {
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
-#[cfg_attr(not(feature = "nmea-v2-3"), nmea(selector(one_of("012"))))]
-#[cfg_attr(feature = "nmea-v2-3", nmea(selector(one_of("012345678"))))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("012345678")))]
 /// Quality of the GPS fix
 pub enum Quality {
     #[default]
@@ -297,40 +761,29 @@ pub enum Quality {
     #[nmea(selector('2'))]
     /// 2 - Differential GPS fix
     DGPSFix,
-    #[cfg(feature = "nmea-v2-3")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
     #[nmea(selector('3'))]
     /// 3 - PPS fix
     PPSFix,
-    #[cfg(feature = "nmea-v2-3")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
     #[nmea(selector('4'))]
     /// 4 - Real Time Kinematic
     RTK,
-    #[cfg(feature = "nmea-v2-3")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
     #[nmea(selector('5'))]
     /// 5 - Float RTK
     FloatRTK,
-    #[cfg(feature = "nmea-v2-3")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
     #[nmea(selector('6'))]
     /// 6 - estimated (dead reckoning)
     Estimated,
-    #[cfg(feature = "nmea-v2-3")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
     #[nmea(selector('7'))]
     /// 7 - Manual input mode
     Manual,
-    #[cfg(feature = "nmea-v2-3")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
     #[nmea(selector('8'))]
     /// 8 - Simulation mode
     Simulation,
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 #[nmea(selector(one_of("AM")))]
 /// Selection Mode
 pub enum SelectionMode {
@@ -344,7 +797,8 @@ pub enum SelectionMode {
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 #[nmea(selector(one_of("123")))]
 /// Fix Mode
 pub enum FixMode {
@@ -360,10 +814,9 @@ pub enum FixMode {
     Fix3D,
 }
 
-#[cfg(feature = "nmea-v4-11")]
-#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 #[nmea(selector(one_of("123456")))]
 /// NMEA 4.11 System ID
 ///
@@ -390,28 +843,230 @@ pub enum SystemId {
     Navic,
 }
 
+macro_rules! signal_ids {
+    ($(#[$meta:meta])* $name:ident { $($(#[$variant_meta:meta])* $variant:ident = $id:literal,)* }) => {
+        $(#[$meta])*
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u8)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant = $id,)*
+        }
+
+        impl TryFrom<u8> for $name {
+            type Error = u8;
+
+            fn try_from(id: u8) -> Result<Self, Self::Error> {
+                match id {
+                    $($id => Ok($name::$variant),)*
+                    _ => Err(id),
+                }
+            }
+        }
+    };
+}
+
 /// NMEA 4.11 Signal ID
 ///
+/// The meaning of the signal ID field depends on the GNSS system, which is given by
+/// the [`SystemId`] of a sentence, or by the [`TalkerId`] of [`GSV`] sentences.
+/// Use [`SignalId::new`] to decode a raw signal ID for a given system.
+///
 /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_nmea_4_11_system_id_and_signal_id>
-#[cfg(feature = "nmea-v4-11")]
-#[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
-pub type SignalId = u8;
-/*
- * // TODO:
- * pub enum SignalId {
- *     Gps(GpsSignalId),
- *     Glonass(GlonassSignalId),
- *     Galileo(GalileoSignalId),
- *     Beidou(BeidouSignalId),
- *     Qzss(QzssSignalId),
- *     Navic(NavicSignalId),
- *     Unknown(u8),
- * }
- */
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignalId {
+    /// GPS signal
+    Gps(GpsSignalId),
+    /// GLONASS signal
+    Glonass(GlonassSignalId),
+    /// Galileo signal
+    Galileo(GalileoSignalId),
+    /// BeiDou signal
+    Beidou(BeidouSignalId),
+    /// QZSS signal
+    Qzss(QzssSignalId),
+    /// NavIC signal
+    Navic(NavicSignalId),
+    /// Signal ID that is not defined for its system, or whose system is unknown
+    Unknown(u8),
+}
+
+impl SignalId {
+    /// Decodes a raw signal ID for the given GNSS system.
+    ///
+    /// Returns [`SignalId::Unknown`] if the signal ID is not defined for the system.
+    pub fn new(system_id: SystemId, id: u8) -> Self {
+        let signal_id = match system_id {
+            SystemId::Gps => id.try_into().map(SignalId::Gps),
+            SystemId::Glonass => id.try_into().map(SignalId::Glonass),
+            SystemId::Galileo => id.try_into().map(SignalId::Galileo),
+            SystemId::Beidou => id.try_into().map(SignalId::Beidou),
+            SystemId::Qzss => id.try_into().map(SignalId::Qzss),
+            SystemId::Navic => id.try_into().map(SignalId::Navic),
+        };
+
+        signal_id.unwrap_or(SignalId::Unknown(id))
+    }
+
+    /// Returns the raw signal ID.
+    pub fn id(&self) -> u8 {
+        match *self {
+            SignalId::Gps(id) => id as u8,
+            SignalId::Glonass(id) => id as u8,
+            SignalId::Galileo(id) => id as u8,
+            SignalId::Beidou(id) => id as u8,
+            SignalId::Qzss(id) => id as u8,
+            SignalId::Navic(id) => id as u8,
+            SignalId::Unknown(id) => id,
+        }
+    }
+}
+
+signal_ids! {
+    /// GPS Signal ID
+    GpsSignalId {
+        /// 0 - All signals
+        All = 0x0,
+        /// 1 - L1 C/A
+        L1CA = 0x1,
+        /// 2 - L1 P(Y)
+        L1PY = 0x2,
+        /// 3 - L1 M
+        L1M = 0x3,
+        /// 4 - L2 P(Y)
+        L2PY = 0x4,
+        /// 5 - L2C-M
+        L2CM = 0x5,
+        /// 6 - L2C-L
+        L2CL = 0x6,
+        /// 7 - L5-I
+        L5I = 0x7,
+        /// 8 - L5-Q
+        L5Q = 0x8,
+    }
+}
+
+signal_ids! {
+    /// GLONASS Signal ID
+    GlonassSignalId {
+        /// 0 - All signals
+        All = 0x0,
+        /// 1 - G1 C/A
+        G1CA = 0x1,
+        /// 2 - G1 P
+        G1P = 0x2,
+        /// 3 - G2 C/A
+        G2CA = 0x3,
+        /// 4 - G2 P
+        G2P = 0x4,
+    }
+}
+
+signal_ids! {
+    /// Galileo Signal ID
+    GalileoSignalId {
+        /// 0 - All signals
+        All = 0x0,
+        /// 1 - E5a
+        E5A = 0x1,
+        /// 2 - E5b
+        E5B = 0x2,
+        /// 3 - E5 a+b
+        E5AB = 0x3,
+        /// 4 - E6-A
+        E6A = 0x4,
+        /// 5 - E6-BC
+        E6BC = 0x5,
+        /// 6 - L1-A
+        L1A = 0x6,
+        /// 7 - L1-BC (E1)
+        L1BC = 0x7,
+    }
+}
+
+signal_ids! {
+    /// BeiDou Signal ID
+    BeidouSignalId {
+        /// 0 - All signals
+        All = 0x0,
+        /// 1 - B1I
+        B1I = 0x1,
+        /// 2 - B1Q
+        B1Q = 0x2,
+        /// 3 - B1C
+        B1C = 0x3,
+        /// 4 - B1A
+        B1A = 0x4,
+        /// 5 - B2-a
+        B2A = 0x5,
+        /// 6 - B2-b
+        B2B = 0x6,
+        /// 7 - B2 a+b
+        B2AB = 0x7,
+        /// 8 - B3I
+        B3I = 0x8,
+        /// 9 - B3Q
+        B3Q = 0x9,
+        /// A - B3A
+        B3A = 0xA,
+        /// B - B2I
+        B2I = 0xB,
+        /// C - B2Q
+        B2Q = 0xC,
+    }
+}
+
+signal_ids! {
+    /// QZSS Signal ID
+    QzssSignalId {
+        /// 0 - All signals
+        All = 0x0,
+        /// 1 - L1 C/A
+        L1CA = 0x1,
+        /// 2 - L1C (D)
+        L1CD = 0x2,
+        /// 3 - L1C (P)
+        L1CP = 0x3,
+        /// 4 - LIS
+        Lis = 0x4,
+        /// 5 - L2C-M
+        L2CM = 0x5,
+        /// 6 - L2C-L
+        L2CL = 0x6,
+        /// 7 - L5-I
+        L5I = 0x7,
+        /// 8 - L5-Q
+        L5Q = 0x8,
+        /// 9 - L6D
+        L6D = 0x9,
+        /// A - L6E
+        L6E = 0xA,
+    }
+}
+
+signal_ids! {
+    /// NavIC Signal ID
+    NavicSignalId {
+        /// 0 - All signals
+        All = 0x0,
+        /// 1 - L5-SPS
+        L5Sps = 0x1,
+        /// 2 - S-SPS
+        SSps = 0x2,
+        /// 3 - L5-RS
+        L5Rs = 0x3,
+        /// 4 - S-RS
+        SRs = 0x4,
+        /// 5 - L1-SPS
+        L1Sps = 0x5,
+    }
+}
 
 /// Satellite information used in [`GSV`] sentences
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 pub struct Satellite {
     /// PRN number of the satellite
     pub prn: u8,
@@ -430,10 +1085,31 @@ pub struct Location {
     pub longitude: f64,
 }
 
+impl NmeaEncode for Sentence {
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        self.talker.encode(e)?;
+        self.data.encode(e)
+    }
+}
+
+impl NmeaEncode for TalkerId {
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        self.code()
+            .iter()
+            .try_for_each(|&byte| e.write_char(byte as char))
+    }
+}
+
+impl NmeaEncode for SignalId {
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        write!(e, "{:X}", self.id())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::IResult;
+    use crate::{IResult, Nmea0183ParserBuilder, Nmea0183WriterBuilder};
 
     #[test]
     fn test_status() {
@@ -450,64 +1126,53 @@ mod tests {
 
     #[test]
     fn test_faa_mode() {
-        #[cfg(feature = "nmea-v2-3")]
-        {
-            assert_eq!(
-                (FaaMode::parse("A") as IResult<_, _>).unwrap(),
-                ("", FaaMode::Autonomous)
-            );
-            assert_eq!(
-                (FaaMode::parse("C") as IResult<_, _>).unwrap(),
-                ("", FaaMode::Caution)
-            );
-            assert_eq!(
-                (FaaMode::parse("D") as IResult<_, _>).unwrap(),
-                ("", FaaMode::Differential)
-            );
-            assert_eq!(
-                (FaaMode::parse("E") as IResult<_, _>).unwrap(),
-                ("", FaaMode::Estimated)
-            );
-            assert_eq!(
-                (FaaMode::parse("F") as IResult<_, _>).unwrap(),
-                ("", FaaMode::FloatRtk)
-            );
-            assert_eq!(
-                (FaaMode::parse("M") as IResult<_, _>).unwrap(),
-                ("", FaaMode::Manual)
-            );
-            assert_eq!(
-                (FaaMode::parse("N") as IResult<_, _>).unwrap(),
-                ("", FaaMode::DataNotValid)
-            );
-            #[cfg(feature = "nmea-v4-11")]
-            {
-                assert_eq!(
-                    (FaaMode::parse("P") as IResult<_, _>).unwrap(),
-                    ("", FaaMode::Precise)
-                );
-            }
-            #[cfg(not(feature = "nmea-v4-11"))]
-            {
-                assert!((FaaMode::parse("P") as IResult<_, _>).is_err());
-            }
-            assert_eq!(
-                (FaaMode::parse("R") as IResult<_, _>).unwrap(),
-                ("", FaaMode::FixedRtk)
-            );
-            assert_eq!(
-                (FaaMode::parse("S") as IResult<_, _>).unwrap(),
-                ("", FaaMode::Simulator)
-            );
-            assert_eq!(
-                (FaaMode::parse("U") as IResult<_, _>).unwrap(),
-                ("", FaaMode::Unsafe)
-            );
-            assert!((FaaMode::parse("X") as IResult<_, _>).is_err());
-        }
+        assert_eq!(
+            (FaaMode::parse("A") as IResult<_, _>).unwrap(),
+            ("", FaaMode::Autonomous)
+        );
+        assert_eq!(
+            (FaaMode::parse("C") as IResult<_, _>).unwrap(),
+            ("", FaaMode::Caution)
+        );
+        assert_eq!(
+            (FaaMode::parse("D") as IResult<_, _>).unwrap(),
+            ("", FaaMode::Differential)
+        );
+        assert_eq!(
+            (FaaMode::parse("E") as IResult<_, _>).unwrap(),
+            ("", FaaMode::Estimated)
+        );
+        assert_eq!(
+            (FaaMode::parse("F") as IResult<_, _>).unwrap(),
+            ("", FaaMode::FloatRtk)
+        );
+        assert_eq!(
+            (FaaMode::parse("M") as IResult<_, _>).unwrap(),
+            ("", FaaMode::Manual)
+        );
+        assert_eq!(
+            (FaaMode::parse("N") as IResult<_, _>).unwrap(),
+            ("", FaaMode::DataNotValid)
+        );
+        assert_eq!(
+            (FaaMode::parse("P") as IResult<_, _>).unwrap(),
+            ("", FaaMode::Precise)
+        );
+        assert_eq!(
+            (FaaMode::parse("R") as IResult<_, _>).unwrap(),
+            ("", FaaMode::FixedRtk)
+        );
+        assert_eq!(
+            (FaaMode::parse("S") as IResult<_, _>).unwrap(),
+            ("", FaaMode::Simulator)
+        );
+        assert_eq!(
+            (FaaMode::parse("U") as IResult<_, _>).unwrap(),
+            ("", FaaMode::Unsafe)
+        );
+        assert!((FaaMode::parse("X") as IResult<_, _>).is_err());
     }
 
-    #[cfg(feature = "nmea-v4-11")]
     #[test]
     fn test_gsa_quality() {
         assert_eq!(
@@ -549,6 +1214,53 @@ mod tests {
         assert!((Quality::parse("9") as IResult<_, _>).is_err());
     }
 
+    #[test]
+    fn test_talker_id() {
+        let cases = [
+            ("GP", TalkerId::Gps),
+            ("GL", TalkerId::Glonass),
+            ("GA", TalkerId::Galileo),
+            ("GB", TalkerId::Beidou),
+            ("BD", TalkerId::Beidou),
+            ("GQ", TalkerId::Qzss),
+            ("GI", TalkerId::Navic),
+            ("GN", TalkerId::Gnss),
+            ("II", TalkerId::Other(*b"II")),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                (TalkerId::parse(input) as IResult<_, _>).unwrap(),
+                ("", expected)
+            );
+            assert_eq!(
+                (TalkerId::parse(input.as_bytes()) as IResult<_, _>).unwrap(),
+                (&b""[..], expected)
+            );
+        }
+
+        assert!((TalkerId::parse("G") as IResult<_, _>).is_err());
+        assert!((TalkerId::parse("G,") as IResult<_, _>).is_err());
+        assert_eq!(TalkerId::Beidou.code(), *b"GB");
+        assert_eq!(TalkerId::Other(*b"II").code(), *b"II");
+    }
+
+    #[test]
+    fn test_sentence() {
+        let result: IResult<_, _> = Sentence::parse("GLGSV,1,1,00");
+        let (_, sentence) = result.unwrap();
+        assert_eq!(sentence.talker, TalkerId::Glonass);
+        assert!(matches!(sentence.data, NmeaSentence::GSV(_)));
+
+        let result: IResult<_, _> = Sentence::parse("GPUNK,some,data");
+        assert!(matches!(
+            result,
+            Err(nom::Err::Error(Error::UnrecognizedMessage(
+                "GPUNK,some,data"
+            )))
+        ));
+    }
+
     #[test]
     fn test_selection_mode() {
         assert_eq!(
@@ -579,7 +1291,6 @@ mod tests {
         assert!((FixMode::parse("4") as IResult<_, _>).is_err());
     }
 
-    #[cfg(feature = "nmea-v4-11")]
     #[test]
     fn test_system_id() {
         assert_eq!(
@@ -609,11 +1320,84 @@ mod tests {
         assert!((SystemId::parse("7") as IResult<_, _>).is_err());
     }
 
-    #[cfg(feature = "nmea-v2-3")]
-    #[cfg(not(feature = "nmea-v3-0"))]
+    #[test]
+    fn test_signal_id() {
+        let cases = [
+            (SystemId::Gps, 0x1, SignalId::Gps(GpsSignalId::L1CA)),
+            (SystemId::Gps, 0x8, SignalId::Gps(GpsSignalId::L5Q)),
+            (SystemId::Gps, 0x9, SignalId::Unknown(0x9)),
+            (
+                SystemId::Glonass,
+                0x3,
+                SignalId::Glonass(GlonassSignalId::G2CA),
+            ),
+            (
+                SystemId::Galileo,
+                0x7,
+                SignalId::Galileo(GalileoSignalId::L1BC),
+            ),
+            (SystemId::Beidou, 0xB, SignalId::Beidou(BeidouSignalId::B2I)),
+            (SystemId::Qzss, 0xA, SignalId::Qzss(QzssSignalId::L6E)),
+            (SystemId::Navic, 0x5, SignalId::Navic(NavicSignalId::L1Sps)),
+            (SystemId::Navic, 0x6, SignalId::Unknown(0x6)),
+        ];
+
+        for (system_id, id, expected) in cases {
+            let signal_id = SignalId::new(system_id, id);
+            assert_eq!(signal_id, expected);
+            assert_eq!(signal_id.id(), id);
+        }
+    }
+
+    #[test]
+    fn test_gsv_signal_id_from_talker() {
+        let cases = [
+            ("GPGSV,1,1,00,1", Some(SignalId::Gps(GpsSignalId::L1CA))),
+            (
+                "GAGSV,1,1,00,7",
+                Some(SignalId::Galileo(GalileoSignalId::L1BC)),
+            ),
+            (
+                "GBGSV,1,1,00,B",
+                Some(SignalId::Beidou(BeidouSignalId::B2I)),
+            ),
+            ("GPGSV,1,1,00,9", Some(SignalId::Unknown(0x9))),
+            ("GNGSV,1,1,00,1", Some(SignalId::Unknown(0x1))),
+            ("GPGSV,1,1,00", None),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = Sentence::parse(input);
+            match result {
+                Ok((
+                    _,
+                    Sentence {
+                        talker,
+                        data: NmeaSentence::GSV(gsv),
+                    },
+                )) => assert_eq!(gsv.signal_id(talker), expected, "Failed: {input:?}"),
+                result => panic!("Failed: {input:?}\n\t{result:?}"),
+            }
+        }
+
+        // The signal ID is a single hexadecimal digit
+        for input in ["GPGSV,1,1,01,01,40,083,46,101", "GPGSV,1,1,00,G"] {
+            let result: IResult<_, _> = Sentence::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+
     #[test]
     fn test_nmea_parser() {
         let valid = [
+            "GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M,A",
+            "GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M",
+            "GPAPB,V,V,,,,V,V,,,,,,,,N",
+            "GPBOD,097.0,T,103.2,M,POINTB,POINTA",
+            "GPBOD,,T,,M,,",
+            "GPBWC,225444,4917.24,N,12309.57,W,051.9,T,031.6,M,001.3,N,004,A",
+            "GPBWC,,,,,,,T,,M,,N,,N",
+            "GPBWR,225444,4917.24,N,12309.57,W,051.9,T,031.6,M,001.3,N,004,A",
             "GPDBT,12.34,f,3.76,M,2.05,F",
             "GPDBT,0.00,f,0.00,M,0.00,F",
             "GPDBT,50.00,f,15.24,M,8.20,F",
@@ -624,36 +1408,107 @@ mod tests {
             "GPDPT,50.0,1.0",
             "GPDPT,1.2,",
             "GPDPT,100.0,0.5",
+            "GPDTM,999,,0.08,N,0.07,W,-47.7,W84",
+            "GPDTM,W84,,,,,,,W84",
+            "GPGBS,015509.00,-0.031,-0.186,0.219,19,0.000,-0.354,6.972",
+            "GPGBS,,,,,,,,",
             "GPGGA,092725.00,4717.113,N,00833.915,E,1,08,1.0,499.7,M,48.0,M,,",
             "GPGGA,235959,0000.000,N,00000.000,W,1,00,99.9,0.0,M,0.0,M,,",
             "GPGGA,000000,9000.000,S,18000.000,W,1,12,0.5,100.0,M,10.0,M,,",
             "GPGGA,010203,1234.567,N,01234.567,E,2,05,2.0,20.0,M,5.0,M,,",
             "GPGLL,4916.45,N,12311.12,W,225444,A,A",
+            "GPGLL,4916.45,N,12311.12,W,225444,A",
             "GPGLL,0000.00,N,00000.00,E,000000,V,N",
             "GPGLL,9000.00,S,18000.00,W,235959,A,D",
             "GPGLL,3456.78,N,07890.12,E,123456,A,A",
             "GPGLL,1234.56,S,01234.56,W,010203,V,N",
+            "GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,",
+            "GNGNS,094620,5157.19,N,00045.61,W,ANNNNN,10,0.8,99.9,45.3,1.5,0042",
+            "GNGNS,,,,,,NNN,,,,,,",
+            "GPGRS,024603.00,1,-1.8,-2.7,0.3,,,,,,,,,",
             "GPGSA,A,3,01,02,03,04,05,06,07,08,09,10,11,12,1.5,1.0,2.0",
             "GPGSA,M,1,,,,,,,,,,,,,99.9,99.9,99.9",
             "GPGSA,A,2,10,20,30,,,,,,,,,,2.0,1.5,2.5",
             "GPGSA,A,3,01,03,05,07,09,11,13,15,17,19,21,23,0.5,0.3,0.7",
             "GPGSA,M,2,02,04,06,,,,,,,,,,3.0,2.5,3.5",
+            "GPGST,024603.00,3.2,6.6,4.7,47.3,5.8,5.6,22.0",
+            "GPGST,,,,,,,,",
             "GPGSV,3,1,11,01,65,123,45,02,40,210,30,03,70,300,35,04,20,090,20",
             "GPGSV,3,2,11,05,50,045,25,06,30,180,15,07,80,270,40,08,10,315,10",
             "GPGSV,3,3,11,09,40,060,22,10,60,150,33,11,75,240,38",
             "GPGSV,1,1,01,01,90,100,50",
             "GPGSV,2,1,04,01,45,120,25,02,30,200,18,03,60,090,30,04,70,310,35",
             "GPGSV,2,2,04,05,20,150,10,06,50,070,28,07,85,240,42",
+            "HEHDG,238.5,,,3.0,W",
+            "HEHDG,101.1,2.5,E,6.2,E",
+            "HEHDG,,,,,",
+            "HEHDM,235.5,M",
+            "HEHDM,,M",
+            "HEHDT,274.07,T",
+            "HEHDT,,T",
+            "WIMDA,30.12,I,1.0200,B,18.5,C,14.2,C,65.0,,11.8,C,245.0,T,241.0,M,8.5,N,4.4,M",
+            "WIMDA,,,,,,,,,,,,,,,,,,,,",
+            "YXMTW,14.2,C",
+            "YXMTW,,C",
+            "WIMWD,270.0,T,266.0,M,12.4,N,6.4,M",
+            "WIMWD,,,,,,,,",
+            "WIMWV,214.8,R,10.2,N,A",
+            "WIMWV,214.8,T,5.2,M,A",
+            "WIMWV,,R,,,V",
+            "GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V,A",
+            "GPRMB,V,,,,,,,,,,,,V,N",
             "GPRMC,123519,A,4807.038,N,01131.000,E,0.20,0.83,230394,004.2,W,A",
+            "GPRMC,123519,A,4807.038,N,01131.000,E,0.20,0.83,230394,004.2,W",
             "GPRMC,092725.00,A,4717.113,N,00833.915,E,0.0,0.0,010190,,,A",
             "GPRMC,235959,V,0000.000,N,00000.000,W,10.5,180.0,311299,,,N",
             "GPRMC,000000,A,9000.000,S,18000.000,W,100.0,0.0,010100,,,A",
             "GPRMC,010203,A,1234.567,N,01234.567,E,5.0,270.0,050607,,,A",
+            "TIROT,-12.5,A",
+            "TIROT,35.0,V",
+            "TIROT,,V",
+            "GPRTE,2,1,c,0,W3IWI,DRIVWY,32CEDR,32-29,32BKLD,32-I95,32-US1,BW-32,BW-198",
+            "GPRTE,1,1,w,",
+            "IIACN,124305.00,,192,1,A,C",
+            "RAALC,01,01,03,2,,192,1,1,SRD,3008,2,1",
+            "RAALF,1,1,0,124304.50,A,W,A,,192,1,1,0,LOST TARGET",
+            "RAALF,2,2,1,,,,,,,,,,TCPA 2.5 MIN",
+            "IIALR,124304.50,001,A,V,BILGE ALARM",
+            "RAARC,124305.00,,192,1,A",
+            "APHSC,040.0,T,036.5,M",
+            "CDDSC,12,3380400790,12,06,00,1423108312,2019,,,S,E",
+            "CDDSE,1,1,A,3380400790,00,45894494",
+            "GPTXT,01,01,02,u-blox ag - www.u-blox.com",
+            "GPTXT,01,01,01,ANTENNA OK^2C LOW GAIN",
+            "RAOSD,035.1,A,036.0,P,10.2,P,,,N",
+            "ERRPM,E,1,2418.2,10.5,A",
+            "ERRPM,S,2,-850,,V",
+            "AGRSA,10.5,A,,V",
+            "RARSD,0.5,90,1.2,45,,,,,2.1,270,3.0,N,H",
+            "RATLL,01,4917.24,N,12309.57,W,TGT01,100021.00,T,",
+            "RATTM,01,0.2,190.8,T,12.1,109.7,T,0.1,0.5,N,TGT01,T,",
+            "RATTM,02,,,R,,,R,,,,,L,",
+            "VWVBW,12.3,-0.07,A,11.8,0.12,A",
+            "VWVBW,,,V,,,V",
+            "IIVDR,10.1,T,12.3,M,1.2,N",
+            "VWVHW,245.1,T,245.1,M,12.5,N,23.2,K",
+            "VWVHW,,T,,M,,N,,K",
+            "VWVLW,7803.2,N,0.00,N",
             "GPVTG,054.7,T,034.4,M,005.5,N,010.2,K,A",
+            "GPVTG,054.7,T,034.4,M,005.5,N,010.2,K",
             "GPVTG,000.0,T,000.0,M,000.0,N,000.0,K,N",
             "GPVTG,359.9,T,330.0,M,010.0,N,018.5,K,A",
             "GPVTG,090.0,T,060.0,M,001.0,N,001.8,K,A",
             "GPVTG,180.0,T,150.0,M,020.0,N,037.0,K,A",
+            "WIVWR,75.0,R,10.0,N,5.1,M,18.5,K",
+            "WIVWR,120.5,L,,,,,18.5,K",
+            "WIVWT,30.0,L,12.0,N,6.2,M,22.2,K",
+            "GPWPL,4917.16,N,12310.64,W,003",
+            "GPWPL,,,,,",
+            "IIXDR,P,1.02,B,BARO,C,18.5,C,AIRTEMP,H,65,P,",
+            "IIXDR,S,,,VALVE",
+            "GPXTE,A,A,0.67,L,N,A",
+            "GPXTE,A,A,0.67,L,N",
+            "GPXTE,V,V,,,,N",
             "GPZDA,123519,04,07,2025,,",
             "GPZDA,092725.00,01,01,1990,,",
             "GPZDA,235959,31,12,1999,,",
@@ -677,49 +1532,103 @@ mod tests {
         }
 
         let invalid = [
-            "GPDBT,12.34,x,3.76,M,2.05,F",   // Invalid unit 'x'
-            "GPDBT,1.0,f,a,M,2.0,F",         // Non-numeric depth
-            "GPDBT,10.0,f,5.0,M",            // Missing last field
-            "GPDBT,TooDeep,f,1.0,M,2.0,F",   // Non-numeric depth
-            "GPDBT,1.0,f,2.0,M,3.0,F,extra", // Extra field
-            "GPDPT,10.5,0.2,x",              // Invalid character
-            "GPDPT,10.5,0.2,1,2",            // Too many fields
-            "GPDPT,abc,,",                   // Non-numeric depth
-            "GPDPT,,0.5,",                   // Missing depth
-            "GPDPT,10.0",                    // Too few fields
+            "GPAPB,A,A,0.10,R,N,V,V,011,X,DEST,011,M,011,M,A", // Invalid bearing reference
+            "GPBOD,097.0,T,103.2,M,POINTB",                    // Missing origin waypoint
+            "GPBOD,097.0,T,103.2,M,WAYPOINT_BB,POINTA",        // Waypoint identifier too long
+            "GPBWC,225444,4917.24,N,12309.57,W,051.9,T,031.6,M,001.3,K,004,A", // Invalid distance unit
+            "GPDBT,12.34,x,3.76,M,2.05,F",                                     // Invalid unit 'x'
+            "GPDBT,1.0,f,a,M,2.0,F",                                           // Non-numeric depth
+            "GPDBT,10.0,f,5.0,M",                                              // Missing last field
+            "GPDBT,TooDeep,f,1.0,M,2.0,F",                                     // Non-numeric depth
+            "GPDBT,1.0,f,2.0,M,3.0,F,extra",                                   // Extra field
+            "GPDPT,10.5,0.2,x",                                                // Invalid character
+            "GPDPT,10.5,0.2,1,2",                                              // Too many fields
+            "GPDPT,abc,,",                                                     // Non-numeric depth
+            "GPDPT,10.0",                                                      // Too few fields
+            "GPDTM,W84,,0.08,E,0.07,W,-47.7,W84", // Invalid latitude offset direction
+            "GPDTM,W84,,0.08,N,0.07,W,-47.7",     // Missing reference datum
+            "GPGBS,015509.00,-0.031,-0.186,0.219,XX,0.000,-0.354,6.972", // Non-numeric satellite ID
             "GPGGA,123519,4807.038,N,01131.000,X,1,08,0.9,545.4,M,46.9,M,,", // Invalid East/West indicator
             "GPGGA,123519,4807.038,N,01131.000,E,9,08,0.9,545.4,M,46.9,M,,", // Invalid Fix Quality
             "GPGGA,123519,4807.038,N,01131.000,E,1,A8,0.9,545.4,M,46.9,M,,", // Invalid satellites (non-numeric)
             "GPGLL,4916.45,N,12311.12,W,225444,A,X", // Invalid mode indicator
-            "GPGLL,4916.45,N,12311.12,W,225444,A",   // Missing mode indicator
             "GPGLL,abc,N,12311.12,W,225444,A,A",     // Non-numeric latitude
             "GPGLL,4916.45,N,def,W,225444,A,A",      // Non-numeric longitude
             "GPGLL,4916.45,N,12311.12,W,25444,A,A",  // Invalid time format (too short)
-            "GPGSA,A,3,01,02,03,04,05,06,07,08,09,10,11,12,A,1.0,2.0", // Non-numeric PDOP
-            "GPGSA,A,3,01,02,03,04,05,06,07,08,09,10,11,12,1.5,B,2.0", // Non-numeric HDOP
-            "GPGSA,A,3,01,02,03,04,05,06,07,08,09,10,11,12,1.5,1.0,C", // Non-numeric VDOP
+            "GNGNS,014035.00,4332.69262,S,17235.48549,E,RX,13,0.9,25.63,11.24,,", // Invalid mode indicator
+            "GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24", // Missing reference station
+            "GPGRS,024603.00,2,-1.8,-2.7,0.3,,,,,,,,,",                         // Invalid mode
+            "GPGRS,024603.00,1,-1.8,-2.7,0.3",                                  // Missing residuals
+            "GPGSA,A,3,01,02,03,04,05,06,07,08,09,10,11,12,A,1.0,2.0",          // Non-numeric PDOP
+            "GPGSA,A,3,01,02,03,04,05,06,07,08,09,10,11,12,1.5,B,2.0",          // Non-numeric HDOP
+            "GPGSA,A,3,01,02,03,04,05,06,07,08,09,10,11,12,1.5,1.0,C",          // Non-numeric VDOP
             "GPGSA,A,4,01,02,03,04,05,06,07,08,09,10,11,12,1.5,1.0,2.0", // Invalid fix mode (4 is not 1, 2, or 3)
             "GPGSA,A,3,01,02,03,04,05,06,07,08,09,10,11,12,1.5,1.0",     // Missing VDOP
+            "GPGST,024603.00,3.2,6.6,4.7,47.3,5.8,5.6", // Missing altitude deviation
             "GPGSV,3,1,11,01,65,123,45,02,40,210,30,03,70,300,35,04,20,090,XX", // Non-numeric SNR
             "GPGSV,3,1,11,01,65,123,45,02,40,210,30,03,70,300,35,04,20,090", // Missing SNR
+            "HEHDG,238.5,,,3.0,N",                      // Invalid variation direction
+            "HEHDG,238.5,1.0,,,",                       // Missing deviation direction
+            "HEHDG,238.5,,,3.0",                        // Missing variation direction
+            "HEHDM,235.5,T",                            // Invalid unit 'T'
+            "HEHDT,274.07,M",                           // Invalid unit 'M'
+            "HEHDT,abc,T",                              // Non-numeric heading
+            "HEHDT,274.07,T,extra",                     // Extra field
+            "WIMDA,30.12,X,1.0200,B,18.5,C,14.2,C,65.0,,11.8,C,245.0,T,241.0,M,8.5,N,4.4,M", // Invalid pressure unit
+            "WIMDA,30.12,I,1.0200,B",             // Too few fields
+            "YXMTW,14.2,F",                       // Invalid unit 'F'
+            "WIMWD,270.0,M,266.0,M,12.4,N,6.4,M", // Invalid true direction unit
+            "WIMWV,214.8,X,10.2,N,A",             // Invalid reference
+            "WIMWV,214.8,R,10.2,F,A",             // Invalid speed unit
+            "WIMWV,214.8,R,10.2,N",               // Missing status
+            "GPRMB,A,0.66,X,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V,A", // Invalid direction to steer
+            "GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,X,A", // Invalid arrival status
             "GPRMC,123519,A,4807.038,N,01131.000,E,0.20,0.83,230394,004.2,W,X", // Invalid mode (X not one of ACDEFMNRSU)
-            "GPRMC,123519,A,4807.038,N,01131.000,E,0.20,0.83,230394,004.2,W",   // Missing mode
             "GPRMC,123519,A,4807.038,N,01131.000,E,abc,0.83,230394,004.2,W,A",  // Non-numeric speed
-            "GPVTG,054.7,T,034.4,M,005.5,N,010.2,K,X", // Invalid mode indicator
-            "GPVTG,054.7,T,034.4,M,005.5,N,010.2,K",   // Missing mode indicator
-            "GPVTG,abc,T,034.4,M,005.5,N,010.2,K,A",   // Non-numeric true track
-            "GPVTG,054.7,T,def,M,005.5,N,010.2,K,A",   // Non-numeric magnetic track
-            "GPVTG,054.7,T,034.4,M,ghi,N,010.2,K,A",   // Non-numeric speed over ground (knots)
-            "GPZDA,123519,04,07,2025,XX,",             // Non-numeric local time zone hours
-            "GPZDA,123519,04,07,2025,,XX",             // Non-numeric local time zone minutes
-            "GPZDA,123519,32,07,2025,,",               // Invalid day (32)
-            "GPZDA,123519,04,13,2025,,",               // Invalid month (13)
-            "GPZDA,123519,04,07,2025",                 // Missing local time zone fields
-            "GPZDA,abc,04,07,2025,,",                  // Non-numeric time
-            "GPZDA,123519,0,07,2025,,",                // Day 0
-            "GPZDA,123519,04,0,2025,,",                // Month 0
-            "GPZDA,123519,04,07,2025,01,ab",           // Non-numeric local time zone minutes
-            "GPZDA,123519,04,07,2025,ab,00",           // Non-numeric local time zone hours
+            "TIROT,-12.5,X",                                                    // Invalid status
+            "TIROT,abc,A",                                // Non-numeric rate of turn
+            "TIROT,-12.5",                                // Missing status
+            "GPRTE,2,1,x,0,W3IWI",                        // Invalid route mode
+            "GPRTE,2,1,c,0,W3IWI,,DRIVWY",                // Empty waypoint identifier
+            "IIACN,124305.00,,192,1,A,X",                 // Invalid sentence status flag
+            "RAALC,01,01,03,2,,192,1",                    // Incomplete alert entry
+            "RAALF,1,1,0,124304.50,X,W,A,,192,1,1,0,TXT", // Invalid alert category
+            "IIALR,124304.50,001,X,V,BILGE ALARM",        // Invalid alarm condition
+            "RAARC,124305.00,,192,1,X",                   // Invalid refused command
+            "CDDSC,13,3380400790,12,06,00,1423108312,2019,,,S,E", // Invalid format specifier
+            "CDDSE,1,1,X,3380400790,00,45894494",         // Invalid query flag
+            "GPTXT,01,01,01,BAD ^Z ESCAPE",               // Invalid escape
+            "APHSC,040.0,M,036.5,M",                      // Invalid true heading unit
+            "ERRPM,X,1,2418.2,10.5,A",                    // Invalid source
+            "AGRSA,10.5,A,-3.2",                          // Missing port status
+            "RAOSD,035.1,A,036.0,X,10.2,P,,,N",           // Invalid course reference
+            "RARSD,0.5,90,1.2,45,,,,,2.1,270,3.0,N,X",    // Invalid display rotation
+            "RATLL,01,4917.24,N,12309.57,W,TGT01,100021.00,T", // Missing reference target
+            "RATTM,01,0.2,190.8,T,12.1,109.7,T,0.1,0.5,N,TGT01,X,", // Invalid target status
+            "VWVBW,12.3,-0.07,X,11.8,0.12,A",             // Invalid water status
+            "IIVDR,10.1,M,12.3,M,1.2,N",                  // Invalid set reference
+            "VWVHW,245.1,T,245.1,M,12.5,K,23.2,K",        // Invalid speed unit
+            "VWVLW,7803.2,N",                             // Missing trip distance
+            "GPVTG,054.7,T,034.4,M,005.5,N,010.2,K,X",    // Invalid mode indicator
+            "GPVTG,abc,T,034.4,M,005.5,N,010.2,K,A",      // Non-numeric true track
+            "GPVTG,054.7,T,def,M,005.5,N,010.2,K,A",      // Non-numeric magnetic track
+            "GPVTG,054.7,T,034.4,M,ghi,N,010.2,K,A",      // Non-numeric speed over ground (knots)
+            "WIVWR,75.0,X,10.0,N,5.1,M,18.5,K",           // Invalid side
+            "WIVWT,30.0,,12.0,N,6.2,M,22.2,K",            // Missing side
+            "GPWPL,4917.16,N,12310.64,W",                 // Missing waypoint identifier
+            "IIXDR,X,1.0,B,BARO",                         // Invalid transducer type
+            "IIXDR,P,1.0,B,BARO,C",                       // Incomplete measurement
+            "GPXTE,A,A,0.67,L,K,A",                       // Invalid cross-track error unit
+            "GPZDA,123519,04,07,2025,XX,",                // Non-numeric local time zone hours
+            "GPZDA,123519,04,07,2025,,XX",                // Non-numeric local time zone minutes
+            "GPZDA,123519,32,07,2025,,",                  // Invalid day (32)
+            "GPZDA,123519,04,13,2025,,",                  // Invalid month (13)
+            "GPZDA,123519,04,07,2025",                    // Missing local time zone fields
+            "GPZDA,abc,04,07,2025,,",                     // Non-numeric time
+            "GPZDA,123519,0,07,2025,,",                   // Day 0
+            "GPZDA,123519,04,0,2025,,",                   // Month 0
+            "GPZDA,123519,04,07,2025,01,ab",              // Non-numeric local time zone minutes
+            "GPZDA,123519,04,07,2025,ab,00",              // Non-numeric local time zone hours
         ];
 
         for sentence in invalid {
@@ -732,4 +1641,264 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_nmea_encoder() {
+        // Sentences already in the encoded format are written back unchanged
+        let canonical = [
+            "GPAPB,A,A,0.1,R,N,V,V,11,M,DEST,11,M,11,M,A",
+            "GPBOD,97,T,103.2,M,POINTB,POINTA",
+            "GPBOD,,,,,,",
+            "GPBWC,225444.00,4917.24000,N,12309.57000,W,51.9,T,31.6,M,1.3,N,004,A",
+            "GPDPT,10.5,0.2,100",
+            "GPDTM,999,,0.08,N,0.07,W,-47.7,W84",
+            "GPDTM,W84,A,,,,,,W84",
+            "GPGBS,015509.00,-0.031,-0.186,0.219,19,0,-0.354,6.972,1,1",
+            "GPGGA,092725.00,4717.11300,N,00833.91500,E,1,8,1,499.7,M,48,M,,",
+            "GPGGA,,,,,,0,,,,,,,,",
+            "GPGLL,4916.45000,N,12311.12000,W,225444.00,A,A",
+            "GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,,V",
+            "GNGNS,,,,,,,,,,,,,V",
+            "GPGRS,024603.00,1,-1.8,-2.7,0.3,,,,,,,,,,1,1",
+            "GPGST,024603.00,3.2,6.6,4.7,47.3,5.8,5.6,22",
+            "GPGSA,A,3,1,2,3,,,,,,,,,,1.5,1,2,1",
+            "GPGSV,1,1,1,1,90,100,50,1",
+            "HEHDG,238.5,,,3,W",
+            "HEHDG,101.1,2.5,E,6.2,E",
+            "HEHDG,,,,,",
+            "HEHDM,235.5,M",
+            "HEHDT,274.07,T",
+            "HEHDT,,",
+            "GPRMC,123519.00,A,4807.03800,N,01131.00000,E,0.2,0.83,230394,4.2,W,A,V",
+            "AIVDM,2,1,3,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0",
+            "AIVDO,1,1,,,B5NJ;PP005l4ot5Isbl03wsUkP06,0",
+            "IIACN,124305.00,,192,1,A,C",
+            "IIACN,,SRD,3008,2,S,C",
+            "RAALC,1,1,3,2,,192,1,1,SRD,3008,2,1",
+            "RAALF,1,1,0,124304.50,A,W,A,,192,1,1,0,LOST TARGET",
+            "RAALF,2,2,1,,,,,,,,,,TCPA 2.5 MIN",
+            "IIALR,124304.50,1,A,V,BILGE ALARM",
+            "RAARC,124305.00,,192,1,A",
+            "APHSC,40,T,36.5,M",
+            "CDDSC,12,3380400790,12,06,00,1423108312,2019,,,S,E",
+            "CDDSC,20,0023100000,00,,,,,,,R,",
+            "CDDSE,1,1,A,3380400790,00,45894494,06,12",
+            "GPTXT,1,1,2,u-blox ag - www.u-blox.com",
+            "GPTXT,1,1,1,ANTENNA OK^2C LOW GAIN",
+            "APHSC,,,,",
+            "RAOSD,35.1,A,36,P,10.2,P,,,N",
+            "ERRPM,E,1,2418.2,10.5,A",
+            "AGRSA,10.5,A,-3.2,A",
+            "RARSD,0.5,90,1.2,45,,,,,2.1,270,3,N,H",
+            "RATLL,1,4917.24000,N,12309.57000,W,TGT01,100021.00,T,",
+            "RATTM,1,0.2,190.8,T,12.1,109.7,R,0.1,-0.5,N,TGT01,T,R,100021.00,A",
+            "VWVBW,12.3,-0.07,A,11.8,0.12,A,0.1,A,,V",
+            "IIVDR,10.1,T,12.3,M,1.2,N",
+            "IIVDR,,,,,,",
+            "VWVHW,245.1,T,245.1,M,12.5,N,23.15,K",
+            "VWVHW,,,,,,,,",
+            "VWVLW,7803.2,N,0,N,1021.4,N,12.6,N",
+            "WIMDA,,,,,,,,,,,,,,,,,,,,",
+            "YXMTW,14.2,C",
+            "GPRMB,A,0.66,L,003,004,4917.24000,N,12309.57000,W,1.3,52.5,0.5,V,A",
+            "GPRTE,2,1,c,0,W3IWI,DRIVWY,32CEDR",
+            "GPRTE,1,1,w,",
+            "GPWPL,4917.16000,N,12310.64000,W,003",
+            "GPXTE,A,A,0.67,L,N,A",
+            "WIMWV,214.8,R,10.2,N,A",
+            "WIMWV,,T,,,V",
+            "IIXDR,P,1.02,B,BARO,C,18.5,C,AIRTEMP,H,65,P,",
+            "IIXDR,S,,,VALVE",
+            "GPZDA,100000.00,15,03,2024,01,30",
+            "GPZDA,153045.50,20,11,2023,-08,00",
+            "GPZDA,,,,,,",
+            "TIROT,-12.5,A",
+            "TIROT,,V",
+            "GPTHS,77.52,E",
+            "GPTHS,,V",
+        ];
+
+        for input in canonical {
+            let (_, sentence): (_, Sentence) = (Sentence::parse(input) as IResult<_, _>)
+                .unwrap_or_else(|e| panic!("Failed: {input:?}\n\t{e:?}"));
+
+            let mut output = String::new();
+            sentence.encode(&mut Encoder::new(&mut output)).unwrap();
+            assert_eq!(output, input, "Failed: {sentence:?}");
+        }
+
+        // Other sentences parse back to the same values
+        let round_trip = [
+            "GPAPB,V,V,,,,V,V,,,,,,,,A",
+            "GPBWR,225444,4917.24,N,12309.57,W,051.9,T,031.6,M,001.3,N,004,A",
+            "GPXTE,V,V,,,N,A",
+            "GPDBT,12.34,f,3.76,M,2.05,F",
+            "GPDBT,50.00,f,,M,,F",
+            "GPGGA,001043.00,4404.14036,N,12118.85961,W,1,12,0.98,1113.0,M,-21.3,M,2.5,0042",
+            "GPGLL,0000.00,N,00000.00,E,000000,V,A",
+            "GPGSA,M,1,,,,,,,,,,,,,99.9,99.9,99.9,1",
+            "GPGSV,3,3,11,09,40,060,22,10,60,150,33,11,75,240,38,1",
+            "GPRMC,092725.00,A,4717.113,N,00833.915,E,0.0,0.0,010190,,,A,V",
+            "GPVTG,054.7,T,034.4,M,005.5,N,010.2,K,A",
+            "GPVTG,,T,,M,,N,018.5,K,A",
+            "VWVHW,,T,,M,,N,18.52,K",
+            "IIALR,124304.50,001,A,V,BILGE ALARM",
+            "GPTXT,01,01,02,ANTARIS ATR0620 HW 00000040",
+            "RATTM,02,,,T,,,T,,,,,L,,100021.00,A",
+            "WIMDA,30.12,I,1.0200,B,18.5,C,14.2,C,65.0,,11.8,C,245.0,T,241.0,M,8.5,N,4.4,M",
+            "WIMWD,270.0,T,266.0,M,12.4,N,6.4,M",
+            "WIMWV,214.8,T,5.2,M,A",
+            "WIVWR,120.5,L,,,,,18.5,K",
+            "WIVWT,30.0,L,12.0,N,6.2,M,22.2,K",
+            "GPZDA,123456.78,29,02,2024,03,00",
+        ];
+
+        for input in round_trip {
+            let (_, sentence): (_, Sentence) = (Sentence::parse(input) as IResult<_, _>)
+                .unwrap_or_else(|e| panic!("Failed: {input:?}\n\t{e:?}"));
+
+            let mut output = String::new();
+            sentence.encode(&mut Encoder::new(&mut output)).unwrap();
+
+            let result: IResult<_, _> = Sentence::parse(output.as_str());
+            assert_eq!(
+                result,
+                Ok(("", sentence)),
+                "Failed: {input:?}\n\t{output:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_nmea_parser_bytes() {
+        let valid = [
+            "GPDBT,12.34,f,3.76,M,2.05,F",
+            "GPGGA,092725.00,4717.113,N,00833.915,E,1,08,1.0,499.7,M,48.0,M,,",
+            "GPGSV,1,1,00",
+            "AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0",
+            "GPZDA,100000,15,03,2024,+01,30",
+        ];
+
+        for sentence in valid {
+            let (_, expected) = (NmeaSentence::parse(sentence) as IResult<_, _>).unwrap();
+            let result: IResult<_, _> = NmeaSentence::parse(sentence.as_bytes());
+            assert_eq!(
+                result,
+                Ok((&b""[..], expected)),
+                "Failed: {sentence:?}\n\t{result:?}"
+            );
+        }
+
+        let result: IResult<_, _> = Sentence::parse(&b"GPUNK,some,data"[..]);
+        assert!(matches!(
+            result,
+            Err(nom::Err::Error(Error::UnrecognizedMessage(
+                b"GPUNK,some,data"
+            )))
+        ));
+
+        let mut parser = Nmea0183ParserBuilder::new().build(Sentence::parse);
+        let result: IResult<_, _> = parser.parse(&b"$GLGSV,1,1,00*65\r\n"[..]);
+        let (_, sentence) = result.unwrap();
+        assert_eq!(sentence.talker, TalkerId::Glonass);
+        assert!(matches!(sentence.data, NmeaSentence::GSV(_)));
+    }
+
+    #[test]
+    fn test_nmea_parser_version() {
+        use crate::NmeaVersion::{V2_0, V2_3, V3_0, V4_11};
+
+        let rmc = "GPRMC,001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,";
+        let rmc_v2_3 = "GPRMC,001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A";
+        let rmc_v4_11 = "GPRMC,001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A,V";
+        let dpt = "IIDPT,10.0,2.0";
+        let dpt_v3_0 = "IIDPT,10.0,2.0,20.0";
+        let gsv = "GPGSV,1,1,01,05,45,120,38";
+        let gsv_v4_11 = "GPGSV,1,1,01,05,45,120,38,1";
+
+        let cases = [
+            (
+                VersionRange::from(V2_0),
+                [true, false, false, true, false, true, false],
+            ),
+            (
+                VersionRange::from(V2_3),
+                [false, true, false, true, false, true, false],
+            ),
+            (
+                VersionRange::from(V3_0),
+                [false, true, false, false, true, true, false],
+            ),
+            (
+                VersionRange::from(V4_11),
+                [false, false, true, false, true, false, true],
+            ),
+            (VersionRange::from(V2_0..=V4_11), [true; 7]),
+            (
+                VersionRange::from(V2_3..=V4_11),
+                [false, true, true, true, true, true, true],
+            ),
+        ];
+
+        for (versions, expected) in cases {
+            for (input, expected) in [rmc, rmc_v2_3, rmc_v4_11, dpt, dpt_v3_0, gsv, gsv_v4_11]
+                .into_iter()
+                .zip(expected)
+            {
+                let result: IResult<_, _> = Sentence::parse_version(input, versions);
+                assert_eq!(
+                    matches!(result, Ok(("", _))),
+                    expected,
+                    "Failed: {input:?} for {versions:?}\n\t{result:?}"
+                );
+            }
+        }
+
+        // Fields of the versions after the range are left to their default value
+        let result: IResult<_, _> = Sentence::parse_version(rmc_v2_3, V2_3.into());
+        let Ok((
+            _,
+            Sentence {
+                data: NmeaSentence::RMC(rmc),
+                ..
+            },
+        )) = result
+        else {
+            panic!("{result:?}");
+        };
+        assert_eq!(rmc.faa_mode, Some(FaaMode::Autonomous));
+        assert_eq!(rmc.nav_status, None);
+
+        let mut parser = Nmea0183ParserBuilder::new()
+            .version(VersionRange::from(V2_0..=V4_11))
+            .build_version(AnySentence::parse_version);
+        let result: IResult<_, _> = parser.parse("$GPGSV,1,1,01,05,45,120,38*44\r\n");
+        let Ok((
+            _,
+            AnySentence::Known(Sentence {
+                data: NmeaSentence::GSV(gsv),
+                ..
+            }),
+        )) = result
+        else {
+            panic!("{result:?}");
+        };
+        assert_eq!(gsv.signal_id_raw, None);
+    }
+
+    #[test]
+    fn test_nmea_writer() {
+        let input = "$GPGGA,092725.00,4717.11300,N,00833.91500,E,1,8,1,499.7,M,48,M,,*52\r\n";
+
+        let mut parser = Nmea0183ParserBuilder::new().build(NmeaSentence::parse);
+        let (_, sentence): (_, NmeaSentence) = (parser(input) as IResult<_, _>).unwrap();
+
+        let mut writer = Nmea0183WriterBuilder::new()
+            .talker(TalkerId::Gps.code())
+            .build(NmeaSentence::encode);
+
+        let mut output = String::new();
+        writer(&mut output, &sentence).unwrap();
+        assert_eq!(output, input);
+    }
 }
@@ -0,0 +1,66 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{encode::write_with_unit, parse::with_unit},
+};
+
+/// MTW - Mean Temperature of Water
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mtw_mean_temperature_of_water>
+///
+/// ```text
+///         1   2
+///         |   |
+///  $--MTW,x.x,C*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct MTW {
+    #[nmea(parser(with_unit('C')), writer(write_with_unit('C')))]
+    /// Water temperature in degrees Celsius
+    pub temperature: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_mtw_parsing() {
+        let cases = [
+            (
+                "17.9,C",
+                MTW {
+                    temperature: Some(17.9),
+                },
+            ),
+            (
+                "-1.5,C",
+                MTW {
+                    temperature: Some(-1.5),
+                },
+            ),
+            (",C", MTW { temperature: None }),
+            (",", MTW { temperature: None }),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = MTW::parse(input);
+            assert_eq!(
+                result,
+                Ok(("", expected)),
+                "Failed: {input:?}\n\t{result:?}"
+            );
+        }
+
+        for input in ["17.9,F", "17.9", "warm,C"] {
+            let result: IResult<_, _> = MTW::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use nom::{
+    AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser, character::complete::char,
+    error::ParseError,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+
+use crate::{
+    self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse,
+    nmea_content::{encode::write_with_unit, parse::with_unit},
+};
+
+/// MWD - Wind Direction & Speed
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mwd_wind_direction_speed>
+///
+/// ```text
+///         1   2 3   4 5   6 7   8
+///         |   | |   | |   | |   |
+///  $--MWD,x.x,T,x.x,M,x.x,N,x.x,M*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct MWD {
+    #[nmea(parser(with_unit('T')), writer(write_with_unit('T')))]
+    /// Direction the wind blows from in degrees true
+    pub wind_direction_true: Option<f32>,
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
+    /// Direction the wind blows from in degrees magnetic
+    pub wind_direction_magnetic: Option<f32>,
+    #[nmea(parser(wind_speed), writer(write_wind_speed))]
+    /// Wind speed in knots
+    pub wind_speed: Option<f32>,
+}
+
+pub fn wind_speed<I, E>(i: I) -> IResult<I, Option<f32>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: for<'a> Compare<&'a [u8]> + Compare<&'static str>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    let (i, wind_speed_knots) = with_unit('N').parse(i)?;
+    let (i, _) = char(',').parse(i)?;
+    let (i, wind_speed_mps) = with_unit('M').parse(i)?;
+
+    Ok((
+        i,
+        wind_speed_knots.or(wind_speed_mps.map(|mps: f32| mps * 3600.0 / 1852.0)),
+    ))
+}
+
+pub fn write_wind_speed(wind_speed: &Option<f32>, e: &mut Encoder<'_>) -> fmt::Result {
+    write_with_unit('N')(wind_speed, e)?;
+    e.write_char(',')?;
+    write_with_unit('M')(&wind_speed.map(|knots| knots * 1852.0 / 3600.0), e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mwd_parsing() {
+        let cases = [
+            ("270.0,T,266.0,M,12.4,N,6.4,M", Some(12.4)),
+            ("270.0,T,,,,,6.4,M", Some(6.4 * 3600.0 / 1852.0)),
+            (",,,,,,,", None),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = MWD::parse(input);
+            let Ok(("", mwd)) = result else {
+                panic!("Failed: {input:?}\n\t{result:?}");
+            };
+            assert_eq!(mwd.wind_speed, expected, "Failed: {input:?}");
+        }
+    }
+}
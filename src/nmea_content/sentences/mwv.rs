@@ -0,0 +1,114 @@
+use nom::{
+    AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser,
+    character::complete::{char, one_of},
+    combinator::opt,
+    error::ParseError,
+    sequence::separated_pair,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::{
+    self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse,
+    nmea_content::{Status, encode::write_with_unit},
+};
+
+/// MWV - Wind Speed and Angle
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mwv_wind_speed_and_angle>
+///
+/// ```text
+///         1   2 3   4 5
+///         |   | |   | |
+///  $--MWV,x.x,a,x.x,a,A*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct MWV {
+    /// Wind angle in degrees (0-359), clockwise from the bow
+    pub wind_angle: Option<f32>,
+    /// Reference of the wind angle and speed
+    pub reference: WindReference,
+    #[nmea(parser(wind_speed), writer(write_wind_speed))]
+    /// Wind speed in knots
+    pub wind_speed: Option<f32>,
+    /// Status of the data
+    pub status: Status,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("RT")))]
+/// Reference of the wind reported by [`MWV`]
+pub enum WindReference {
+    #[default]
+    #[nmea(selector('R'))]
+    /// R - Relative, apparent wind as measured on the moving vessel
+    Relative,
+    #[nmea(selector('T'))]
+    /// T - Theoretical, true wind calculated from the vessel speed
+    True,
+}
+
+/// Parses a wind speed in any of the MWV units, converted to knots.
+///
+/// A speed without unit is kept as is, in knots, the unit written by the encoder.
+fn wind_speed<I, E>(i: I) -> IResult<I, Option<f32>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: for<'a> Compare<&'a [u8]> + Compare<&'static str>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    separated_pair(<Option<f32>>::parse, char(','), opt(one_of("KMNS")))
+        .map(|(speed, unit)| {
+            let factor = match unit {
+                Some('K') => 1.0 / 1.852,
+                Some('M') => 3600.0 / 1852.0,
+                Some('S') => 1609.344 / 1852.0,
+                _ => 1.0,
+            };
+            speed.map(|speed| speed * factor)
+        })
+        .parse(i)
+}
+
+fn write_wind_speed(wind_speed: &Option<f32>, e: &mut Encoder<'_>) -> fmt::Result {
+    write_with_unit('N')(wind_speed, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mwv_parsing() {
+        let cases = [
+            ("214.8,R,10.2,N,A", Some(10.2)),
+            ("214.8,R,18.9,K,A", Some(18.9 / 1.852)),
+            ("214.8,R,5.0,M,A", Some(5.0 * 3600.0 / 1852.0)),
+            ("214.8,T,10.0,S,A", Some(10.0 * 1609.344 / 1852.0)),
+            ("214.8,R,10.2,,A", Some(10.2)),
+            ("214.8,R,,,V", None),
+            ("214.8,R,,N,V", None),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = MWV::parse(input);
+            let Ok(("", mwv)) = result else {
+                panic!("Failed: {input:?}\n\t{result:?}");
+            };
+            assert_eq!(mwv.wind_speed, expected, "Failed: {input:?}");
+        }
+
+        for input in ["214.8,X,10.2,N,A", "214.8,R,10.2,F,A", "214.8,R,10.2,N"] {
+            let result: IResult<_, _> = MWV::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
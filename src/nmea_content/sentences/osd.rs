@@ -0,0 +1,110 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use nom::character::complete::one_of;
+
+use super::ttm::DistanceUnit;
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse, nmea_content::Status};
+
+/// OSD - Own Ship Data
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_osd_own_ship_data>
+///
+/// ```text
+///         1   2 3   4 5   6 7   8   9
+///         |   | |   | |   | |   |   |
+///  $--OSD,x.x,A,x.x,a,x.x,a,x.x,x.x,a*hh<CR><LF>
+/// ```
+///
+/// Speeds are given in the [`units`](OSD::units) of the sentence.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct OSD {
+    /// Heading in degrees true
+    pub heading: Option<f32>,
+    /// Status of the heading
+    pub status: Status,
+    /// Course in degrees true
+    pub course: Option<f32>,
+    /// Reference of the course
+    pub course_reference: Option<MotionReference>,
+    /// Speed of the vessel
+    pub speed: Option<f32>,
+    /// Reference of the speed
+    pub speed_reference: Option<MotionReference>,
+    /// Set of the current in degrees true
+    pub set: Option<f32>,
+    /// Drift (speed) of the current
+    pub drift: Option<f32>,
+    /// Units of the speeds
+    pub units: Option<DistanceUnit>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("BMWRP")))]
+/// Source of the own ship course and speed reported by [`OSD`]
+pub enum MotionReference {
+    #[nmea(selector('B'))]
+    /// B - Bottom tracking log
+    BottomTracking,
+    #[nmea(selector('M'))]
+    /// M - Manually entered
+    Manual,
+    #[nmea(selector('W'))]
+    /// W - Water referenced
+    Water,
+    #[nmea(selector('R'))]
+    /// R - Radar tracking of a fixed target
+    Radar,
+    #[default]
+    #[nmea(selector('P'))]
+    /// P - Positioning system ground reference
+    Positioning,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_osd_parsing() {
+        let result: IResult<_, _> = OSD::parse("035.5,A,036.1,B,10.2,W,120.0,0.8,N");
+        let (rest, osd) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(osd.heading, Some(35.5));
+        assert_eq!(osd.status, Status::Valid);
+        assert_eq!(osd.course, Some(36.1));
+        assert_eq!(osd.course_reference, Some(MotionReference::BottomTracking));
+        assert_eq!(osd.speed, Some(10.2));
+        assert_eq!(osd.speed_reference, Some(MotionReference::Water));
+        assert_eq!(osd.set, Some(120.0));
+        assert_eq!(osd.drift, Some(0.8));
+        assert_eq!(osd.units, Some(DistanceUnit::NauticalMiles));
+
+        let result: IResult<_, _> = OSD::parse(",V,,,5.5,P,,,K");
+        let (rest, osd) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(osd.heading, None);
+        assert_eq!(osd.status, Status::Invalid);
+        assert_eq!(osd.course_reference, None);
+        assert_eq!(osd.speed, Some(5.5));
+        assert_eq!(osd.speed_reference, Some(MotionReference::Positioning));
+        assert_eq!(osd.set, None);
+        assert_eq!(osd.units, Some(DistanceUnit::Kilometers));
+
+        for input in [
+            "035.5,X,036.1,B,10.2,W,120.0,0.8,N",
+            "035.5,,036.1,B,10.2,W,120.0,0.8,N",
+            "035.5,A,036.1,X,10.2,W,120.0,0.8,N",
+            "035.5,A,036.1,B,10.2,W,120.0,0.8,M",
+        ] {
+            let result: IResult<_, _> = OSD::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
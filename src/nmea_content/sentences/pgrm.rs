@@ -0,0 +1,171 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{FixMode, encode::write_with_unit, parse::with_unit},
+};
+
+/// Maximum length of a map datum name reported by [`PGRMM`].
+pub const MAP_DATUM_CAPACITY: usize = 32;
+
+/// PGRME - Garmin Estimated Error Information
+///
+/// ```text
+///         1   2 3   4 5   6
+///         |   | |   | |   |
+///  $PGRME,x.x,M,x.x,M,x.x,M*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PGRME {
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
+    /// Estimated horizontal position error in meters
+    pub horizontal_error: Option<f32>,
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
+    /// Estimated vertical position error in meters
+    pub vertical_error: Option<f32>,
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
+    /// Estimated spherical position error in meters
+    pub spherical_error: Option<f32>,
+}
+
+/// PGRMM - Garmin Map Datum
+///
+/// ```text
+///         1
+///         |
+///  $PGRMM,c--c*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PGRMM {
+    /// Name of the map datum in use, such as `WGS 84`
+    pub datum: Option<heapless::String<MAP_DATUM_CAPACITY>>,
+}
+
+/// PGRMO - Garmin Output Sentence Enable/Disable
+///
+/// ```text
+///         1    2
+///         |    |
+///  $PGRMO,c--c,x*hh<CR><LF>
+/// ```
+///
+/// Sentences are enabled or disabled on the receiver by sending it a PGRMO command:
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     Nmea0183WriterBuilder, NmeaEncode,
+///     nmea_content::{OutputMode, PGRMO, ProprietarySentence},
+/// };
+///
+/// let mut writer = Nmea0183WriterBuilder::new().build(ProprietarySentence::encode);
+///
+/// let pgrmo = PGRMO {
+///     sentence: Some("GPGSV".try_into().unwrap()),
+///     mode: OutputMode::Disable,
+/// };
+///
+/// let mut output = String::new();
+/// writer(&mut output, &ProprietarySentence::PGRMO(pgrmo)).unwrap();
+/// assert_eq!(output, "$PGRMO,GPGSV,0*22\r\n");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PGRMO {
+    /// Address of the target sentence, such as `GPGSV`, empty for the modes applying to all
+    /// sentences
+    pub sentence: Option<heapless::String<5>>,
+    /// Output mode to apply
+    pub mode: OutputMode,
+}
+
+/// Output mode of [`PGRMO`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("01234")))]
+pub enum OutputMode {
+    #[nmea(selector('0'))]
+    /// 0 - Disable the target sentence
+    Disable,
+    #[default]
+    #[nmea(selector('1'))]
+    /// 1 - Enable the target sentence
+    Enable,
+    #[nmea(selector('2'))]
+    /// 2 - Disable all output sentences
+    DisableAll,
+    #[nmea(selector('3'))]
+    /// 3 - Enable all output sentences
+    EnableAll,
+    #[nmea(selector('4'))]
+    /// 4 - Restore the factory default output sentences
+    RestoreDefaults,
+}
+
+/// PGRMZ - Garmin Altitude
+///
+/// ```text
+///         1   2 3
+///         |   | |
+///  $PGRMZ,x.x,f,x*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PGRMZ {
+    #[nmea(parser(with_unit('f')), writer(write_with_unit('f')))]
+    /// Altitude in feet, as sent by the receiver
+    pub altitude_feet: Option<f32>,
+    /// Position fix dimension
+    pub fix_mode: Option<FixMode>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_pgrm_parsing() {
+        let result: IResult<_, _> = PGRME::parse("15.0,M,45.0,M,25.0,M");
+        let (rest, pgrme) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(pgrme.horizontal_error, Some(15.0));
+        assert_eq!(pgrme.spherical_error, Some(25.0));
+
+        let result: IResult<_, _> = PGRMM::parse("WGS 84");
+        let (rest, pgrmm) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(pgrmm.datum.as_deref(), Some("WGS 84"));
+
+        let result: IResult<_, _> = PGRMO::parse(",2".as_bytes());
+        let (rest, pgrmo) = result.unwrap();
+        assert_eq!(rest, b"");
+        assert_eq!(pgrmo.sentence, None);
+        assert_eq!(pgrmo.mode, OutputMode::DisableAll);
+
+        let result: IResult<_, _> = PGRMZ::parse("1000,f,3");
+        let (rest, pgrmz) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(pgrmz.altitude_feet, Some(1000.0));
+        assert_eq!(pgrmz.fix_mode, Some(FixMode::Fix3D));
+
+        let result: IResult<_, PGRME> = PGRME::parse("15.0,M,45.0,M");
+        assert!(result.is_err(), "{result:?}");
+        let result: IResult<_, PGRMO> = PGRMO::parse("GPGGA,5");
+        assert!(result.is_err(), "{result:?}");
+        let result: IResult<_, PGRMZ> = PGRMZ::parse("246,M,3");
+        assert!(result.is_err(), "{result:?}");
+    }
+}
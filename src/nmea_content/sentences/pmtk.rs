@@ -0,0 +1,121 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse};
+
+/// PMTK001 - MediaTek Command Acknowledgement
+///
+/// ```text
+///           1   2
+///           |   |
+///  $PMTK001,xxx,x*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PMTK001 {
+    /// Type of the acknowledged command, such as `220` for [`PMTK220`]
+    pub command: u16,
+    /// Result of the command
+    pub flag: AckFlag,
+}
+
+/// Result of a command acknowledged by [`PMTK001`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("0123")))]
+pub enum AckFlag {
+    #[default]
+    #[nmea(selector('0'))]
+    /// 0 - Invalid command
+    InvalidCommand,
+    #[nmea(selector('1'))]
+    /// 1 - Unsupported command
+    UnsupportedCommand,
+    #[nmea(selector('2'))]
+    /// 2 - Valid command, but action failed
+    Failed,
+    #[nmea(selector('3'))]
+    /// 3 - Valid command, and action succeeded
+    Succeeded,
+}
+
+/// PMTK220 - MediaTek Set Position Fix Interval
+///
+/// ```text
+///           1
+///           |
+///  $PMTK220,x*hh<CR><LF>
+/// ```
+///
+/// Configuration commands are written through [`ProprietarySentence`], and acknowledged by the
+/// receiver with a [`PMTK001`] sentence:
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     Nmea0183WriterBuilder, NmeaEncode,
+///     nmea_content::{PMTK220, ProprietarySentence},
+/// };
+///
+/// let mut writer = Nmea0183WriterBuilder::new().build(ProprietarySentence::encode);
+///
+/// let pmtk220 = PMTK220 { fix_interval: 1000 };
+///
+/// let mut output = String::new();
+/// writer(&mut output, &ProprietarySentence::PMTK220(pmtk220)).unwrap();
+/// assert_eq!(output, "$PMTK220,1000*1F\r\n");
+/// ```
+///
+/// [`ProprietarySentence`]: crate::nmea_content::ProprietarySentence
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PMTK220 {
+    /// Interval between two position fixes in milliseconds
+    pub fix_interval: u32,
+}
+
+/// PMTK251 - MediaTek Set Baud Rate
+///
+/// ```text
+///           1
+///           |
+///  $PMTK251,x*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PMTK251 {
+    /// Baud rate of the serial port, `0` restoring the default one
+    pub baud_rate: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_pmtk_parsing() {
+        let result: IResult<_, _> = PMTK001::parse("220,3");
+        let (rest, pmtk001) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(pmtk001.command, 220);
+        assert_eq!(pmtk001.flag, AckFlag::Succeeded);
+
+        let result: IResult<_, _> = PMTK251::parse("38400".as_bytes());
+        let (rest, pmtk251) = result.unwrap();
+        assert_eq!(rest, b"");
+        assert_eq!(pmtk251.baud_rate, 38400);
+
+        for input in ["220", "220,4", ",3"] {
+            let result: IResult<_, PMTK001> = PMTK001::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
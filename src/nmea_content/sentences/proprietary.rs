@@ -0,0 +1,260 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "garmin")]
+use super::pgrm::{PGRME, PGRMM, PGRMO, PGRMZ};
+#[cfg(feature = "mediatek")]
+use super::pmtk::{PMTK001, PMTK220, PMTK251};
+#[cfg(feature = "sirf")]
+use super::psrf::{PSRF100, PSRF103};
+#[cfg(feature = "ublox")]
+use super::pubx::PUBX;
+use crate::{self as nmea0183_parser, Error, NmeaEncode, NmeaParse, parse::text_field};
+
+/// Proprietary NMEA 0183 sentence, dispatched on the manufacturer code of its address field.
+///
+/// The address field of proprietary sentences starts with `P`, followed by a three-character
+/// manufacturer code and a sentence type of any length, instead of a talker ID. Each
+/// manufacturer is enabled by its own feature flag:
+///
+/// | Manufacturer | Feature Flag | Sentences                                     |
+/// | ------------ | ------------ | --------------------------------------------- |
+/// | Garmin       | `garmin`     | `PGRME`, `PGRMM`, `PGRMO`, `PGRMZ`            |
+/// | MediaTek     | `mediatek`   | `PMTK001`, `PMTK220`, `PMTK251`               |
+/// | SiRF         | `sirf`       | `PSRF100`, `PSRF103`                          |
+/// | u-blox       | `ublox`      | `PUBX,00`, `PUBX,03`, `PUBX,04`, `PUBX,40`, `PUBX,41` |
+///
+/// Other sentences fail with [`Error::UnrecognizedMessage`], and are kept as
+/// [`AnySentence::Unknown`] when parsed through [`AnySentence`].
+///
+/// ```rust
+/// # #[cfg(feature = "ublox")] {
+/// use nmea0183_parser::{
+///     IResult, Nmea0183ParserBuilder, NmeaParse,
+///     nmea_content::{ProprietarySentence, PUBX, PubxNavStatus},
+/// };
+/// use nom::Parser;
+///
+/// let mut parser = Nmea0183ParserBuilder::new().build(ProprietarySentence::parse);
+///
+/// let result: IResult<_, _> = parser.parse(
+///     "$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F\r\n",
+/// );
+/// let Ok((_, ProprietarySentence::PUBX(PUBX::Position(position)))) = result else {
+///     panic!("Expected a PUBX position");
+/// };
+/// assert_eq!(position.nav_status, PubxNavStatus::StandAlone3D);
+/// assert_eq!(position.satellites_used, Some(9));
+/// # }
+/// ```
+///
+/// [`AnySentence`]: crate::nmea_content::AnySentence
+/// [`AnySentence::Unknown`]: crate::nmea_content::AnySentence::Unknown
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(pre_exec(let msg = nmea_input;))]
+#[nmea(selector(text_field))]
+#[nmea(selection_error(Error::UnrecognizedMessage(msg)))]
+#[nmea(exact)]
+// Boxing `PUBX` would cost an allocation for every u-blox sentence
+#[allow(clippy::large_enum_variant)]
+pub enum ProprietarySentence {
+    #[cfg(feature = "garmin")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "garmin")))]
+    #[nmea(selector("PGRME"))]
+    /// PGRME - Garmin Estimated Error Information
+    PGRME(PGRME),
+    #[cfg(feature = "garmin")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "garmin")))]
+    #[nmea(selector("PGRMM"))]
+    /// PGRMM - Garmin Map Datum
+    PGRMM(PGRMM),
+    #[cfg(feature = "garmin")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "garmin")))]
+    #[nmea(selector("PGRMO"))]
+    /// PGRMO - Garmin Output Sentence Enable/Disable
+    PGRMO(PGRMO),
+    #[cfg(feature = "garmin")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "garmin")))]
+    #[nmea(selector("PGRMZ"))]
+    /// PGRMZ - Garmin Altitude
+    PGRMZ(PGRMZ),
+    #[cfg(feature = "mediatek")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mediatek")))]
+    #[nmea(selector("PMTK001"))]
+    /// PMTK001 - MediaTek Command Acknowledgement
+    PMTK001(PMTK001),
+    #[cfg(feature = "mediatek")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mediatek")))]
+    #[nmea(selector("PMTK220"))]
+    /// PMTK220 - MediaTek Set Position Fix Interval
+    PMTK220(PMTK220),
+    #[cfg(feature = "mediatek")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mediatek")))]
+    #[nmea(selector("PMTK251"))]
+    /// PMTK251 - MediaTek Set Baud Rate
+    PMTK251(PMTK251),
+    #[cfg(feature = "sirf")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sirf")))]
+    #[nmea(selector("PSRF100"))]
+    /// PSRF100 - SiRF Set Serial Port
+    PSRF100(PSRF100),
+    #[cfg(feature = "sirf")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sirf")))]
+    #[nmea(selector("PSRF103"))]
+    /// PSRF103 - SiRF Query/Rate Control
+    PSRF103(PSRF103),
+    #[cfg(feature = "ublox")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ublox")))]
+    #[nmea(selector("PUBX"))]
+    /// PUBX - u-blox proprietary messages
+    PUBX(PUBX),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_proprietary_sentence_parsing() {
+        for input in [
+            "PFEC,GPatt,125.3",
+            "PSRF150,1",
+            "PMTK705,AXN_2.31",
+            "PSRF",
+            "PGRM",
+        ] {
+            let result: IResult<_, _> = ProprietarySentence::parse(input);
+            assert!(
+                matches!(result, Err(nom::Err::Error(Error::UnrecognizedMessage(_)))),
+                "Failed: {input:?}\n\t{result:?}"
+            );
+        }
+
+        #[cfg(feature = "garmin")]
+        {
+            let result: IResult<_, _> = ProprietarySentence::parse("PGRMZ,1000,f,3");
+            assert!(
+                matches!(
+                    result,
+                    Ok(("", ProprietarySentence::PGRMZ(PGRMZ { altitude_feet: Some(altitude), .. })))
+                        if altitude == 1000.0
+                ),
+                "{result:?}"
+            );
+        }
+
+        #[cfg(feature = "mediatek")]
+        {
+            let result: IResult<_, _> = ProprietarySentence::parse("PMTK001,220,3");
+            assert!(
+                matches!(
+                    result,
+                    Ok((
+                        "",
+                        ProprietarySentence::PMTK001(PMTK001 { command: 220, .. })
+                    ))
+                ),
+                "{result:?}"
+            );
+        }
+
+        #[cfg(feature = "sirf")]
+        {
+            let result: IResult<_, _> = ProprietarySentence::parse("PSRF100,1,38400,8,1,0");
+            assert!(
+                matches!(
+                    result,
+                    Ok((
+                        "",
+                        ProprietarySentence::PSRF100(PSRF100 {
+                            baud_rate: 38400,
+                            ..
+                        })
+                    ))
+                ),
+                "{result:?}"
+            );
+        }
+
+        #[cfg(feature = "ublox")]
+        {
+            let result: IResult<_, _> = ProprietarySentence::parse("PUBX,40,GLL,1,0,0,0,0,0");
+            assert!(
+                matches!(
+                    result,
+                    Ok(("", ProprietarySentence::PUBX(PUBX::Rate(ref rate)))) if rate.ddc == 1
+                ),
+                "{result:?}"
+            );
+        }
+
+        let cases: &[&str] = &[
+            #[cfg(feature = "garmin")]
+            "PGRME,15.0,M,45.0",
+            #[cfg(feature = "garmin")]
+            "PGRMO,GPGGA,5",
+            #[cfg(feature = "mediatek")]
+            "PMTK220",
+            #[cfg(feature = "mediatek")]
+            "PMTK251,9600,1",
+            #[cfg(feature = "sirf")]
+            "PSRF103,00,00,01",
+            #[cfg(feature = "ublox")]
+            "PUBX,05,1",
+            #[cfg(feature = "ublox")]
+            "PUBX,40,GLL,1",
+            #[cfg(feature = "ublox")]
+            "PUBX,41,1,0007,0003,19200,0,1",
+        ];
+
+        for &input in cases {
+            let result: IResult<_, ProprietarySentence> = ProprietarySentence::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+
+    #[test]
+    #[cfg(any(
+        feature = "garmin",
+        feature = "mediatek",
+        feature = "sirf",
+        feature = "ublox"
+    ))]
+    fn test_proprietary_sentence_encoding() {
+        use crate::Encoder;
+
+        let cases: &[&str] = &[
+            #[cfg(feature = "garmin")]
+            "PGRME,15,M,45,M,25,M",
+            #[cfg(feature = "garmin")]
+            "PGRMM,WGS 84",
+            #[cfg(feature = "garmin")]
+            "PGRMO,,4",
+            #[cfg(feature = "garmin")]
+            "PGRMZ,1000,f,3",
+            #[cfg(feature = "mediatek")]
+            "PMTK001,604,3",
+            #[cfg(feature = "mediatek")]
+            "PMTK220,200",
+            #[cfg(feature = "mediatek")]
+            "PMTK251,115200",
+            #[cfg(feature = "sirf")]
+            "PSRF100,0,57600,8,1,0",
+            #[cfg(feature = "sirf")]
+            "PSRF103,05,01,00,00",
+            #[cfg(feature = "ublox")]
+            "PUBX,40,VTG,0,1,0,0,0,0",
+        ];
+
+        for &input in cases {
+            let result: IResult<_, ProprietarySentence> = ProprietarySentence::parse(input);
+            let (_, sentence) = result.unwrap();
+            let mut output = String::new();
+            sentence.encode(&mut Encoder::new(&mut output)).unwrap();
+            assert_eq!(output, input);
+        }
+    }
+}
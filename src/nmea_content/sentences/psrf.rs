@@ -0,0 +1,210 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+
+use crate::{self as nmea0183_parser, Encoder, NmeaEncode, NmeaParse, nmea_content::parse::code};
+
+/// PSRF100 - SiRF Set Serial Port
+///
+/// ```text
+///           1 2 3 4 5
+///           | | | | |
+///  $PSRF100,x,x,x,x,x*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PSRF100 {
+    /// Protocol of the serial port
+    pub protocol: SerialProtocol,
+    /// Baud rate
+    pub baud_rate: u32,
+    /// Number of data bits, 7 or 8
+    pub data_bits: u8,
+    /// Number of stop bits, 0 or 1
+    pub stop_bits: u8,
+    /// Parity
+    pub parity: Parity,
+}
+
+impl Default for PSRF100 {
+    /// NMEA at 4800 baud, with 8 data bits, 1 stop bit and no parity.
+    fn default() -> Self {
+        Self {
+            protocol: SerialProtocol::Nmea,
+            baud_rate: 4800,
+            data_bits: 8,
+            stop_bits: 1,
+            parity: Parity::None,
+        }
+    }
+}
+
+/// Protocol of the serial port set by [`PSRF100`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("01")))]
+pub enum SerialProtocol {
+    #[nmea(selector('0'))]
+    /// 0 - SiRF binary
+    Binary,
+    #[default]
+    #[nmea(selector('1'))]
+    /// 1 - NMEA
+    Nmea,
+}
+
+/// Parity of the serial port set by [`PSRF100`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("012")))]
+pub enum Parity {
+    #[default]
+    #[nmea(selector('0'))]
+    /// 0 - None
+    None,
+    #[nmea(selector('1'))]
+    /// 1 - Odd
+    Odd,
+    #[nmea(selector('2'))]
+    /// 2 - Even
+    Even,
+}
+
+/// PSRF103 - SiRF Query/Rate Control
+///
+/// ```text
+///           1  2  3  4
+///           |  |  |  |
+///  $PSRF103,xx,xx,xx,xx*hh<CR><LF>
+/// ```
+///
+/// Output rates are configured by sending a PSRF103 command to the receiver:
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     Nmea0183WriterBuilder, NmeaEncode,
+///     nmea_content::{PSRF103, ProprietarySentence, RateMode, SirfMessage},
+/// };
+///
+/// let mut writer = Nmea0183WriterBuilder::new().build(ProprietarySentence::encode);
+///
+/// let psrf103 = PSRF103 {
+///     message: SirfMessage::GSV,
+///     mode: RateMode::SetRate,
+///     rate: 5,
+///     checksum: true,
+/// };
+///
+/// let mut output = String::new();
+/// writer(&mut output, &ProprietarySentence::PSRF103(psrf103)).unwrap();
+/// assert_eq!(output, "$PSRF103,03,00,05,01*22\r\n");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PSRF103 {
+    /// Message to query or whose rate to set
+    pub message: SirfMessage,
+    /// Query or set the output rate
+    pub mode: RateMode,
+    #[nmea(writer(write_two_digits))]
+    /// Output rate in seconds, `0` disabling the message
+    pub rate: u8,
+    #[nmea(map(|checksum: u8| checksum != 0), parse_as(u8), writer(write_checksum))]
+    /// Whether the message is output with a checksum
+    pub checksum: bool,
+}
+
+/// Message of [`PSRF103`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(code(2)))]
+pub enum SirfMessage {
+    #[default]
+    #[nmea(selector("00"))]
+    /// 00 - GGA
+    GGA,
+    #[nmea(selector("01"))]
+    /// 01 - GLL
+    GLL,
+    #[nmea(selector("02"))]
+    /// 02 - GSA
+    GSA,
+    #[nmea(selector("03"))]
+    /// 03 - GSV
+    GSV,
+    #[nmea(selector("04"))]
+    /// 04 - RMC
+    RMC,
+    #[nmea(selector("05"))]
+    /// 05 - VTG
+    VTG,
+    #[nmea(selector("06"))]
+    /// 06 - MSS
+    MSS,
+    #[nmea(selector("08"))]
+    /// 08 - ZDA
+    ZDA,
+}
+
+/// Mode of [`PSRF103`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(code(2)))]
+pub enum RateMode {
+    #[default]
+    #[nmea(selector("00"))]
+    /// 00 - Set the output rate
+    SetRate,
+    #[nmea(selector("01"))]
+    /// 01 - Query the message once
+    Query,
+}
+
+fn write_two_digits(value: &u8, e: &mut Encoder<'_>) -> fmt::Result {
+    write!(e, "{value:02}")
+}
+
+fn write_checksum(checksum: &bool, e: &mut Encoder<'_>) -> fmt::Result {
+    e.write_str(if *checksum { "01" } else { "00" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_psrf_parsing() {
+        let result: IResult<_, _> = PSRF100::parse("1,9600,8,1,0");
+        let (rest, psrf100) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            psrf100,
+            PSRF100 {
+                baud_rate: 9600,
+                ..Default::default()
+            }
+        );
+
+        let result: IResult<_, _> = PSRF103::parse("08,01,00,01".as_bytes());
+        let (rest, psrf103) = result.unwrap();
+        assert_eq!(rest, b"");
+        assert_eq!(psrf103.message, SirfMessage::ZDA);
+        assert_eq!(psrf103.mode, RateMode::Query);
+        assert!(psrf103.checksum);
+
+        for input in ["07,00,01,01", "00,02,01,01", "00,00,01"] {
+            let result: IResult<_, PSRF103> = PSRF103::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,489 @@
+use nom::{
+    AsChar, Compare, Input, Parser,
+    character::complete::{char, one_of},
+    combinator::opt,
+    error::ParseError,
+    number::complete::hex_u32,
+    sequence::terminated,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+
+use crate::{
+    self as nmea0183_parser, Encoder, Error, IResult, NmeaEncode, NmeaParse,
+    nmea_content::{
+        Location,
+        encode::write_location,
+        parse::{code, location},
+    },
+};
+
+/// Maximum number of satellites listed by a single [`PubxSatellites`] sentence.
+pub const PUBX_SATELLITES_CAPACITY: usize = 32;
+
+/// PUBX - u-blox proprietary messages
+///
+/// The message is selected by the two-digit message ID following the `PUBX` address, as
+/// described in the u-blox receiver protocol specifications.
+///
+/// ```text
+///         1  2
+///         |  |
+///  $PUBX,xx,c--c*hh<CR><LF>
+/// ```
+///
+/// The `40` and `41` configuration commands are encoded through [`ProprietarySentence`],
+/// whose address is written by the encoder:
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     Nmea0183WriterBuilder, NmeaEncode,
+///     nmea_content::{ProprietarySentence, PUBX, PubxConfig, PubxRate},
+/// };
+///
+/// let mut writer = Nmea0183WriterBuilder::new().build(ProprietarySentence::encode);
+///
+/// let rate = PubxRate {
+///     sentence_type: "GSV".try_into().unwrap(),
+///     usart1: 0,
+///     ..Default::default()
+/// };
+///
+/// let mut output = String::new();
+/// writer(&mut output, &ProprietarySentence::PUBX(PUBX::Rate(rate))).unwrap();
+/// assert_eq!(output, "$PUBX,40,GSV,0,0,0,0,0,0*59\r\n");
+///
+/// let config = PubxConfig {
+///     port: 1,
+///     in_protocols: 0x0007,
+///     out_protocols: 0x0003,
+///     baud_rate: 19200,
+///     autobauding: false,
+/// };
+///
+/// let mut output = String::new();
+/// writer(&mut output, &ProprietarySentence::PUBX(PUBX::Config(config))).unwrap();
+/// assert_eq!(output, "$PUBX,41,1,0007,0003,19200,0*25\r\n");
+/// ```
+///
+/// [`ProprietarySentence`]: crate::nmea_content::ProprietarySentence
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(pre_exec(let msg = nmea_input;))]
+#[nmea(selector(code(2)))]
+#[nmea(selection_error(Error::UnrecognizedMessage(msg)))]
+// Boxing `Satellites` would cost an allocation for every satellite status message
+#[allow(clippy::large_enum_variant)]
+pub enum PUBX {
+    #[nmea(selector("00"))]
+    /// 00 - Lat/Long Position Data
+    Position(PubxPosition),
+    #[nmea(selector("03"))]
+    /// 03 - Satellite Status
+    Satellites(PubxSatellites),
+    #[nmea(selector("04"))]
+    /// 04 - Time of Day and Clock Information
+    Time(PubxTime),
+    #[nmea(selector("40"))]
+    /// 40 - Set NMEA message output rate
+    Rate(PubxRate),
+    #[nmea(selector("41"))]
+    /// 41 - Set Protocols and Baudrate
+    Config(PubxConfig),
+}
+
+/// PUBX,00 - Lat/Long Position Data
+///
+/// ```text
+///           1         2       3 4        5 6   7  8   9   10  11  12  13  14  15  16  171819
+///           |         |       | |        | |   |  |   |   |   |   |   |   |   |   |   | | |
+///  $PUBX,00,hhmmss.ss,ddmm.mm,a,dddmm.mm,a,x.x,cc,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x,x,x*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PubxPosition {
+    /// Fix time in UTC
+    pub fix_time: Option<time::Time>,
+    #[nmea(parser(location), writer(write_location))]
+    /// Location (latitude and longitude)
+    pub location: Option<Location>,
+    /// Altitude above the user datum ellipsoid in meters
+    pub altitude: Option<f32>,
+    /// Navigation status
+    pub nav_status: PubxNavStatus,
+    /// Horizontal accuracy estimate in meters
+    pub horizontal_accuracy: Option<f32>,
+    /// Vertical accuracy estimate in meters
+    pub vertical_accuracy: Option<f32>,
+    #[nmea(map(|kph: Option<f32>| kph.map(|kph| kph / 1.852)), writer(write_kph))]
+    /// Speed over ground in knots, sent in km/h
+    pub speed_over_ground: Option<f32>,
+    /// Course over ground in degrees
+    pub course_over_ground: Option<f32>,
+    /// Vertical velocity in meters per second, positive downwards
+    pub vertical_velocity: Option<f32>,
+    /// Age of the differential corrections in seconds
+    pub differential_age: Option<f32>,
+    /// Horizontal Dilution of Precision
+    pub hdop: Option<f32>,
+    /// Vertical Dilution of Precision
+    pub vdop: Option<f32>,
+    /// Time Dilution of Precision
+    pub tdop: Option<f32>,
+    /// Number of satellites used in the navigation solution
+    pub satellites_used: Option<u8>,
+    /// Reserved, `0` in current firmware
+    pub reserved: Option<u8>,
+    /// Whether dead reckoning is used
+    pub dead_reckoning: Option<u8>,
+}
+
+/// Navigation status of [`PubxPosition`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(code(2)))]
+pub enum PubxNavStatus {
+    #[default]
+    #[nmea(selector("NF"))]
+    /// NF - No fix
+    NoFix,
+    #[nmea(selector("DR"))]
+    /// DR - Dead reckoning only solution
+    DeadReckoning,
+    #[nmea(selector("G2"))]
+    /// G2 - Stand alone 2D solution
+    StandAlone2D,
+    #[nmea(selector("G3"))]
+    /// G3 - Stand alone 3D solution
+    StandAlone3D,
+    #[nmea(selector("D2"))]
+    /// D2 - Differential 2D solution
+    Differential2D,
+    #[nmea(selector("D3"))]
+    /// D3 - Differential 3D solution
+    Differential3D,
+    #[nmea(selector("RK"))]
+    /// RK - Combined GNSS and dead reckoning solution
+    Combined,
+    #[nmea(selector("TT"))]
+    /// TT - Time only solution
+    TimeOnly,
+}
+
+/// PUBX,03 - Satellite Status
+///
+/// Fields 2 to 7 are repeated for each tracked satellite.
+///
+/// ```text
+///           1  2  3 4   5  6  7
+///           |  |  | |   |  |  |
+///  $PUBX,03,xx,xx,a,xxx,xx,xx,xxx,...*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PubxSatellites {
+    /// Number of satellites tracked
+    pub satellite_count: u8,
+    /// Status of the tracked satellites
+    pub satellites: heapless::Vec<PubxSatellite, PUBX_SATELLITES_CAPACITY>,
+}
+
+/// Satellite tracked by [`PubxSatellites`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PubxSatellite {
+    /// Satellite ID
+    pub prn: u16,
+    /// Usage of the satellite in the navigation solution
+    pub status: PubxSatelliteStatus,
+    /// Azimuth in degrees (0-359)
+    pub azimuth: Option<u16>,
+    /// Elevation in degrees (0-90)
+    pub elevation: Option<u8>,
+    /// Signal strength (C/N0) in dBHz
+    pub snr: Option<u8>,
+    /// Satellite carrier lock time in seconds, saturating at 64
+    pub lock_time: Option<u8>,
+}
+
+/// Usage of a [`PubxSatellite`] in the navigation solution
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("-Ue")))]
+pub enum PubxSatelliteStatus {
+    #[default]
+    #[nmea(selector('-'))]
+    /// \- - Not used
+    NotUsed,
+    #[nmea(selector('U'))]
+    /// U - Used in the navigation solution
+    Used,
+    #[nmea(selector('e'))]
+    /// e - Ephemeris available, but not used for navigation
+    EphemerisAvailable,
+}
+
+/// PUBX,04 - Time of Day and Clock Information
+///
+/// The last field is followed by an empty reserved field.
+///
+/// ```text
+///           1         2      3   4    5   6 7   8
+///           |         |      |   |    |   | |   |
+///  $PUBX,04,hhmmss.ss,ddmmyy,x.x,xxxx,xxa,x,x.x,x,*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PubxTime {
+    /// Time in UTC
+    pub time: Option<time::Time>,
+    /// Date in UTC
+    pub date: Option<time::Date>,
+    /// UTC time of week in seconds
+    pub time_of_week: Option<f64>,
+    /// UTC week number, continuing beyond 1023
+    pub week: Option<u16>,
+    #[nmea(parser(leap_seconds), writer(write_leap_seconds))]
+    /// Leap seconds between GPS time and UTC
+    pub leap_seconds: Option<LeapSeconds>,
+    /// Receiver clock bias in nanoseconds
+    pub clock_bias: Option<i64>,
+    /// Receiver clock drift in nanoseconds per second
+    pub clock_drift: Option<f32>,
+    #[nmea(parser(terminated(<Option<u32>>::parse, opt(char(',')))))]
+    #[nmea(writer(write_time_pulse_granularity))]
+    /// Time pulse granularity in nanoseconds
+    pub time_pulse_granularity: Option<u32>,
+}
+
+/// Leap seconds reported by [`PubxTime`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LeapSeconds {
+    /// Number of leap seconds
+    pub seconds: u8,
+    /// Whether the value is the firmware default, flagged with `D`, rather than the one
+    /// broadcast by the satellites
+    pub firmware_default: bool,
+}
+
+/// PUBX,40 - Set NMEA message output rate
+///
+/// The rates are the number of navigation solutions between two outputs of the message on
+/// each port, `0` disabling it.
+///
+/// ```text
+///           1   2 3 4 5 6 7
+///           |   | | | | | |
+///  $PUBX,40,ccc,x,x,x,x,x,x*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PubxRate {
+    /// Sentence type of the NMEA message, such as `GSV`
+    pub sentence_type: heapless::String<3>,
+    /// Output rate on the DDC (I2C) port
+    pub ddc: u8,
+    /// Output rate on the USART 1 port
+    pub usart1: u8,
+    /// Output rate on the USART 2 port
+    pub usart2: u8,
+    /// Output rate on the USB port
+    pub usb: u8,
+    /// Output rate on the SPI port
+    pub spi: u8,
+    /// Reserved, `0` in current firmware
+    pub reserved: u8,
+}
+
+/// PUBX,41 - Set Protocols and Baudrate
+///
+/// The protocol masks combine `0x0001` for UBX, `0x0002` for NMEA, `0x0004` for RTCM 2 and
+/// `0x0020` for RTCM 3.
+///
+/// ```text
+///           1 2    3    4 5
+///           | |    |    | |
+///  $PUBX,41,x,hhhh,hhhh,x,x*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct PubxConfig {
+    /// Port identifier: 0 for DDC (I2C), 1 for USART 1, 2 for USART 2, 3 for USB, 4 for SPI
+    pub port: u8,
+    #[nmea(parser(hex_u32.map_opt(|mask| u16::try_from(mask).ok())), writer(write_protocols))]
+    /// Input protocol mask
+    pub in_protocols: u16,
+    #[nmea(parser(hex_u32.map_opt(|mask| u16::try_from(mask).ok())), writer(write_protocols))]
+    /// Output protocol mask
+    pub out_protocols: u16,
+    /// Baud rate
+    pub baud_rate: u32,
+    #[nmea(parser(one_of("01").map(|flag| flag == '1')), writer(write_autobauding))]
+    /// Whether autobauding is enabled
+    pub autobauding: bool,
+}
+
+fn write_kph(knots: &Option<f32>, e: &mut Encoder<'_>) -> fmt::Result {
+    knots.map(|knots| knots * 1.852).encode(e)
+}
+
+fn leap_seconds<I, E>(i: I) -> IResult<I, Option<LeapSeconds>, E>
+where
+    I: Input + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    opt((u8::parse, opt(char('D'))))
+        .map(|leap_seconds| {
+            leap_seconds.map(|(seconds, flag)| LeapSeconds {
+                seconds,
+                firmware_default: flag.is_some(),
+            })
+        })
+        .parse(i)
+}
+
+fn write_leap_seconds(leap_seconds: &Option<LeapSeconds>, e: &mut Encoder<'_>) -> fmt::Result {
+    if let Some(leap_seconds) = leap_seconds {
+        leap_seconds.seconds.encode(e)?;
+        if leap_seconds.firmware_default {
+            e.write_char('D')?;
+        }
+    }
+    Ok(())
+}
+
+fn write_time_pulse_granularity(granularity: &Option<u32>, e: &mut Encoder<'_>) -> fmt::Result {
+    granularity.encode(e)?;
+    e.write_char(',')
+}
+
+fn write_protocols(mask: &u16, e: &mut Encoder<'_>) -> fmt::Result {
+    write!(e, "{mask:04X}")
+}
+
+fn write_autobauding(autobauding: &bool, e: &mut Encoder<'_>) -> fmt::Result {
+    e.write_char(if *autobauding { '1' } else { '0' })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pubx_parsing() {
+        let result: IResult<_, _> = PUBX::parse(
+            "00,081350.00,4717.11321,N,00833.91519,E,546.589,G3,2.1,2.0,1.852,77.52,0.007,,0.92,1.19,0.77,9,0,0",
+        );
+        let (rest, pubx) = result.unwrap();
+        assert_eq!(rest, "");
+        let PUBX::Position(position) = pubx else {
+            panic!("Expected a position: {pubx:?}");
+        };
+        assert_eq!(position.nav_status, PubxNavStatus::StandAlone3D);
+        assert_eq!(position.speed_over_ground, Some(1.0));
+        assert_eq!(position.differential_age, None);
+        assert_eq!(position.satellites_used, Some(9));
+
+        let result: IResult<_, _> =
+            PUBX::parse("03,3,23,-,,,45,010,08,U,067,31,42,025,10,e,195,33,46,026".as_bytes());
+        let (rest, pubx) = result.unwrap();
+        assert_eq!(rest, b"");
+        let PUBX::Satellites(satellites) = pubx else {
+            panic!("Expected satellites: {pubx:?}");
+        };
+        assert_eq!(satellites.satellite_count, 3);
+        assert_eq!(satellites.satellites.len(), 3);
+        assert_eq!(
+            satellites.satellites[0].status,
+            PubxSatelliteStatus::NotUsed
+        );
+        assert_eq!(satellites.satellites[0].azimuth, None);
+        assert_eq!(satellites.satellites[1].status, PubxSatelliteStatus::Used);
+        assert_eq!(satellites.satellites[1].lock_time, Some(25));
+
+        for input in [
+            "04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,",
+            "04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43",
+        ] {
+            let result: IResult<_, _> = PUBX::parse(input);
+            let (rest, pubx) = result.unwrap();
+            assert_eq!(rest, "");
+            let PUBX::Time(time) = pubx else {
+                panic!("Expected a time: {pubx:?}");
+            };
+            assert_eq!(time.week, Some(1196));
+            assert_eq!(
+                time.leap_seconds,
+                Some(LeapSeconds {
+                    seconds: 15,
+                    firmware_default: true
+                })
+            );
+            assert_eq!(time.clock_bias, Some(1930035));
+            assert_eq!(time.time_pulse_granularity, Some(43));
+        }
+
+        let result: IResult<_, _> = PUBX::parse("41,1,0007,0023,115200,1");
+        let (rest, pubx) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            pubx,
+            PUBX::Config(PubxConfig {
+                port: 1,
+                in_protocols: 0x0007,
+                out_protocols: 0x0023,
+                baud_rate: 115200,
+                autobauding: true,
+            })
+        );
+
+        for input in ["41,1,10007,0023,115200,1", "41,1,0007,FFFF0023,115200,1"] {
+            let result: IResult<_, _> = PUBX::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+
+        let result: IResult<_, _> = PUBX::parse("05,1");
+        assert!(
+            matches!(result, Err(nom::Err::Error(Error::UnrecognizedMessage(_)))),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn test_pubx_encoding() {
+        let cases = [
+            "00,081350.00,4717.11321,N,00833.91519,E,546.589,G3,2.1,2,1.852,77.52,0.007,,0.92,1.19,0.77,9,0,0",
+            "03,2,23,-,,,45,10,8,U,67,31,42,25",
+            "04,073731.00,091202,113851,1196,15D,1930035,-2660.664,43,",
+            "40,GLL,1,0,0,0,0,0",
+            "41,1,0007,0003,19200,0",
+        ];
+
+        for input in cases {
+            let result: IResult<_, PUBX> = PUBX::parse(input);
+            let (_, pubx) = result.unwrap();
+            let mut output = String::new();
+            pubx.encode(&mut Encoder::new(&mut output)).unwrap();
+            assert_eq!(output, input);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use nom::{
+    AsChar, Input, Parser,
+    bytes::complete::take,
+    character::complete::char,
+    error::{ErrorKind, ParseError},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+
+use crate::{Encoder, IResult, NmeaEncode, NmeaParse, nmea_content::TalkerId};
+
+/// Query sentence, a request from a listener for a sentence of a talker.
+///
+/// ```text
+///         1
+///         |
+///  $aaccQ,ccc*hh<CR><LF>
+/// ```
+///
+/// The address field is made of the talker ID of the requester (`aa`), the talker ID of the
+/// queried talker (`cc`) and `Q`, followed by the type of the requested sentence. Queries are
+/// parsed by [`AnySentence`], and written by a framing writer without talker ID:
+///
+/// ```rust
+/// use nmea0183_parser::{
+///     IResult, Nmea0183ParserBuilder, Nmea0183WriterBuilder, NmeaEncode, NmeaParse,
+///     nmea_content::{AnySentence, Query, TalkerId},
+/// };
+/// use nom::Parser;
+///
+/// let query = Query::new(TalkerId::Other(*b"CC"), TalkerId::Gps, "GGA").unwrap();
+///
+/// let mut writer = Nmea0183WriterBuilder::new().build(Query::encode);
+/// let mut output = String::new();
+/// writer(&mut output, &query).unwrap();
+/// assert_eq!(output, "$CCGPQ,GGA*2B\r\n");
+///
+/// let mut parser = Nmea0183ParserBuilder::new().build(AnySentence::parse);
+/// let result: IResult<_, _> = parser.parse(output.as_str());
+/// assert_eq!(result, Ok(("", AnySentence::Query(query))));
+/// ```
+///
+/// [`AnySentence`]: crate::nmea_content::AnySentence
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    /// Talker ID of the listener requesting the sentence
+    pub requester: TalkerId,
+    /// Talker ID of the talker queried for the sentence
+    pub target_talker: TalkerId,
+    /// Type of the requested sentence, such as `GGA`
+    pub sentence: heapless::String<3>,
+}
+
+impl Query {
+    /// Creates a query from `requester` for the `sentence` type of `target_talker`.
+    ///
+    /// Returns [`None`] if `sentence` is not a three-character alphanumeric sentence type.
+    pub fn new(requester: TalkerId, target_talker: TalkerId, sentence: &str) -> Option<Self> {
+        if sentence.len() != 3 || !sentence.bytes().all(|byte| byte.is_ascii_alphanumeric()) {
+            return None;
+        }
+
+        Some(Query {
+            requester,
+            target_talker,
+            sentence: sentence.try_into().ok()?,
+        })
+    }
+}
+
+impl<I, E> NmeaParse<I, E> for Query
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        let (i, requester) = TalkerId::parse(i)?;
+        let (i, target_talker) = TalkerId::parse(i)?;
+        let (i, _) = char('Q').parse(i)?;
+        let (i, _) = char(',').parse(i)?;
+        let (i, code) = take(3u8).parse(i)?;
+
+        let mut sentence = heapless::String::new();
+        for item in code.iter_elements() {
+            let c = item.as_char();
+            if !c.is_ascii_alphanumeric() || sentence.push(c).is_err() {
+                return Err(nom::Err::Error(nom::error::make_error(
+                    code,
+                    ErrorKind::AlphaNumeric,
+                )));
+            }
+        }
+
+        if i.input_len() != 0 {
+            return Err(nom::Err::Error(nom::error::make_error(i, ErrorKind::Eof)));
+        }
+
+        Ok((
+            i,
+            Query {
+                requester,
+                target_talker,
+                sentence,
+            },
+        ))
+    }
+}
+
+impl NmeaEncode for Query {
+    fn encode(&self, e: &mut Encoder<'_>) -> fmt::Result {
+        self.requester.encode(e)?;
+        self.target_talker.encode(e)?;
+        e.write_char('Q')?;
+        e.write_char(',')?;
+        e.write_str(&self.sentence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_parsing() {
+        let result: IResult<_, _> = Query::parse("CCGPQ,GGA");
+        let (rest, query) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(query.requester, TalkerId::Other(*b"CC"));
+        assert_eq!(query.target_talker, TalkerId::Gps);
+        assert_eq!(query.sentence, "GGA");
+
+        let result: IResult<_, _> = Query::parse("ECIIQ,HDT".as_bytes());
+        let (rest, query) = result.unwrap();
+        assert_eq!(rest, b"");
+        assert_eq!(query.target_talker, TalkerId::Other(*b"II"));
+        assert_eq!(query.sentence, "HDT");
+
+        for input in [
+            "CCGPQ",
+            "CCGPQ,GG",
+            "CCGPQ,GG,",
+            "CCGPQ,GGA,1",
+            "CCGPGGA",
+            "CC-PQ,GGA",
+        ] {
+            let result: IResult<_, Query> = Query::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+
+    #[test]
+    fn test_query_new() {
+        let query = Query::new(TalkerId::Other(*b"EC"), TalkerId::Gnss, "RMC").unwrap();
+        let mut output = String::new();
+        query.encode(&mut Encoder::new(&mut output)).unwrap();
+        assert_eq!(output, "ECGNQ,RMC");
+
+        for sentence in ["", "GG", "GGAA", "G,A"] {
+            assert_eq!(
+                Query::new(TalkerId::Gps, TalkerId::Gps, sentence),
+                None,
+                "Failed: {sentence:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::xte::{cross_track_error, write_cross_track_error};
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{
+        FaaMode, Location, Status, WaypointId, encode::write_location, parse::location,
+    },
+};
+
+/// RMB - Recommended Minimum Navigation Information
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_rmb_recommended_minimum_navigation_information>
+///
+/// ```text
+///         1 2   3 4    5    6       7 8        9 10  11  12  13
+///         | |   | |    |    |       | |        | |   |   |   |
+///  $--RMB,A,x.x,a,c--c,c--c,llll.ll,a,yyyyy.yy,a,x.x,x.x,x.x,A*hh<CR><LF>
+/// ```
+///
+/// NMEA 2.3:
+///
+/// ```text
+///         1 2   3 4    5    6       7 8        9 10  11  12  13 14
+///         | |   | |    |    |       | |        | |   |   |   |  |
+///  $--RMB,A,x.x,a,c--c,c--c,llll.ll,a,yyyyy.yy,a,x.x,x.x,x.x,A,m*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct RMB {
+    /// Status of the data
+    pub status: Status,
+    #[nmea(parser(cross_track_error), writer(write_cross_track_error))]
+    /// Cross-track error in nautical miles, negative values indicate to steer left
+    pub cross_track_error: Option<f32>,
+    /// Origin waypoint identifier
+    pub origin_waypoint_id: Option<WaypointId>,
+    /// Destination waypoint identifier
+    pub destination_waypoint_id: Option<WaypointId>,
+    #[nmea(parser(location), writer(write_location))]
+    /// Destination waypoint location (latitude and longitude)
+    pub destination_location: Option<Location>,
+    /// Range to destination in nautical miles
+    pub range_to_destination: Option<f32>,
+    /// Bearing to destination in degrees true
+    pub bearing_to_destination: Option<f32>,
+    /// Destination closing velocity in knots
+    pub closing_velocity: Option<f32>,
+    /// Arrival status
+    pub arrival_status: ArrivalStatus,
+    #[nmea(version(NmeaVersion::V2_3))]
+    /// FAA Mode Indicator
+    pub faa_mode: Option<FaaMode>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("AV")))]
+/// Arrival status reported by [`RMB`] and [`APB`](crate::nmea_content::APB)
+pub enum ArrivalStatus {
+    #[nmea(selector('A'))]
+    /// A - Arrived
+    Arrived,
+    #[default]
+    #[nmea(selector('V'))]
+    /// V - Not arrived
+    NotArrived,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_rmb_parsing() {
+        let input = "A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V,A";
+        let result: IResult<_, _> = RMB::parse(input);
+        let (rest, rmb) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(rmb.status, Status::Valid);
+        assert_eq!(rmb.cross_track_error, Some(-0.66));
+        assert_eq!(rmb.origin_waypoint_id.as_deref(), Some("003"));
+        assert_eq!(rmb.destination_waypoint_id.as_deref(), Some("004"));
+        assert!(rmb.destination_location.is_some());
+        assert_eq!(rmb.range_to_destination, Some(1.3));
+        assert_eq!(rmb.bearing_to_destination, Some(52.5));
+        assert_eq!(rmb.closing_velocity, Some(0.5));
+        assert_eq!(rmb.arrival_status, ArrivalStatus::NotArrived);
+        assert_eq!(rmb.faa_mode, Some(FaaMode::Autonomous));
+
+        let input = "V,,,,,,,,,,,,A";
+        let result: IResult<_, _> = RMB::parse(input);
+        let (rest, rmb) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(rmb.cross_track_error, None);
+        assert_eq!(rmb.arrival_status, ArrivalStatus::Arrived);
+        assert_eq!(rmb.faa_mode, None);
+    }
+}
@@ -1,5 +1,6 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
 
 use nom::{
     AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser,
@@ -10,13 +11,9 @@ use nom::{
     sequence::separated_pair,
 };
 
-#[cfg(feature = "nmea-v2-3")]
-use crate::nmea_content::FaaMode;
-#[cfg(feature = "nmea-v4-11")]
-use crate::nmea_content::NavStatus;
 use crate::{
-    self as nmea0183_parser, IResult, NmeaParse,
-    nmea_content::{Location, Status, parse::location},
+    self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{FaaMode, Location, NavStatus, Status, encode::write_location, parse::location},
 };
 
 /// RMC - Recommended Minimum Navigation Information
@@ -44,13 +41,14 @@ use crate::{
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 pub struct RMC {
     /// Fix time in UTC
     pub fix_time: Option<time::Time>,
     /// Status Mode Indicator
     pub status: Status,
-    #[nmea(parser(location))]
+    #[nmea(parser(location), writer(write_location))]
     /// Location (latitude and longitude)
     pub location: Option<Location>,
     /// Speed over ground in knots
@@ -59,15 +57,13 @@ pub struct RMC {
     pub course_over_ground: Option<f32>,
     /// Fix date in UTC
     pub fix_date: Option<time::Date>,
-    #[nmea(parser(magnetic_variation))]
+    #[nmea(parser(magnetic_variation), writer(write_magnetic_variation))]
     /// Magnetic variation in degrees
     pub magnetic_variation: Option<f32>,
-    #[cfg(feature = "nmea-v2-3")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
+    #[nmea(version(NmeaVersion::V2_3))]
     /// FAA Mode Indicator
     pub faa_mode: Option<FaaMode>,
-    #[cfg(feature = "nmea-v4-11")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v4-11")))]
+    #[nmea(version(NmeaVersion::V4_11))]
     /// Navigation status
     pub nav_status: Option<NavStatus>,
 }
@@ -93,6 +89,20 @@ where
     .parse(i)
 }
 
+pub fn write_magnetic_variation(
+    magnetic_variation: &Option<f32>,
+    e: &mut Encoder<'_>,
+) -> fmt::Result {
+    match magnetic_variation {
+        Some(value) => {
+            value.abs().encode(e)?;
+            let dir = if value.is_sign_negative() { 'W' } else { 'E' };
+            write!(e, ",{dir}")
+        }
+        None => e.write_char(','),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,66 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse, nmea_content::Status};
+
+/// ROT - Rate Of Turn
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_rot_rate_of_turn>
+///
+/// ```text
+///         1   2
+///         |   |
+///  $--ROT,x.x,A*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct ROT {
+    /// Rate of turn in degrees per minute, negative values indicate a turn to port
+    pub rate_of_turn: Option<f32>,
+    /// Status of the data
+    pub status: Status,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_rot_parsing() {
+        let cases = [
+            (
+                "-12.5,A",
+                ROT {
+                    rate_of_turn: Some(-12.5),
+                    status: Status::Valid,
+                },
+            ),
+            (
+                "35.0,V",
+                ROT {
+                    rate_of_turn: Some(35.0),
+                    status: Status::Invalid,
+                },
+            ),
+            (
+                ",V",
+                ROT {
+                    rate_of_turn: None,
+                    status: Status::Invalid,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = ROT::parse(input);
+            assert_eq!(
+                result,
+                Ok(("", expected)),
+                "Failed: {input:?}\n\t{result:?}"
+            );
+        }
+    }
+}
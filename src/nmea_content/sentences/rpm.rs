@@ -0,0 +1,89 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use nom::character::complete::one_of;
+
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse, nmea_content::Status};
+
+/// RPM - Revolutions
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_rpm_revolutions>
+///
+/// ```text
+///         1 2 3   4   5
+///         | | |   |   |
+///  $--RPM,a,x,x.x,x.x,A*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct RPM {
+    /// Source of the measurement
+    pub source: RpmSource,
+    /// Engine or shaft number, numbered from centerline (odd starboard, even port)
+    pub number: Option<u8>,
+    /// Speed in revolutions per minute, negative for counter-clockwise
+    pub rpm: Option<f32>,
+    /// Propeller pitch in percent of maximum, negative for astern
+    pub pitch: Option<f32>,
+    /// Status of the data
+    pub status: Status,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("SE")))]
+/// Source of the revolutions reported by [`RPM`]
+pub enum RpmSource {
+    #[nmea(selector('S'))]
+    /// S - Shaft
+    Shaft,
+    #[default]
+    #[nmea(selector('E'))]
+    /// E - Engine
+    Engine,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_rpm_parsing() {
+        let cases = [
+            (
+                "E,1,2418.2,10.5,A",
+                RPM {
+                    source: RpmSource::Engine,
+                    number: Some(1),
+                    rpm: Some(2418.2),
+                    pitch: Some(10.5),
+                    status: Status::Valid,
+                },
+            ),
+            (
+                "S,2,-850,,V",
+                RPM {
+                    source: RpmSource::Shaft,
+                    number: Some(2),
+                    rpm: Some(-850.0),
+                    pitch: None,
+                    status: Status::Invalid,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = RPM::parse(input);
+            assert_eq!(result, Ok(("", expected)), "Failed: {input:?}");
+        }
+
+        for input in ["X,1,2418.2,10.5,A", ",1,2418.2,10.5,A", "E,1,2418.2,10.5"] {
+            let result: IResult<_, _> = RPM::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
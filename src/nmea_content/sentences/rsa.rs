@@ -0,0 +1,79 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse, nmea_content::Status};
+
+/// RSA - Rudder Sensor Angle
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_rsa_rudder_sensor_angle>
+///
+/// ```text
+///         1   2 3   4
+///         |   | |   |
+///  $--RSA,x.x,A,x.x,A*hh<CR><LF>
+/// ```
+///
+/// Rudder angles are negative when turning the vessel to port.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct RSA {
+    /// Starboard (or single) rudder angle in degrees
+    pub starboard_rudder_angle: Option<f32>,
+    /// Status of the starboard rudder angle
+    pub starboard_status: Status,
+    /// Port rudder angle in degrees
+    pub port_rudder_angle: Option<f32>,
+    /// Status of the port rudder angle
+    pub port_status: Status,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_rsa_parsing() {
+        let cases = [
+            (
+                "10.5,A,-9.8,A",
+                RSA {
+                    starboard_rudder_angle: Some(10.5),
+                    starboard_status: Status::Valid,
+                    port_rudder_angle: Some(-9.8),
+                    port_status: Status::Valid,
+                },
+            ),
+            (
+                "-3.2,A,,V",
+                RSA {
+                    starboard_rudder_angle: Some(-3.2),
+                    starboard_status: Status::Valid,
+                    port_rudder_angle: None,
+                    port_status: Status::Invalid,
+                },
+            ),
+            (
+                ",V,,V",
+                RSA {
+                    starboard_rudder_angle: None,
+                    starboard_status: Status::Invalid,
+                    port_rudder_angle: None,
+                    port_status: Status::Invalid,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = RSA::parse(input);
+            assert_eq!(result, Ok(("", expected)), "Failed: {input:?}");
+        }
+
+        for input in ["10.5,X,-9.8,A", "10.5,A,,", "10.5,A", "port,A,-9.8,A"] {
+            let result: IResult<_, _> = RSA::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use nom::character::complete::one_of;
+
+use super::ttm::DistanceUnit;
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse};
+
+/// RSD - Radar System Data
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_rsd_radar_system_data>
+///
+/// ```text
+///         1   2   3   4   5   6   7   8   9   10  11  1213
+///         |   |   |   |   |   |   |   |   |   |   |   | |
+///  $--RSD,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,a,a*hh<CR><LF>
+/// ```
+///
+/// Ranges are given in the [`range_units`](RSD::range_units) of the sentence.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct RSD {
+    /// Origin 1 with its range markers
+    pub first_origin: RadarOrigin,
+    /// Origin 2 with its range markers
+    pub second_origin: RadarOrigin,
+    /// Range of the cursor
+    pub cursor_range: Option<f32>,
+    /// Bearing of the cursor in degrees clockwise from 0°
+    pub cursor_bearing: Option<f32>,
+    /// Range scale in use
+    pub range_scale: Option<f32>,
+    /// Units of the ranges
+    pub range_units: Option<DistanceUnit>,
+    /// Display rotation
+    pub display_rotation: Option<DisplayRotation>,
+}
+
+/// Origin of the radar display with its variable range marker and electronic bearing line
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct RadarOrigin {
+    /// Range of the origin
+    pub range: Option<f32>,
+    /// Bearing of the origin in degrees clockwise from 0°
+    pub bearing: Option<f32>,
+    /// Variable range marker (VRM)
+    pub variable_range_marker: Option<f32>,
+    /// Bearing of the electronic bearing line (EBL) in degrees
+    pub electronic_bearing_line: Option<f32>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("CHN")))]
+/// Rotation of the radar display reported by [`RSD`]
+pub enum DisplayRotation {
+    #[nmea(selector('C'))]
+    /// C - Course up, course over ground up in degrees true
+    CourseUp,
+    #[nmea(selector('H'))]
+    /// H - Head up, ship's heading up
+    HeadUp,
+    #[default]
+    #[nmea(selector('N'))]
+    /// N - North up, true north is 0°
+    NorthUp,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_rsd_parsing() {
+        let result: IResult<_, _> = RSD::parse("0.0,0.0,2.0,045.0,1.5,270.0,,,2.5,090.0,6.0,N,H");
+        let (rest, rsd) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            rsd.first_origin,
+            RadarOrigin {
+                range: Some(0.0),
+                bearing: Some(0.0),
+                variable_range_marker: Some(2.0),
+                electronic_bearing_line: Some(45.0),
+            }
+        );
+        assert_eq!(
+            rsd.second_origin,
+            RadarOrigin {
+                range: Some(1.5),
+                bearing: Some(270.0),
+                variable_range_marker: None,
+                electronic_bearing_line: None,
+            }
+        );
+        assert_eq!(rsd.cursor_range, Some(2.5));
+        assert_eq!(rsd.cursor_bearing, Some(90.0));
+        assert_eq!(rsd.range_scale, Some(6.0));
+        assert_eq!(rsd.range_units, Some(DistanceUnit::NauticalMiles));
+        assert_eq!(rsd.display_rotation, Some(DisplayRotation::HeadUp));
+
+        let result: IResult<_, _> = RSD::parse(",,,,,,,,,,,,");
+        assert_eq!(result, Ok(("", RSD::default())));
+
+        for input in [
+            "0.0,0.0,2.0,045.0,1.5,270.0,,,2.5,090.0,6.0,N,X",
+            "0.0,0.0,2.0,045.0,1.5,270.0,,,2.5,090.0,6.0,X,H",
+            "0.0,0.0,2.0,045.0,1.5,270.0,,,2.5,090.0,6.0,N",
+        ] {
+            let result: IResult<_, _> = RSD::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{Fragment, WaypointId},
+};
+
+/// Maximum number of waypoints listed by a single [`RTE`] sentence.
+pub const RTE_WAYPOINTS_CAPACITY: usize = 16;
+
+/// Maximum number of waypoints of a [`Route`] assembled from [`RTE`] sentences.
+pub const ROUTE_WAYPOINTS_CAPACITY: usize = 64;
+
+/// RTE - Routes
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_rte_routes>
+///
+/// ```text
+///         1   2   3 4    5           x    n
+///         |   |   | |    |           |    |
+///  $--RTE,x.x,x.x,a,c--c,c--c, ..... c--c*hh<CR><LF>
+/// ```
+///
+/// Routes with more waypoints than fit in a sentence are split over several
+/// sentences, which can be assembled into a [`Route`] by a
+/// [`Reassembler`](crate::nmea_content::Reassembler).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+// The waypoints take the rest of the sentence, any unparsed input is a malformed waypoint
+#[nmea(exact)]
+pub struct RTE {
+    /// Total number of RTE sentences to be transmitted for this route
+    pub total_messages: u8,
+    /// Sentence number of this RTE message within the route
+    pub message_number: u8,
+    /// Whether the route is complete or a working route
+    pub mode: RouteMode,
+    /// Route identifier
+    pub route_id: Option<WaypointId>,
+    /// Waypoint identifiers, in route order
+    pub waypoints: heapless::Vec<WaypointId, RTE_WAYPOINTS_CAPACITY>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("cw")))]
+/// Route mode reported by [`RTE`]
+pub enum RouteMode {
+    #[default]
+    #[nmea(selector('c'))]
+    /// c - Complete route, all waypoints are listed
+    Complete,
+    #[nmea(selector('w'))]
+    /// w - Working route, the first waypoint is the one just left and the second one
+    /// is the destination
+    Working,
+}
+
+/// Route reported by a complete group of [`RTE`] sentences
+///
+/// Assembled from the sentences of the group by a [`Reassembler`](crate::nmea_content::Reassembler).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Route {
+    /// Whether the route is complete or a working route
+    pub mode: RouteMode,
+    /// Route identifier
+    pub route_id: Option<WaypointId>,
+    /// Waypoint identifiers, in route order
+    ///
+    /// Waypoints beyond [`ROUTE_WAYPOINTS_CAPACITY`] are dropped.
+    pub waypoints: heapless::Vec<WaypointId, ROUTE_WAYPOINTS_CAPACITY>,
+}
+
+impl Fragment for RTE {
+    type Message = Route;
+
+    /// Complete and working routes may be sent at the same time
+    type Key = (RouteMode, Option<WaypointId>);
+
+    fn key(&self) -> Self::Key {
+        (self.mode, self.route_id.clone())
+    }
+
+    fn fragment_count(&self) -> u8 {
+        self.total_messages
+    }
+
+    fn fragment_number(&self) -> u8 {
+        self.message_number
+    }
+
+    fn assemble(fragments: impl Iterator<Item = Self>) -> Self::Message {
+        let mut message = Route::default();
+
+        for rte in fragments {
+            message.mode = rte.mode;
+            message.route_id = rte.route_id;
+            for waypoint in rte.waypoints {
+                if message.waypoints.push(waypoint).is_err() {
+                    break;
+                }
+            }
+        }
+
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        IResult,
+        nmea_content::{Reassembler, TalkerId},
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_rte_parsing() {
+        let result: IResult<_, _> = RTE::parse("2,1,c,0,W3IWI,DRIVWY,32CEDR,32-29");
+        let (rest, rte) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(rte.total_messages, 2);
+        assert_eq!(rte.message_number, 1);
+        assert_eq!(rte.mode, RouteMode::Complete);
+        assert_eq!(rte.route_id.as_deref(), Some("0"));
+        assert_eq!(rte.waypoints, ["W3IWI", "DRIVWY", "32CEDR", "32-29"]);
+
+        let result: IResult<_, _> = RTE::parse("1,1,w,");
+        let (rest, rte) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(rte.mode, RouteMode::Working);
+        assert_eq!(rte.route_id, None);
+        assert!(rte.waypoints.is_empty());
+
+        for input in ["2,1,x,0,W3IWI", "2,1,c,0,W3IWI,,DRIVWY", "2,1"] {
+            let result: IResult<_, _> = RTE::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+
+    #[test]
+    fn test_rte_reassembly() {
+        let mut reassembler: Reassembler<RTE> = Reassembler::new(Duration::from_secs(1));
+
+        let sentences = [
+            "2,1,c,0,W3IWI,DRIVWY,32CEDR",
+            "1,1,w,0,W3IWI,DRIVWY",
+            "2,2,c,0,32-29,32BKLD",
+        ];
+        let mut routes = Vec::new();
+        for sentence in sentences {
+            let (_, rte) = (RTE::parse(sentence) as IResult<_, _>).unwrap();
+            routes.extend(reassembler.push(TalkerId::Gps, rte, Duration::ZERO));
+        }
+
+        assert_eq!(routes.len(), 2);
+        assert_eq!(routes[0].mode, RouteMode::Working);
+        assert_eq!(routes[0].waypoints, ["W3IWI", "DRIVWY"]);
+        assert_eq!(routes[1].mode, RouteMode::Complete);
+        assert_eq!(routes[1].route_id.as_deref(), Some("0"));
+        assert_eq!(
+            routes[1].waypoints,
+            ["W3IWI", "DRIVWY", "32CEDR", "32-29", "32BKLD"]
+        );
+    }
+}
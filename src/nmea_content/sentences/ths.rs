@@ -0,0 +1,89 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse};
+
+/// THS - True Heading and Status
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_ths_true_heading_and_status>
+///
+/// ```text
+///         1   2
+///         |   |
+///  $--THS,x.x,a*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct THS {
+    /// Heading in degrees true
+    pub heading: Option<f32>,
+    /// Mode indicator
+    pub mode: ThsMode,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("AEMSV")))]
+/// THS Mode Indicator
+pub enum ThsMode {
+    #[nmea(selector('A'))]
+    /// A - Autonomous
+    Autonomous,
+    #[nmea(selector('E'))]
+    /// E - Estimated (dead reckoning)
+    Estimated,
+    #[nmea(selector('M'))]
+    /// M - Manual input
+    Manual,
+    #[nmea(selector('S'))]
+    /// S - Simulator
+    Simulator,
+    #[default]
+    #[nmea(selector('V'))]
+    /// V - Data not valid
+    NotValid,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_ths_parsing() {
+        let cases = [
+            (
+                "77.52,E",
+                THS {
+                    heading: Some(77.52),
+                    mode: ThsMode::Estimated,
+                },
+            ),
+            (
+                ",V",
+                THS {
+                    heading: None,
+                    mode: ThsMode::NotValid,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = THS::parse(input);
+            assert_eq!(
+                result,
+                Ok(("", expected)),
+                "Failed: {input:?}\n\t{result:?}"
+            );
+        }
+
+        for input in ["77.52,X", "77.52,", "77.52"] {
+            let result: IResult<_, _> = THS::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::ttm::{ReferenceTarget, TargetName, TargetStatus};
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{Location, encode::write_location, parse::location},
+};
+
+/// TLL - Target Latitude and Longitude
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_tll_target_latitude_and_longitude>
+///
+/// ```text
+///         1  2       3 4        5 6    7         8 9
+///         |  |       | |        | |    |         | |
+///  $--TLL,xx,llll.ll,a,yyyyy.yy,a,c--c,hhmmss.ss,a,a*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct TLL {
+    /// Target number (0-99)
+    pub target_number: Option<u8>,
+    #[nmea(parser(location), writer(write_location))]
+    /// Target location (latitude and longitude)
+    pub location: Option<Location>,
+    /// Name of the target
+    pub target_name: Option<TargetName>,
+    /// Time of the data in UTC
+    pub time: Option<time::Time>,
+    /// Status of the target
+    pub target_status: TargetStatus,
+    /// Whether the target is the reference target used to compute own ship speed
+    pub reference_target: Option<ReferenceTarget>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_tll_parsing() {
+        let result: IResult<_, _> = TLL::parse("01,4917.24,N,12309.57,W,TGT01,225444,T,R");
+        let (rest, tll) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(tll.target_number, Some(1));
+        let location = tll.location.unwrap();
+        assert!((location.latitude - 49.287_333).abs() < 1e-6);
+        assert!((location.longitude + 123.1595).abs() < 1e-6);
+        assert_eq!(tll.target_name.as_deref(), Some("TGT01"));
+        assert_eq!(tll.time, time::Time::from_hms(22, 54, 44).ok());
+        assert_eq!(tll.target_status, TargetStatus::Tracking);
+        assert_eq!(tll.reference_target, Some(ReferenceTarget::Reference));
+
+        let result: IResult<_, _> = TLL::parse("02,,,,,,,L,");
+        let (rest, tll) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(tll.target_number, Some(2));
+        assert_eq!(tll.location, None);
+        assert_eq!(tll.target_name, None);
+        assert_eq!(tll.time, None);
+        assert_eq!(tll.target_status, TargetStatus::Lost);
+        assert_eq!(tll.reference_target, None);
+
+        for input in [
+            "01,4917.24,N,12309.57,W,TGT01,225444,X,R",
+            "01,4917.24,N,12309.57,W,TGT01,225444,T,X",
+            "01,4917.24,N,12309.57,W,TGT01,225444,,R",
+            "01,4917.24,N,12309.57,W,TARGET_NAME_TOO_LONG,225444,T,",
+        ] {
+            let result: IResult<_, _> = TLL::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse, NmeaVersion};
+
+/// Maximum length of a [`TargetName`].
+pub const TARGET_NAME_CAPACITY: usize = 16;
+
+/// Name of a radar target, as used by [`TTM`] and [`TLL`]
+///
+/// Names longer than [`TARGET_NAME_CAPACITY`] fail to parse.
+///
+/// [`TLL`]: crate::nmea_content::TLL
+pub type TargetName = heapless::String<TARGET_NAME_CAPACITY>;
+
+/// TTM - Tracked Target Message
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_ttm_tracked_target_message>
+///
+/// ```text
+///         1  2   3   4 5   6   7 8   9   1011   1213
+///         |  |   |   | |   |   | |   |   | |    | |
+///  $--TTM,xx,x.x,x.x,a,x.x,x.x,a,x.x,x.x,a,c--c,a,a*hh<CR><LF>
+/// ```
+///
+/// NMEA 3.0:
+/// ```text
+///         1  2   3   4 5   6   7 8   9   1011   121314        15
+///         |  |   |   | |   |   | |   |   | |    | | |         |
+///  $--TTM,xx,x.x,x.x,a,x.x,x.x,a,x.x,x.x,a,c--c,a,a,hhmmss.ss,a*hh<CR><LF>
+/// ```
+///
+/// Distances and speeds are given in the [`units`](TTM::units) of the sentence.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct TTM {
+    /// Target number (0-99)
+    pub target_number: Option<u8>,
+    /// Distance of the target from own ship
+    pub target_distance: Option<f32>,
+    /// Bearing of the target from own ship in degrees
+    pub bearing: Option<f32>,
+    /// Reference of the bearing
+    pub bearing_reference: TargetReference,
+    /// Speed of the target
+    pub target_speed: Option<f32>,
+    /// Course of the target in degrees
+    pub target_course: Option<f32>,
+    /// Reference of the course
+    pub course_reference: TargetReference,
+    /// Distance of the closest point of approach
+    pub cpa_distance: Option<f32>,
+    /// Time to the closest point of approach in minutes, negative once passed
+    pub cpa_time: Option<f32>,
+    /// Units of the distances and speeds
+    pub units: Option<DistanceUnit>,
+    /// Name of the target
+    pub target_name: Option<TargetName>,
+    /// Status of the target
+    pub target_status: TargetStatus,
+    /// Whether the target is the reference target used to compute own ship speed
+    pub reference_target: Option<ReferenceTarget>,
+    #[nmea(version(NmeaVersion::V3_0))]
+    /// Time of the data in UTC
+    pub time: Option<time::Time>,
+    #[nmea(version(NmeaVersion::V3_0))]
+    /// Type of acquisition of the target
+    pub acquisition: Option<TargetAcquisition>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("TR")))]
+/// Reference of a bearing or course reported by [`TTM`]
+pub enum TargetReference {
+    #[default]
+    #[nmea(selector('T'))]
+    /// T - True
+    True,
+    #[nmea(selector('R'))]
+    /// R - Relative to own ship heading
+    Relative,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("LQT")))]
+/// Status of a radar target
+pub enum TargetStatus {
+    #[nmea(selector('L'))]
+    /// L - Lost, tracked target has been lost
+    Lost,
+    #[default]
+    #[nmea(selector('Q'))]
+    /// Q - Query, target in the process of acquisition
+    Query,
+    #[nmea(selector('T'))]
+    /// T - Tracking
+    Tracking,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("R")))]
+/// Reference target flag of a radar target, empty for other targets
+pub enum ReferenceTarget {
+    #[default]
+    #[nmea(selector('R'))]
+    /// R - Reference target, used to compute own ship speed
+    Reference,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("AMR")))]
+/// Type of acquisition of a [`TTM`] target
+pub enum TargetAcquisition {
+    #[default]
+    #[nmea(selector('A'))]
+    /// A - Automatic
+    Automatic,
+    #[nmea(selector('M'))]
+    /// M - Manual
+    Manual,
+    #[nmea(selector('R'))]
+    /// R - Reported
+    Reported,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("KNS")))]
+/// Unit of the distances and speeds reported by radar sentences
+pub enum DistanceUnit {
+    #[nmea(selector('K'))]
+    /// K - Kilometers and km/h
+    Kilometers,
+    #[default]
+    #[nmea(selector('N'))]
+    /// N - Nautical miles and knots
+    NauticalMiles,
+    #[nmea(selector('S'))]
+    /// S - Statute miles and mph
+    StatuteMiles,
+}
+
+impl DistanceUnit {
+    /// Converts a distance in this unit to nautical miles, or a speed to knots.
+    pub fn to_nautical_miles(self, value: f32) -> f32 {
+        match self {
+            DistanceUnit::Kilometers => value / 1.852,
+            DistanceUnit::NauticalMiles => value,
+            DistanceUnit::StatuteMiles => value * 1609.344 / 1852.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_ttm_parsing() {
+        let input = "01,0.2,190.8,T,12.1,109.7,R,0.1,-0.5,N,TGT01,T,R,100021.00,A";
+        let result: IResult<_, _> = TTM::parse(input);
+        let (rest, ttm) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(ttm.target_number, Some(1));
+        assert_eq!(ttm.course_reference, TargetReference::Relative);
+        assert_eq!(ttm.cpa_time, Some(-0.5));
+        assert_eq!(ttm.units, Some(DistanceUnit::NauticalMiles));
+        assert_eq!(ttm.target_name.as_deref(), Some("TGT01"));
+        assert_eq!(ttm.target_status, TargetStatus::Tracking);
+        assert_eq!(ttm.reference_target, Some(ReferenceTarget::Reference));
+        assert_eq!(ttm.time, time::Time::from_hms(10, 0, 21).ok());
+        assert_eq!(ttm.acquisition, Some(TargetAcquisition::Automatic));
+
+        let input = "01,0.2,190.8,T,12.1,109.7,R,0.1,-0.5,N,TGT01,T,R";
+        let result: IResult<_, _> = TTM::parse(input);
+        let (rest, ttm) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(ttm.time, None);
+        assert_eq!(ttm.acquisition, None);
+
+        let result: IResult<_, _> = TTM::parse_version(input, NmeaVersion::V3_0.into());
+        assert!(result.is_err(), "{result:?}");
+
+        for input in [
+            "01,0.2,190.8,M,12.1,109.7,T,0.1,0.5,N,TGT01,T,,100021.00,A",
+            "01,0.2,190.8,T,12.1,109.7,T,0.1,0.5,N,TGT01,X,,100021.00,A",
+            "01,0.2,190.8,T,12.1,109.7,T,0.1,0.5,N,TGT01,T,X,100021.00,A",
+        ] {
+            let result: IResult<_, _> = TTM::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+
+    #[test]
+    fn test_distance_unit() {
+        assert_eq!(DistanceUnit::NauticalMiles.to_nautical_miles(2.0), 2.0);
+        assert_eq!(DistanceUnit::Kilometers.to_nautical_miles(1.852), 1.0);
+        assert!((DistanceUnit::StatuteMiles.to_nautical_miles(1.0) - 0.868_976).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse, nmea_content::Fragment};
+
+/// Maximum length of the text of a single [`TXT`] sentence.
+pub const TXT_TEXT_CAPACITY: usize = 64;
+
+/// Maximum length of the text of a [`TextMessage`] assembled from [`TXT`] sentences.
+pub const TEXT_MESSAGE_CAPACITY: usize = 256;
+
+/// TXT - Text Transmission
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_txt_text_transmission>
+///
+/// ```text
+///         1  2  3  4
+///         |  |  |  |
+///  $--TXT,xx,xx,xx,c--c*hh<CR><LF>
+/// ```
+///
+/// The `^hh` escapes of reserved characters in the text are decoded.
+///
+/// Long texts are split over several sentences, which can be assembled into a
+/// [`TextMessage`] by a [`Reassembler`](crate::nmea_content::Reassembler).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct TXT {
+    /// Total number of TXT sentences for this text
+    pub total_messages: u8,
+    /// Sentence number of this TXT message within the text
+    pub message_number: u8,
+    /// Text identifier, such as the severity of u-blox receiver messages
+    pub text_id: Option<u8>,
+    /// Text message
+    pub text: Option<heapless::String<TXT_TEXT_CAPACITY>>,
+}
+
+/// Text reported by a complete group of [`TXT`] sentences
+///
+/// Assembled from the sentences of the group by a [`Reassembler`](crate::nmea_content::Reassembler).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TextMessage {
+    /// Text identifier
+    pub text_id: Option<u8>,
+    /// Text of all the sentences, in order
+    ///
+    /// Text beyond [`TEXT_MESSAGE_CAPACITY`] is dropped.
+    pub text: heapless::String<TEXT_MESSAGE_CAPACITY>,
+}
+
+impl Fragment for TXT {
+    type Message = TextMessage;
+
+    type Key = Option<u8>;
+
+    fn key(&self) -> Self::Key {
+        self.text_id
+    }
+
+    fn fragment_count(&self) -> u8 {
+        self.total_messages
+    }
+
+    fn fragment_number(&self) -> u8 {
+        self.message_number
+    }
+
+    fn assemble(fragments: impl Iterator<Item = Self>) -> Self::Message {
+        let mut message = TextMessage::default();
+
+        for txt in fragments {
+            message.text_id = txt.text_id;
+            for c in txt.text.iter().flat_map(|text| text.chars()) {
+                if message.text.push(c).is_err() {
+                    break;
+                }
+            }
+        }
+
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        IResult,
+        nmea_content::{Reassembler, TalkerId},
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_txt_parsing() {
+        let result: IResult<_, _> = TXT::parse("01,01,02,u-blox ag - www.u-blox.com");
+        let (rest, txt) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(txt.text_id, Some(2));
+        assert_eq!(txt.text.as_deref(), Some("u-blox ag - www.u-blox.com"));
+
+        let result: IResult<_, _> = TXT::parse("01,01,01,ANTENNA OK^2C LOW GAIN");
+        let (_, txt) = result.unwrap();
+        assert_eq!(txt.text.as_deref(), Some("ANTENNA OK, LOW GAIN"));
+
+        for input in ["01,01,01,BAD ^ZZ ESCAPE", "01,01"] {
+            let result: IResult<_, _> = TXT::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+
+    #[test]
+    fn test_txt_reassembly() {
+        let mut reassembler: Reassembler<TXT> = Reassembler::new(Duration::from_secs(1));
+
+        let sentences = [
+            "02,02,07, second part",
+            "01,01,02,HW UBX-M8030 00080000",
+            "02,01,07,first part^2C",
+        ];
+        let mut texts = Vec::new();
+        for sentence in sentences {
+            let (_, txt) = (TXT::parse(sentence) as IResult<_, _>).unwrap();
+            texts.extend(reassembler.push(TalkerId::Gps, txt, Duration::ZERO));
+        }
+
+        assert_eq!(texts.len(), 2);
+        assert_eq!(texts[0].text_id, Some(2));
+        assert_eq!(texts[0].text, "HW UBX-M8030 00080000");
+        assert_eq!(texts[1].text_id, Some(7));
+        assert_eq!(texts[1].text, "first part, second part");
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse, NmeaVersion, nmea_content::Status};
+
+/// VBW - Dual Ground/Water Speed
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vbw_dual_groundwater_speed>
+///
+/// ```text
+///         1   2   3 4   5   6
+///         |   |   | |   |   |
+///  $--VBW,x.x,x.x,A,x.x,x.x,A*hh<CR><LF>
+/// ```
+///
+/// NMEA 3.0:
+/// ```text
+///         1   2   3 4   5   6 7   8 9   10
+///         |   |   | |   |   | |   | |   |
+///  $--VBW,x.x,x.x,A,x.x,x.x,A,x.x,A,x.x,A*hh<CR><LF>
+/// ```
+///
+/// Longitudinal speeds are negative astern, transverse speeds are negative to port.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct VBW {
+    /// Longitudinal water speed in knots
+    pub longitudinal_water_speed: Option<f32>,
+    /// Transverse water speed in knots
+    pub transverse_water_speed: Option<f32>,
+    /// Status of the water speeds
+    pub water_speed_status: Status,
+    /// Longitudinal ground speed in knots
+    pub longitudinal_ground_speed: Option<f32>,
+    /// Transverse ground speed in knots
+    pub transverse_ground_speed: Option<f32>,
+    /// Status of the ground speeds
+    pub ground_speed_status: Status,
+    #[nmea(version(NmeaVersion::V3_0))]
+    /// Stern transverse water speed in knots
+    pub stern_transverse_water_speed: Option<f32>,
+    #[nmea(version(NmeaVersion::V3_0))]
+    /// Status of the stern transverse water speed
+    pub stern_water_speed_status: Status,
+    #[nmea(version(NmeaVersion::V3_0))]
+    /// Stern transverse ground speed in knots
+    pub stern_transverse_ground_speed: Option<f32>,
+    #[nmea(version(NmeaVersion::V3_0))]
+    /// Status of the stern transverse ground speed
+    pub stern_ground_speed_status: Status,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_vbw_parsing() {
+        let input = "12.3,-0.07,A,11.8,0.12,A,0.1,A,,V";
+        let result: IResult<_, _> = VBW::parse(input);
+        let (rest, vbw) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(vbw.longitudinal_water_speed, Some(12.3));
+        assert_eq!(vbw.transverse_water_speed, Some(-0.07));
+        assert_eq!(vbw.water_speed_status, Status::Valid);
+        assert_eq!(vbw.transverse_ground_speed, Some(0.12));
+        assert_eq!(vbw.stern_transverse_water_speed, Some(0.1));
+        assert_eq!(vbw.stern_ground_speed_status, Status::Invalid);
+
+        let input = "12.3,-0.07,A,11.8,0.12,A";
+        let result: IResult<_, _> = VBW::parse(input);
+        let (rest, vbw) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(vbw.stern_transverse_water_speed, None);
+
+        let input = "12.3,-0.07,X,11.8,0.12,A,0.1,A,,V";
+        let result: IResult<_, _> = VBW::parse(input);
+        assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+    }
+}
@@ -0,0 +1,168 @@
+use nom::character::complete::one_of;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{
+        Fragment,
+        ais::{AisError, AisMessage, MAX_PAYLOAD_BITS},
+    },
+};
+
+/// Maximum number of payload characters of a single [`VDM`] sentence.
+///
+/// A sentence is limited to 82 characters, which leaves at most 62 characters
+/// for the payload of an `!AIVDM` sentence.
+pub const VDM_PAYLOAD_CAPACITY: usize = 62;
+
+/// VDM - AIS VHF Data-link Message
+///
+/// Also used for VDO - AIS VHF Data-link Own-vessel report, which has the same fields.
+///
+/// <https://gpsd.gitlab.io/gpsd/AIVDM.html#_aivdmaivdo_sentence_layer>
+///
+/// ```text
+///         1 2 3 4 5    6
+///         | | | | |    |
+///  !--VDM,x,x,x,a,s--s,x*hh<CR><LF>
+/// ```
+///
+/// The payload is kept in its 6-bit armored form. Single-sentence messages are decoded
+/// with [`VDM::message`]; multi-sentence messages are joined and decoded by a
+/// [`Reassembler`](crate::nmea_content::Reassembler).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct VDM {
+    /// Total number of sentences needed to transfer the message
+    pub fragment_count: u8,
+    /// Sentence number of this sentence within the message, starting at 1
+    pub fragment_number: u8,
+    /// Sequential message identifier, shared by the sentences of a multi-sentence message
+    pub sequential_message_id: Option<u8>,
+    /// AIS channel the message was received on
+    pub channel: Option<AisChannel>,
+    /// 6-bit armored payload
+    pub payload: heapless::String<VDM_PAYLOAD_CAPACITY>,
+    /// Number of fill bits added to the payload to complete the last 6-bit character
+    pub fill_bits: u8,
+}
+
+/// VDO - AIS VHF Data-link Own-vessel report
+pub type VDO = VDM;
+
+impl VDM {
+    /// Decodes the AIS message carried by this sentence.
+    ///
+    /// Returns [`AisError::Fragmented`] if the message spans several sentences.
+    pub fn message(&self) -> Result<AisMessage, AisError> {
+        if self.fragment_count != 1 {
+            return Err(AisError::Fragmented);
+        }
+
+        AisMessage::decode(self.payload.as_bytes(), self.fill_bits)
+    }
+}
+
+impl Fragment for VDM {
+    type Message = Result<AisMessage, AisError>;
+
+    type Key = (Option<u8>, Option<AisChannel>);
+
+    fn key(&self) -> Self::Key {
+        (self.sequential_message_id, self.channel)
+    }
+
+    fn fragment_count(&self) -> u8 {
+        self.fragment_count
+    }
+
+    fn fragment_number(&self) -> u8 {
+        self.fragment_number
+    }
+
+    fn assemble(fragments: impl Iterator<Item = Self>) -> Self::Message {
+        let mut payload = heapless::String::<{ MAX_PAYLOAD_BITS / 6 }>::new();
+        let mut fill_bits = 0;
+
+        for vdm in fragments {
+            payload
+                .push_str(&vdm.payload)
+                .or(Err(AisError::InvalidLength))?;
+            fill_bits = vdm.fill_bits;
+        }
+
+        AisMessage::decode(payload.as_bytes(), fill_bits)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("AB12")))]
+/// AIS Channel
+pub enum AisChannel {
+    #[default]
+    #[nmea(selector('A' | '1'))]
+    /// A or 1 - 161.975 MHz (87B)
+    A,
+    #[nmea(selector('B' | '2'))]
+    /// B or 2 - 162.025 MHz (88B)
+    B,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_vdm_parsing() {
+        let cases = [
+            (
+                "1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0",
+                VDM {
+                    fragment_count: 1,
+                    fragment_number: 1,
+                    sequential_message_id: None,
+                    channel: Some(AisChannel::B),
+                    payload: "177KQJ5000G?tO`K>RA1wUbN0TKH".try_into().unwrap(),
+                    fill_bits: 0,
+                },
+            ),
+            (
+                "2,2,1,2,88888888880,2",
+                VDM {
+                    fragment_count: 2,
+                    fragment_number: 2,
+                    sequential_message_id: Some(1),
+                    channel: Some(AisChannel::B),
+                    payload: "88888888880".try_into().unwrap(),
+                    fill_bits: 2,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = VDM::parse(input);
+            assert_eq!(
+                result,
+                Ok(("", expected)),
+                "Failed: {input:?}\n\t{result:?}"
+            );
+        }
+
+        let cases = [
+            "1,1,,C,177KQJ5000G?tO`K>RA1wUbN0TKH,0",
+            "1,1,,B,,0",
+            "1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH",
+        ];
+
+        for input in cases {
+            let result: IResult<_, _> = VDM::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{encode::write_with_unit, parse::with_unit},
+};
+
+/// VDR - Set and Drift
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vdr_set_and_drift>
+///
+/// ```text
+///         1   2 3   4 5   6
+///         |   | |   | |   |
+///  $--VDR,x.x,T,x.x,M,x.x,N*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct VDR {
+    #[nmea(parser(with_unit('T')), writer(write_with_unit('T')))]
+    /// Direction of the current in degrees true
+    pub set_true: Option<f32>,
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
+    /// Direction of the current in degrees magnetic
+    pub set_magnetic: Option<f32>,
+    #[nmea(parser(with_unit('N')), writer(write_with_unit('N')))]
+    /// Speed of the current in knots
+    pub drift: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_vdr_parsing() {
+        let result: IResult<_, _> = VDR::parse("10.1,T,12.3,M,1.2,N");
+        let (rest, vdr) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(vdr.set_true, Some(10.1));
+        assert_eq!(vdr.set_magnetic, Some(12.3));
+        assert_eq!(vdr.drift, Some(1.2));
+
+        let result: IResult<_, _> = VDR::parse(",T,,M,0.4,N");
+        let (rest, vdr) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(vdr.set_true, None);
+        assert_eq!(vdr.set_magnetic, None);
+        assert_eq!(vdr.drift, Some(0.4));
+
+        let result: IResult<_, _> = VDR::parse(",,,,,");
+        assert_eq!(result, Ok(("", VDR::default())));
+
+        for input in [
+            "10.1,M,12.3,M,1.2,N",
+            "10.1,T,12.3,M,1.2,K",
+            "10.1,T,12.3,M",
+        ] {
+            let result: IResult<_, _> = VDR::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::vtg::{speed, write_speed};
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{encode::write_with_unit, parse::with_unit},
+};
+
+/// VHW - Water speed and heading
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vhw_water_speed_and_heading>
+///
+/// ```text
+///         1   2 3   4 5   6 7   8
+///         |   | |   | |   | |   |
+///  $--VHW,x.x,T,x.x,M,x.x,N,x.x,K*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct VHW {
+    #[nmea(parser(with_unit('T')), writer(write_with_unit('T')))]
+    /// Heading in degrees true
+    pub heading_true: Option<f32>,
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
+    /// Heading in degrees magnetic
+    pub heading_magnetic: Option<f32>,
+    #[nmea(parser(speed), writer(write_speed))]
+    /// Speed through water in knots
+    pub water_speed: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_vhw_parsing() {
+        let cases = [
+            ("245.1,T,245.1,M,12.5,N,23.2,K", Some(12.5)),
+            (",T,,M,,,18.52,K", Some(10.0)),
+            (",,,,,,,", None),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = VHW::parse(input);
+            let Ok(("", vhw)) = result else {
+                panic!("Failed: {input:?}\n\t{result:?}");
+            };
+            assert_eq!(vhw.water_speed, expected, "Failed: {input:?}");
+        }
+    }
+}
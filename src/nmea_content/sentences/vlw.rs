@@ -0,0 +1,81 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{encode::write_with_unit, parse::with_unit},
+};
+
+/// VLW - Distance Traveled through Water
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vlw_distance_traveled_through_water>
+///
+/// ```text
+///         1   2 3   4
+///         |   | |   |
+///  $--VLW,x.x,N,x.x,N*hh<CR><LF>
+/// ```
+///
+/// NMEA 3.0:
+/// ```text
+///         1   2 3   4 5   6 7   8
+///         |   | |   | |   | |   |
+///  $--VLW,x.x,N,x.x,N,x.x,N,x.x,N*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct VLW {
+    #[nmea(parser(with_unit('N')), writer(write_with_unit('N')))]
+    /// Total cumulative water distance in nautical miles
+    pub total_water_distance: Option<f32>,
+    #[nmea(parser(with_unit('N')), writer(write_with_unit('N')))]
+    /// Water distance since reset in nautical miles
+    pub trip_water_distance: Option<f32>,
+    #[nmea(version(NmeaVersion::V3_0))]
+    #[nmea(parser(with_unit('N')), writer(write_with_unit('N')))]
+    /// Total cumulative ground distance in nautical miles
+    pub total_ground_distance: Option<f32>,
+    #[nmea(version(NmeaVersion::V3_0))]
+    #[nmea(parser(with_unit('N')), writer(write_with_unit('N')))]
+    /// Ground distance since reset in nautical miles
+    pub trip_ground_distance: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_vlw_parsing() {
+        let input = "7803.2,N,0.00,N,1520.8,N,12.4,N";
+        let result: IResult<_, _> = VLW::parse(input);
+        let (rest, vlw) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(vlw.total_water_distance, Some(7803.2));
+        assert_eq!(vlw.trip_water_distance, Some(0.0));
+        assert_eq!(vlw.total_ground_distance, Some(1520.8));
+        assert_eq!(vlw.trip_ground_distance, Some(12.4));
+
+        let input = "7803.2,N,0.00,N";
+        let result: IResult<_, _> = VLW::parse(input);
+        let (rest, vlw) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(vlw.total_water_distance, Some(7803.2));
+        assert_eq!(vlw.total_ground_distance, None);
+
+        let result: IResult<_, _> = VLW::parse(",N,,N,,N,,N");
+        assert_eq!(result, Ok(("", VLW::default())));
+
+        for input in [
+            "7803.2,K,0.00,N,1520.8,N,12.4,N",
+            "7803.2,N,0.00,1520.8,N,12.4,N",
+            "7803.2,N,total,N,1520.8,N,12.4,N",
+        ] {
+            let result: IResult<_, _> = VLW::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
@@ -4,10 +4,12 @@ use nom::{
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
 
-#[cfg(feature = "nmea-v2-3")]
-use crate::nmea_content::FaaMode;
-use crate::{self as nmea0183_parser, IResult, NmeaParse, nmea_content::parse::with_unit};
+use crate::{
+    self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{FaaMode, encode::write_with_unit, parse::with_unit},
+};
 
 /// VTG - Track made good and Ground speed
 ///
@@ -28,24 +30,25 @@ use crate::{self as nmea0183_parser, IResult, NmeaParse, nmea_content::parse::wi
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 pub struct VTG {
-    #[nmea(parser(with_unit('T')))]
+    #[nmea(parser(with_unit('T')), writer(write_with_unit('T')))]
     /// Course over ground in degrees true
     pub course_over_ground_true: Option<f32>,
-    #[nmea(parser(with_unit('M')))]
+    #[nmea(parser(with_unit('M')), writer(write_with_unit('M')))]
     /// Course over ground in degrees magnetic
     pub course_over_ground_magnetic: Option<f32>,
-    #[nmea(parser(speed_over_ground))]
+    #[nmea(parser(speed), writer(write_speed))]
     /// Speed over ground in knots
     pub speed_over_ground: Option<f32>,
-    #[cfg(feature = "nmea-v2-3")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "nmea-v2-3")))]
+    #[nmea(version(NmeaVersion::V2_3))]
     /// FAA Mode Indicator
     pub faa_mode: Option<FaaMode>,
 }
 
-fn speed_over_ground<I, E>(i: I) -> IResult<I, Option<f32>, E>
+/// Parses a speed given both in knots and in km/h, converted to knots.
+pub fn speed<I, E>(i: I) -> IResult<I, Option<f32>, E>
 where
     I: Input + Clone + Offset + ParseTo<f32> + AsBytes,
     I: for<'a> Compare<&'a [u8]> + Compare<&'static str>,
@@ -53,14 +56,17 @@ where
     <I as Input>::Iter: Clone,
     E: ParseError<I>,
 {
-    let (i, speed_over_ground_knots) = with_unit('N').parse(i)?;
+    let (i, speed_knots) = with_unit('N').parse(i)?;
     let (i, _) = char(',').parse(i)?;
-    let (i, speed_over_ground_kph) = with_unit('K').parse(i)?;
+    let (i, speed_kph) = with_unit('K').parse(i)?;
+
+    Ok((i, speed_knots.or(speed_kph.map(|kph: f32| kph / 1.852))))
+}
 
-    Ok((
-        i,
-        speed_over_ground_knots.or(speed_over_ground_kph.map(|kph: f32| kph / 1.852)),
-    ))
+pub fn write_speed(speed: &Option<f32>, e: &mut Encoder<'_>) -> fmt::Result {
+    write_with_unit('N')(speed, e)?;
+    e.write_char(',')?;
+    write_with_unit('K')(&speed.map(|knots| knots * 1.852), e)
 }
 
 #[cfg(test)]
@@ -0,0 +1,145 @@
+use nom::{
+    AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser,
+    branch::alt,
+    character::complete::{char, one_of},
+    combinator::value,
+    error::ParseError,
+    sequence::separated_pair,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+
+use crate::{
+    self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse,
+    nmea_content::{encode::write_with_unit, parse::with_unit},
+};
+
+/// VWR - Relative Wind Speed and Angle
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vwr_relative_wind_speed_and_angle>
+///
+/// ```text
+///         1   2 3   4 5   6 7   8
+///         |   | |   | |   | |   |
+///  $--VWR,x.x,a,x.x,N,x.x,M,x.x,K*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct VWR {
+    #[nmea(parser(wind_angle), writer(write_wind_angle))]
+    /// Wind angle relative to the bow in degrees (0-180), negative values indicate wind from port
+    pub wind_angle: Option<f32>,
+    #[nmea(parser(wind_speed), writer(write_wind_speed))]
+    /// Wind speed in knots
+    pub wind_speed: Option<f32>,
+}
+
+pub fn wind_angle<I, E>(i: I) -> IResult<I, Option<f32>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    alt((
+        value(None, char(',')),
+        separated_pair(f32::parse, char(','), one_of("LR")).map(|(value, side)| {
+            if side == 'L' {
+                Some(-value)
+            } else {
+                Some(value)
+            }
+        }),
+    ))
+    .parse(i)
+}
+
+pub fn write_wind_angle(wind_angle: &Option<f32>, e: &mut Encoder<'_>) -> fmt::Result {
+    match wind_angle {
+        Some(value) => {
+            value.abs().encode(e)?;
+            let side = if value.is_sign_negative() { 'L' } else { 'R' };
+            write!(e, ",{side}")
+        }
+        None => e.write_char(','),
+    }
+}
+
+pub fn wind_speed<I, E>(i: I) -> IResult<I, Option<f32>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: for<'a> Compare<&'a [u8]> + Compare<&'static str>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    let (i, wind_speed_knots) = with_unit('N').parse(i)?;
+    let (i, _) = char(',').parse(i)?;
+    let (i, wind_speed_mps) = with_unit('M').parse(i)?;
+    let (i, _) = char(',').parse(i)?;
+    let (i, wind_speed_kph) = with_unit('K').parse(i)?;
+
+    let wind_speed = wind_speed_knots
+        .or(wind_speed_mps.map(|mps: f32| mps * 3600.0 / 1852.0))
+        .or(wind_speed_kph.map(|kph: f32| kph / 1.852));
+
+    Ok((i, wind_speed))
+}
+
+pub fn write_wind_speed(wind_speed: &Option<f32>, e: &mut Encoder<'_>) -> fmt::Result {
+    write_with_unit('N')(wind_speed, e)?;
+    e.write_char(',')?;
+    write_with_unit('M')(&wind_speed.map(|knots| knots * 1852.0 / 3600.0), e)?;
+    e.write_char(',')?;
+    write_with_unit('K')(&wind_speed.map(|knots| knots * 1.852), e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vwr_parsing() {
+        let cases = [
+            (
+                "75.0,R,10.0,N,5.1,M,18.5,K",
+                VWR {
+                    wind_angle: Some(75.0),
+                    wind_speed: Some(10.0),
+                },
+            ),
+            (
+                "120.5,L,,,,,18.52,K",
+                VWR {
+                    wind_angle: Some(-120.5),
+                    wind_speed: Some(10.0),
+                },
+            ),
+            (
+                ",,,,,,,",
+                VWR {
+                    wind_angle: None,
+                    wind_speed: None,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = VWR::parse(input);
+            assert_eq!(
+                result,
+                Ok(("", expected)),
+                "Failed: {input:?}\n\t{result:?}"
+            );
+        }
+
+        for input in ["75.0,X,10.0,N,5.1,M,18.5,K", "75.0,,10.0,N,5.1,M,18.5,K"] {
+            let result: IResult<_, _> = VWR::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
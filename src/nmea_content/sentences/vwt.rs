@@ -0,0 +1,85 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::vwr::{wind_angle, wind_speed, write_wind_angle, write_wind_speed};
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse};
+
+/// VWT - True Wind Speed and Angle
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vwt_true_wind_speed_and_angle>
+///
+/// ```text
+///         1   2 3   4 5   6 7   8
+///         |   | |   | |   | |   |
+///  $--VWT,x.x,a,x.x,N,x.x,M,x.x,K*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct VWT {
+    #[nmea(parser(wind_angle), writer(write_wind_angle))]
+    /// True wind angle relative to the bow in degrees (0-180), negative values indicate wind from port
+    pub wind_angle: Option<f32>,
+    #[nmea(parser(wind_speed), writer(write_wind_speed))]
+    /// True wind speed in knots
+    pub wind_speed: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_vwt_parsing() {
+        let cases = [
+            (
+                "30.0,R,12.0,N,6.2,M,22.2,K",
+                VWT {
+                    wind_angle: Some(30.0),
+                    wind_speed: Some(12.0),
+                },
+            ),
+            (
+                "150.0,L,,,,,18.52,K",
+                VWT {
+                    wind_angle: Some(-150.0),
+                    wind_speed: Some(10.0),
+                },
+            ),
+            (
+                "45.0,R,,,10.0,M,,",
+                VWT {
+                    wind_angle: Some(45.0),
+                    wind_speed: Some(10.0 * 3600.0 / 1852.0),
+                },
+            ),
+            (
+                ",,,,,,,",
+                VWT {
+                    wind_angle: None,
+                    wind_speed: None,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = VWT::parse(input);
+            assert_eq!(
+                result,
+                Ok(("", expected)),
+                "Failed: {input:?}\n\t{result:?}"
+            );
+        }
+
+        for input in [
+            "30.0,X,12.0,N,6.2,M,22.2,K",
+            "30.0,,12.0,N,6.2,M,22.2,K",
+            "30.0,R,12.0,N,6.2,M",
+        ] {
+            let result: IResult<_, _> = VWT::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
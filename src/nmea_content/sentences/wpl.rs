@@ -0,0 +1,63 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as nmea0183_parser, NmeaEncode, NmeaParse,
+    nmea_content::{Location, encode::write_location, parse::location},
+};
+
+/// Maximum length of a [`WaypointId`].
+pub const WAYPOINT_ID_CAPACITY: usize = 10;
+
+/// Waypoint identifier, as used by route and waypoint sentences such as [`WPL`] and [`RTE`]
+///
+/// Identifiers longer than [`WAYPOINT_ID_CAPACITY`] fail to parse.
+///
+/// [`RTE`]: crate::nmea_content::RTE
+pub type WaypointId = heapless::String<WAYPOINT_ID_CAPACITY>;
+
+/// WPL - Waypoint Location
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_wpl_waypoint_location>
+///
+/// ```text
+///         1       2 3        4 5
+///         |       | |        | |
+///  $--WPL,llll.ll,a,yyyyy.yy,a,c--c*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct WPL {
+    #[nmea(parser(location), writer(write_location))]
+    /// Waypoint location (latitude and longitude)
+    pub location: Option<Location>,
+    /// Waypoint identifier
+    pub waypoint_id: Option<WaypointId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_wpl_parsing() {
+        let result: IResult<_, _> = WPL::parse("4917.16,N,12310.64,W,003");
+        let (rest, wpl) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(wpl.waypoint_id.as_deref(), Some("003"));
+        let location = wpl.location.unwrap();
+        assert!((location.latitude - 49.286).abs() < 1e-6);
+        assert!((location.longitude + 123.177_333).abs() < 1e-6);
+
+        let result: IResult<_, _> = WPL::parse(",,,,");
+        assert_eq!(result, Ok(("", WPL::default())));
+
+        for input in ["4917.16,N,12310.64,W,WAYPOINT0123", "4917.16,N,12310.64,W"] {
+            let result: IResult<_, _> = WPL::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
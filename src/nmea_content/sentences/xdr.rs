@@ -0,0 +1,156 @@
+use nom::{
+    character::complete::{none_of, one_of},
+    combinator::opt,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{self as nmea0183_parser, NmeaEncode, NmeaParse};
+
+/// Maximum number of measurements reported by an [`XDR`] sentence.
+///
+/// Transducers usually report up to 4 measurements per sentence, longer lists
+/// are split across several sentences.
+pub const XDR_MEASUREMENTS_CAPACITY: usize = 6;
+
+/// XDR - Transducer Measurement
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_xdr_transducer_measurement>
+///
+/// ```text
+///         1 2   3 4            n
+///         | |   | |            |
+///  $--XDR,a,x.x,a,c--c, ..... *hh<CR><LF>
+/// ```
+///
+/// Fields 1 to 4 describe a single measurement and repeat for each transducer.
+///
+/// Unlike other sentences, the units of the measurements are kept as sent, since
+/// they depend on the transducer type.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+// The measurements take the whole sentence, any unparsed input is a malformed measurement
+#[nmea(exact)]
+pub struct XDR {
+    /// Transducer measurements
+    pub measurements: heapless::Vec<Measurement, XDR_MEASUREMENTS_CAPACITY>,
+}
+
+/// Transducer measurement reported by [`XDR`] sentences
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct Measurement {
+    /// Type of transducer
+    pub transducer_type: TransducerType,
+    /// Measurement value
+    pub value: Option<f32>,
+    #[nmea(parser(opt(none_of(","))), writer(NmeaEncode::encode))]
+    /// Unit of the measurement, such as `C` for degrees Celsius or `B` for bars
+    pub unit: Option<char>,
+    /// Name of the transducer, up to 8 characters
+    pub name: Option<heapless::String<8>>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+#[nmea(selector(one_of("ACDFGHILNPRSTUV")))]
+/// Type of transducer of an [`XDR`] measurement
+pub enum TransducerType {
+    #[nmea(selector('A'))]
+    /// A - Angular displacement, in degrees
+    AngularDisplacement,
+    #[default]
+    #[nmea(selector('C'))]
+    /// C - Temperature, in degrees Celsius
+    Temperature,
+    #[nmea(selector('D'))]
+    /// D - Linear displacement, in meters
+    LinearDisplacement,
+    #[nmea(selector('F'))]
+    /// F - Frequency, in hertz
+    Frequency,
+    #[nmea(selector('G'))]
+    /// G - Generic, without unit
+    Generic,
+    #[nmea(selector('H'))]
+    /// H - Humidity, in percent
+    Humidity,
+    #[nmea(selector('I'))]
+    /// I - Current, in amperes
+    Current,
+    #[nmea(selector('L'))]
+    /// L - Salinity, in parts per thousand
+    Salinity,
+    #[nmea(selector('N'))]
+    /// N - Force, in newtons
+    Force,
+    #[nmea(selector('P'))]
+    /// P - Pressure, in bars or pascals
+    Pressure,
+    #[nmea(selector('R'))]
+    /// R - Flow rate, in liters per second
+    FlowRate,
+    #[nmea(selector('S'))]
+    /// S - Switch or valve, without unit
+    Switch,
+    #[nmea(selector('T'))]
+    /// T - Tachometer, in revolutions per minute
+    Tachometer,
+    #[nmea(selector('U'))]
+    /// U - Voltage, in volts
+    Voltage,
+    #[nmea(selector('V'))]
+    /// V - Volume, in cubic meters
+    Volume,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IResult;
+
+    #[test]
+    fn test_xdr_parsing() {
+        let result: IResult<_, _> = XDR::parse("P,1.02,B,BARO,C,18.5,C,AIRTEMP,H,65,P,");
+        let (rest, xdr) = result.unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            xdr.measurements,
+            [
+                Measurement {
+                    transducer_type: TransducerType::Pressure,
+                    value: Some(1.02),
+                    unit: Some('B'),
+                    name: Some("BARO".try_into().unwrap()),
+                },
+                Measurement {
+                    transducer_type: TransducerType::Temperature,
+                    value: Some(18.5),
+                    unit: Some('C'),
+                    name: Some("AIRTEMP".try_into().unwrap()),
+                },
+                Measurement {
+                    transducer_type: TransducerType::Humidity,
+                    value: Some(65.0),
+                    unit: Some('P'),
+                    name: None,
+                },
+            ]
+        );
+
+        let result: IResult<_, _> = XDR::parse("S,,,VALVE");
+        let (_, xdr) = result.unwrap();
+        assert_eq!(xdr.measurements[0].unit, None);
+        assert_eq!(xdr.measurements[0].value, None);
+
+        for input in ["X,1.0,B,BARO", "P,abc,B,BARO", "P,1.0,B,BARO,C"] {
+            let result: IResult<_, _> = XDR::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
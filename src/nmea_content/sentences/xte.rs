@@ -0,0 +1,146 @@
+use nom::{
+    AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser,
+    branch::alt,
+    character::complete::{char, none_of, one_of},
+    combinator::{not, opt, value},
+    error::ParseError,
+    sequence::{separated_pair, terminated},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+
+use crate::{
+    self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse, NmeaVersion,
+    nmea_content::{FaaMode, Status},
+};
+
+/// XTE - Cross-Track Error, Measured
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_xte_cross_track_error_measured>
+///
+/// ```text
+///         1 2 3   4 5
+///         | | |   | |
+///  $--XTE,A,A,x.x,a,N*hh<CR><LF>
+/// ```
+///
+/// NMEA 2.3:
+///
+/// ```text
+///         1 2 3   4 5 6
+///         | | |   | | |
+///  $--XTE,A,A,x.x,a,N,m*hh<CR><LF>
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
+pub struct XTE {
+    /// Status, `Invalid` for a Loran-C blink or SNR warning
+    pub status: Status,
+    /// Status, `Invalid` for a Loran-C cycle lock warning
+    pub cycle_lock_status: Status,
+    #[nmea(
+        parser(cross_track_error_with_unit),
+        writer(write_cross_track_error_with_unit)
+    )]
+    /// Cross-track error in nautical miles, negative values indicate to steer left
+    pub cross_track_error: Option<f32>,
+    #[nmea(version(NmeaVersion::V2_3))]
+    /// FAA Mode Indicator
+    pub faa_mode: Option<FaaMode>,
+}
+
+pub fn cross_track_error<I, E>(i: I) -> IResult<I, Option<f32>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    alt((
+        value(None, char(',')),
+        separated_pair(f32::parse, char(','), one_of("LR")).map(|(value, dir)| {
+            if dir == 'L' {
+                Some(-value)
+            } else {
+                Some(value)
+            }
+        }),
+    ))
+    .parse(i)
+}
+
+pub fn write_cross_track_error(
+    cross_track_error: &Option<f32>,
+    e: &mut Encoder<'_>,
+) -> fmt::Result {
+    match cross_track_error {
+        Some(value) => {
+            value.abs().encode(e)?;
+            let dir = if value.is_sign_negative() { 'L' } else { 'R' };
+            write!(e, ",{dir}")
+        }
+        None => e.write_char(','),
+    }
+}
+
+/// Parses a cross-track error followed by its unit, only nautical miles are used.
+pub fn cross_track_error_with_unit<I, E>(i: I) -> IResult<I, Option<f32>, E>
+where
+    I: Input + Offset + ParseTo<f32> + AsBytes,
+    I: Compare<&'static str> + for<'a> Compare<&'a [u8]>,
+    <I as Input>::Item: AsChar,
+    <I as Input>::Iter: Clone,
+    E: ParseError<I>,
+{
+    separated_pair(
+        cross_track_error,
+        char(','),
+        terminated(opt(char('N')), not(none_of(","))),
+    )
+    .map(|(cross_track_error, unit)| unit.and(cross_track_error))
+    .parse(i)
+}
+
+pub fn write_cross_track_error_with_unit(
+    cross_track_error: &Option<f32>,
+    e: &mut Encoder<'_>,
+) -> fmt::Result {
+    write_cross_track_error(cross_track_error, e)?;
+    e.write_char(',')?;
+    match cross_track_error {
+        Some(_) => e.write_char('N'),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xte_parsing() {
+        let cases = [
+            ("A,A,0.67,L,N,A", Some(-0.67)),
+            ("A,A,1.5,R,N,A", Some(1.5)),
+            ("A,A,1.5,R,N", Some(1.5)),
+            ("V,V,,,,", None),
+        ];
+
+        for (input, expected) in cases {
+            let result: IResult<_, _> = XTE::parse(input);
+            let Ok(("", xte)) = result else {
+                panic!("Failed: {input:?}\n\t{result:?}");
+            };
+            assert_eq!(xte.cross_track_error, expected, "Failed: {input:?}");
+        }
+
+        for input in ["A,A,0.67,X,N,A", "A,A,0.67,L,K,A", "A,A,0.67,,N,A"] {
+            let result: IResult<_, _> = XTE::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
+}
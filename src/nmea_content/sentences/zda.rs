@@ -1,5 +1,6 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
 
 use nom::{
     AsChar, Compare, Input, Parser,
@@ -10,7 +11,7 @@ use nom::{
     error::ParseError,
 };
 
-use crate::{self as nmea0183_parser, IResult, NmeaParse};
+use crate::{self as nmea0183_parser, Encoder, IResult, NmeaEncode, NmeaParse};
 
 /// ZDA - Time & Date - UTC, day, month, year and local time zone
 ///
@@ -23,14 +24,15 @@ use crate::{self as nmea0183_parser, IResult, NmeaParse};
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-#[derive(Debug, Default, Clone, PartialEq, NmeaParse)]
+#[derive(Debug, Default, Clone, PartialEq, NmeaEncode, NmeaParse)]
+#[nmea(input(&str), input(&[u8]))]
 pub struct ZDA {
     /// Fix time in UTC
     pub time: Option<time::Time>,
-    #[nmea(parser(date_full_year))]
+    #[nmea(parser(date_full_year), writer(write_date_full_year))]
     /// Fix date in UTC
     pub date: Option<time::Date>,
-    #[nmea(parser(utc_offset))]
+    #[nmea(parser(utc_offset), writer(write_utc_offset))]
     /// Local zone description, offset from UTC
     pub utc_offset: Option<time::UtcOffset>,
 }
@@ -113,6 +115,34 @@ where
     .parse(i)
 }
 
+fn write_date_full_year(date: &Option<time::Date>, e: &mut Encoder<'_>) -> fmt::Result {
+    match date {
+        Some(date) => write!(
+            e,
+            "{:02},{:02},{:04}",
+            date.day(),
+            date.month() as u8,
+            date.year()
+        ),
+        None => e.write_str(",,"),
+    }
+}
+
+fn write_utc_offset(utc_offset: &Option<time::UtcOffset>, e: &mut Encoder<'_>) -> fmt::Result {
+    match utc_offset {
+        Some(utc_offset) => {
+            let sign = if utc_offset.is_negative() { "-" } else { "" };
+            write!(
+                e,
+                "{sign}{:02},{:02}",
+                utc_offset.whole_hours().unsigned_abs(),
+                utc_offset.minutes_past_hour().unsigned_abs()
+            )
+        }
+        None => e.write_char(','),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
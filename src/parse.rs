@@ -1,13 +1,15 @@
 use nom::{
     AsBytes, AsChar, Compare, Input, Offset, ParseTo, Parser,
+    bytes::complete::take_till1,
     character::complete::{anychar, char},
     combinator::opt,
-    error::ParseError,
+    error::{ErrorKind, ParseError},
     multi::many0,
     sequence::preceded,
 };
+use std::{borrow::Cow, fmt};
 
-use crate::{Error, IResult};
+use crate::{Error, IResult, VersionRange};
 
 /// Trait for parsing types from NMEA 0183 sentence fields.
 ///
@@ -134,6 +136,42 @@ where
     /// ```
     fn parse(i: I) -> IResult<I, Self, E>;
 
+    /// Parses the input as sent by a device implementing a range of NMEA versions.
+    ///
+    /// The fields added by each NMEA version are required, optional or forbidden depending on
+    /// the [`VersionRange`], as returned by [`VersionRange::field_mode`]. [`parse`] parses the
+    /// fields of all versions as [`VersionRange::default()`] does, the fields added after
+    /// NMEA 2.0 being optional.
+    ///
+    /// The default implementation calls [`parse`], for types without version-specific fields.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "nmea-content")] {
+    /// use nmea0183_parser::{IResult, NmeaParse, NmeaVersion, nmea_content::RMC};
+    ///
+    /// // NMEA 2.3 RMC sentence content, without the navigation status of NMEA 4.11
+    /// let content = "001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A";
+    ///
+    /// let result: IResult<_, _> = RMC::parse(content);
+    /// let (_, rmc) = result.unwrap();
+    /// assert_eq!(rmc.nav_status, None);
+    ///
+    /// let result: IResult<_, _> = RMC::parse_version(content, NmeaVersion::V2_3.into());
+    /// assert!(result.is_ok());
+    ///
+    /// let result: IResult<_, _> = RMC::parse_version(content, NmeaVersion::V4_11.into());
+    /// assert!(result.is_err());
+    /// # }
+    /// ```
+    ///
+    /// [`parse`]: NmeaParse::parse
+    fn parse_version(i: I, versions: VersionRange) -> IResult<I, Self, E> {
+        let _ = versions;
+        Self::parse(i)
+    }
+
     /// Returns a parser that first consumes a separator, then parses the value.
     /// This is useful for parsing fields that are separated by a specific character,
     /// such as a comma in NMEA sentences.
@@ -161,6 +199,91 @@ where
     {
         preceded(separator, Self::parse)
     }
+
+    /// Returns a parser that first consumes a separator, then parses the value for a range of
+    /// NMEA versions, as [`parse_version`] does.
+    ///
+    /// The default implementation ignores the range and uses [`parse_preceded`], the derived
+    /// implementations of types with version-specific fields parse the value with
+    /// [`parse_version`].
+    ///
+    /// [`parse_preceded`]: NmeaParse::parse_preceded
+    /// [`parse_version`]: NmeaParse::parse_version
+    fn parse_version_preceded<S>(
+        separator: S,
+        versions: VersionRange,
+    ) -> impl Parser<I, Output = Self, Error = Error<I, E>>
+    where
+        S: Parser<I, Error = Error<I, E>>,
+    {
+        let _ = versions;
+        Self::parse_preceded(separator)
+    }
+}
+
+/// Input types whose content can be borrowed as a string slice.
+pub trait AsStr<'a> {
+    fn as_str(&self) -> Option<&'a str>;
+}
+
+impl<'a> AsStr<'a> for &'a str {
+    fn as_str(&self) -> Option<&'a str> {
+        Some(self)
+    }
+}
+
+impl<'a> AsStr<'a> for &'a [u8] {
+    fn as_str(&self) -> Option<&'a str> {
+        std::str::from_utf8(self).ok()
+    }
+}
+
+/// Decodes the `^hh` escapes of reserved characters in a text field, writing the text to `output`.
+///
+/// Fails with [`ErrorKind::Escaped`] if an escape is not followed by two hexadecimal digits
+/// or decodes to a control character other than `<CR>` or `<LF>`, and with
+/// [`ErrorKind::TooLarge`] if `output` fails, such as a full fixed-capacity string.
+pub(crate) fn unescape(text: &str, output: &mut impl fmt::Write) -> Result<(), ErrorKind> {
+    let mut parts = text.split('^');
+    let first = parts.next().unwrap_or_default();
+    output.write_str(first).map_err(|_| ErrorKind::TooLarge)?;
+
+    for part in parts {
+        let (hex, rest) = part.split_at_checked(2).ok_or(ErrorKind::Escaped)?;
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ErrorKind::Escaped);
+        }
+
+        let code = u8::from_str_radix(hex, 16).map_err(|_| ErrorKind::Escaped)?;
+        if !matches!(code, b'\r' | b'\n' | b' '..=b'~') {
+            return Err(ErrorKind::Escaped);
+        }
+
+        output
+            .write_char(char::from(code))
+            .and_then(|()| output.write_str(rest))
+            .map_err(|_| ErrorKind::TooLarge)?;
+    }
+
+    Ok(())
+}
+
+/// Takes a non-empty text field, up to the next separator, as a string slice.
+pub(crate) fn text_field<'a, I, E>(i: I) -> IResult<I, &'a str, E>
+where
+    I: Input + AsStr<'a>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    let (i, text) = take_till1(|c: <I as Input>::Item| c.as_char() == ',').parse(i)?;
+
+    match text.as_str() {
+        Some(text) => Ok((i, text)),
+        None => Err(nom::Err::Error(nom::error::make_error(
+            text,
+            ErrorKind::Char,
+        ))),
+    }
 }
 
 macro_rules! impl_uints_type {
@@ -227,6 +350,42 @@ where
     }
 }
 
+/// Text fields are borrowed from the input as is, `^hh` escapes included.
+///
+/// Use [`Cow<str>`] to decode the escapes.
+impl<'a, I, E> NmeaParse<I, E> for &'a str
+where
+    I: Input + AsStr<'a>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        text_field(i)
+    }
+}
+
+/// Text fields are borrowed from the input, unless they contain `^hh` escapes
+/// of reserved characters, which are decoded into an owned string.
+impl<'a, I, E> NmeaParse<I, E> for Cow<'a, str>
+where
+    I: Input + AsStr<'a>,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+{
+    fn parse(i: I) -> IResult<I, Self, E> {
+        let (rest, text) = text_field(i.clone())?;
+        if !text.contains('^') {
+            return Ok((rest, Cow::Borrowed(text)));
+        }
+
+        let mut decoded = String::with_capacity(text.len());
+        match unescape(text, &mut decoded) {
+            Ok(()) => Ok((rest, Cow::Owned(decoded))),
+            Err(kind) => Err(nom::Err::Error(nom::error::make_error(i, kind))),
+        }
+    }
+}
+
 impl<T, I, E> NmeaParse<I, E> for Option<T>
 where
     T: NmeaParse<I, E>,
@@ -404,6 +563,33 @@ where
 mod tests {
     use crate::{IResult, NmeaParse};
     use nom::{Parser, character::complete::char};
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_parse_text() {
+        let result: IResult<_, &str> = <&str>::parse("GPS ^2A FIX,A");
+        assert_eq!(result, Ok((",A", "GPS ^2A FIX")));
+
+        let result: IResult<_, Option<&str>> =
+            Option::<&str>::parse_preceded(char(',')).parse(",,A");
+        assert_eq!(result, Ok((",A", None)));
+
+        let result: IResult<_, Cow<str>> = Cow::parse("NO FIX,A");
+        assert!(matches!(result, Ok((",A", Cow::Borrowed("NO FIX")))));
+
+        let result: IResult<_, Cow<str>> = Cow::parse("A^2CB^5eC".as_bytes());
+        assert_eq!(result, Ok(("".as_bytes(), Cow::Owned("A,B^C".to_string()))));
+
+        let result: IResult<_, Cow<str>> = Cow::parse("LINE^0D^0A^7E");
+        assert_eq!(result, Ok(("", Cow::Owned("LINE\r\n~".to_string()))));
+
+        for input in [
+            "A^2", "A^ZZ", "A^+1", "A^0", "A^", "A^00", "A^1B", "A^7F", "A^80", "",
+        ] {
+            let result: IResult<_, Cow<str>> = Cow::parse(input);
+            assert!(result.is_err(), "Failed: {input:?}\n\t{result:?}");
+        }
+    }
 
     #[test]
     fn test_parse_vec() {
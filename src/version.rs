@@ -0,0 +1,152 @@
+use std::ops::RangeInclusive;
+
+/// Version of the NMEA 0183 standard implemented by a device.
+///
+/// Later versions of the standard add fields at the end of some sentences, such as the FAA
+/// mode indicator of NMEA 2.3 or the navigation status of NMEA 4.11. The fields of all the
+/// versions are always compiled in, and the fields expected from a device are selected at
+/// runtime with a [`VersionRange`], passed to
+/// [`NmeaParse::parse_version`](crate::NmeaParse::parse_version).
+///
+/// The default version is the latest one, [`NmeaVersion::V4_11`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NmeaVersion {
+    /// NMEA 2.0 to 2.2, before the fields added by later versions
+    V2_0,
+    /// NMEA 2.3
+    V2_3,
+    /// NMEA 3.0
+    V3_0,
+    #[default]
+    /// NMEA 4.11
+    V4_11,
+}
+
+/// Defines how the parser should handle the fields of an NMEA version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldMode {
+    /// The fields are forbidden and must not be present.
+    ///
+    /// The parser leaves the fields to their default value, and the sentence fails to parse
+    /// if they are present.
+    Forbidden,
+
+    /// The fields are optional and will be parsed if present.
+    Optional,
+
+    /// The fields are required and must be present, even if empty.
+    Required,
+}
+
+/// Range of NMEA versions accepted from a device.
+///
+/// The fields of the versions up to the minimum version are required, the fields of the
+/// versions after the maximum version are forbidden, and the fields of the versions in between
+/// are optional. A single version, converted into a range with [`From`], requires all of its
+/// fields and forbids the fields of later versions.
+///
+/// The default range goes from [`NmeaVersion::V2_0`] to the latest version: every field
+/// added after NMEA 2.0 is optional. It is the range used by [`NmeaParse::parse`].
+///
+/// # Examples
+///
+/// ```rust
+/// use nmea0183_parser::{FieldMode, NmeaVersion, VersionRange};
+///
+/// let versions = VersionRange::from(NmeaVersion::V2_3);
+/// assert_eq!(versions.field_mode(NmeaVersion::V2_3), FieldMode::Required);
+/// assert_eq!(versions.field_mode(NmeaVersion::V4_11), FieldMode::Forbidden);
+///
+/// let versions = VersionRange::from(NmeaVersion::V2_3..=NmeaVersion::V4_11);
+/// assert_eq!(versions.field_mode(NmeaVersion::V2_3), FieldMode::Required);
+/// assert_eq!(versions.field_mode(NmeaVersion::V4_11), FieldMode::Optional);
+///
+/// let versions = VersionRange::default();
+/// assert_eq!(versions.field_mode(NmeaVersion::V2_0), FieldMode::Required);
+/// assert_eq!(versions.field_mode(NmeaVersion::V2_3), FieldMode::Optional);
+/// ```
+///
+/// [`NmeaParse::parse`]: crate::NmeaParse::parse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VersionRange {
+    min: NmeaVersion,
+    max: NmeaVersion,
+}
+
+impl VersionRange {
+    /// Creates a range of versions from `min` to `max`, included.
+    ///
+    /// A `max` version older than `min` is raised to `min`.
+    pub fn new(min: NmeaVersion, max: NmeaVersion) -> Self {
+        VersionRange {
+            min,
+            max: max.max(min),
+        }
+    }
+
+    /// Returns the oldest version of the range, whose fields are all required.
+    pub fn min(&self) -> NmeaVersion {
+        self.min
+    }
+
+    /// Returns the latest version of the range, after which fields are forbidden.
+    pub fn max(&self) -> NmeaVersion {
+        self.max
+    }
+
+    /// Returns how the fields added by `version` are handled for this range.
+    pub fn field_mode(&self, version: NmeaVersion) -> FieldMode {
+        if version <= self.min {
+            FieldMode::Required
+        } else if version <= self.max {
+            FieldMode::Optional
+        } else {
+            FieldMode::Forbidden
+        }
+    }
+}
+
+impl Default for VersionRange {
+    fn default() -> Self {
+        VersionRange::new(NmeaVersion::V2_0, NmeaVersion::default())
+    }
+}
+
+impl From<NmeaVersion> for VersionRange {
+    fn from(version: NmeaVersion) -> Self {
+        VersionRange::new(version, version)
+    }
+}
+
+impl From<RangeInclusive<NmeaVersion>> for VersionRange {
+    fn from(range: RangeInclusive<NmeaVersion>) -> Self {
+        VersionRange::new(*range.start(), *range.end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_range() {
+        use NmeaVersion::{V2_0, V2_3, V3_0, V4_11};
+
+        assert_eq!(NmeaVersion::default(), V4_11);
+        assert_eq!(VersionRange::default(), VersionRange::from(V2_0..=V4_11));
+
+        let versions = VersionRange::from(V2_3);
+        assert_eq!(versions.field_mode(V2_0), FieldMode::Required);
+        assert_eq!(versions.field_mode(V2_3), FieldMode::Required);
+        assert_eq!(versions.field_mode(V3_0), FieldMode::Forbidden);
+        assert_eq!(versions.field_mode(V4_11), FieldMode::Forbidden);
+
+        let versions = VersionRange::new(V4_11, V2_0);
+        assert_eq!((versions.min(), versions.max()), (V4_11, V4_11));
+
+        let versions = VersionRange::from(V2_3..=V3_0);
+        assert_eq!(versions.field_mode(V2_3), FieldMode::Required);
+        assert_eq!(versions.field_mode(V3_0), FieldMode::Optional);
+        assert_eq!(versions.field_mode(V4_11), FieldMode::Forbidden);
+    }
+}